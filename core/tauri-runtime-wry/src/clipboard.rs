@@ -6,23 +6,41 @@
 
 use crate::{getter, Context, Message};
 
-use std::sync::{
-  mpsc::{channel, Sender},
-  Arc, Mutex,
+use std::{
+  path::PathBuf,
+  sync::{
+    mpsc::{channel, Sender},
+    Arc, Mutex,
+  },
 };
 
-use tauri_runtime::{ClipboardManager, Result, UserEvent};
+use tauri_runtime::{
+  clipboard::{ClipboardChangeListener, ClipboardPollWatcher},
+  ClipboardFormat, ClipboardImage, ClipboardManager, Error, Result, UserEvent,
+};
 pub use wry::application::clipboard::Clipboard;
 
 #[derive(Debug, Clone)]
 pub enum ClipboardMessage {
   WriteText(String, Sender<()>),
   ReadText(Sender<Option<String>>),
+  WriteImage(ClipboardImage, Sender<Result<()>>),
+  ReadImage(Sender<Result<Option<ClipboardImage>>>),
+  WriteHtml(String, Option<String>, Sender<Result<()>>),
+  ReadHtml(Sender<Result<Option<String>>>),
+  WriteFiles(Vec<PathBuf>, Sender<Result<()>>),
+  ReadFiles(Sender<Result<Option<Vec<PathBuf>>>>),
+  WriteFormats(Vec<ClipboardFormat>, Sender<Result<()>>),
+  ReadFormat(String, Sender<Result<Option<Vec<u8>>>>),
+  AvailableFormats(Sender<Result<Vec<String>>>),
+  Clear(Sender<Result<()>>),
 }
 
 #[derive(Debug, Clone)]
 pub struct ClipboardManagerWrapper<T: UserEvent> {
   pub context: Context<T>,
+  pub change_listener: ClipboardChangeListener,
+  pub poll_watcher: Arc<Mutex<Option<ClipboardPollWatcher>>>,
 }
 
 // SAFETY: this is safe since the `Context` usage is guarded on `send_user_message`.
@@ -44,6 +62,110 @@ impl<T: UserEvent> ClipboardManager for ClipboardManagerWrapper<T> {
     )?;
     Ok(())
   }
+
+  fn write_image(&mut self, image: ClipboardImage) -> Result<()> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Clipboard(ClipboardMessage::WriteImage(image, tx))
+    )?
+  }
+
+  fn read_image(&self) -> Result<Option<ClipboardImage>> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Clipboard(ClipboardMessage::ReadImage(tx))
+    )?
+  }
+
+  fn write_html(&mut self, html: String, alt_text: Option<String>) -> Result<()> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Clipboard(ClipboardMessage::WriteHtml(html, alt_text, tx))
+    )?
+  }
+
+  fn read_html(&self) -> Result<Option<String>> {
+    let (tx, rx) = channel();
+    getter!(self, rx, Message::Clipboard(ClipboardMessage::ReadHtml(tx)))?
+  }
+
+  fn write_files(&mut self, paths: Vec<PathBuf>) -> Result<()> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Clipboard(ClipboardMessage::WriteFiles(paths, tx))
+    )?
+  }
+
+  fn read_files(&self) -> Result<Option<Vec<PathBuf>>> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Clipboard(ClipboardMessage::ReadFiles(tx))
+    )?
+  }
+
+  fn write_formats(&mut self, formats: Vec<ClipboardFormat>) -> Result<()> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Clipboard(ClipboardMessage::WriteFormats(formats, tx))
+    )?
+  }
+
+  fn read_format(&self, mime_type: String) -> Result<Option<Vec<u8>>> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Clipboard(ClipboardMessage::ReadFormat(mime_type, tx))
+    )?
+  }
+
+  fn available_formats(&self) -> Result<Vec<String>> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Clipboard(ClipboardMessage::AvailableFormats(tx))
+    )?
+  }
+
+  fn clear(&mut self) -> Result<()> {
+    let (tx, rx) = channel();
+    getter!(self, rx, Message::Clipboard(ClipboardMessage::Clear(tx)))?
+  }
+
+  fn watch<F: Fn(Vec<String>) + Send + 'static>(&mut self, handler: F) -> Result<()> {
+    self.change_listener.set(Box::new(handler));
+    if !imp::NATIVE_CHANGE_EVENTS {
+      let mut poll_watcher = self.poll_watcher.lock().unwrap();
+      if poll_watcher.is_none() {
+        poll_watcher.replace(ClipboardPollWatcher::start(
+          self.clone(),
+          imp::change_count,
+          ClipboardPollWatcher::DEFAULT_INTERVAL,
+          self.change_listener.clone(),
+        ));
+      }
+    }
+    Ok(())
+  }
+
+  fn unwatch(&mut self) -> Result<()> {
+    self.change_listener.clear();
+    self.poll_watcher.lock().unwrap().take();
+    Ok(())
+  }
 }
 
 pub fn handle_clipboard_message(
@@ -58,5 +180,612 @@ pub fn handle_clipboard_message(
     ClipboardMessage::ReadText(tx) => tx
       .send(clipboard_manager.lock().unwrap().read_text())
       .unwrap(),
+    ClipboardMessage::WriteImage(image, tx) => tx.send(imp::write_image(image)).unwrap(),
+    ClipboardMessage::ReadImage(tx) => tx.send(imp::read_image()).unwrap(),
+    ClipboardMessage::WriteHtml(html, alt_text, tx) => {
+      tx.send(imp::write_html(html, alt_text)).unwrap()
+    }
+    ClipboardMessage::ReadHtml(tx) => tx.send(imp::read_html()).unwrap(),
+    ClipboardMessage::WriteFiles(paths, tx) => tx.send(imp::write_files(paths)).unwrap(),
+    ClipboardMessage::ReadFiles(tx) => tx.send(imp::read_files()).unwrap(),
+    ClipboardMessage::WriteFormats(formats, tx) => tx.send(imp::write_formats(formats)).unwrap(),
+    ClipboardMessage::ReadFormat(mime_type, tx) => tx.send(imp::read_format(mime_type)).unwrap(),
+    ClipboardMessage::AvailableFormats(tx) => tx.send(imp::available_formats()).unwrap(),
+    ClipboardMessage::Clear(tx) => tx.send(imp::clear()).unwrap(),
+  }
+}
+
+pub use imp::connect_change_listener;
+
+fn error<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> Error {
+  Error::Clipboard(error.into())
+}
+
+#[allow(dead_code)]
+fn unsupported<T>() -> Result<T> {
+  Err(error("operation not supported on this platform"))
+}
+
+fn check_image_size(image: &ClipboardImage) -> Result<()> {
+  if image.rgba.len() as u64 == image.width as u64 * image.height as u64 * 4 {
+    Ok(())
+  } else {
+    Err(error("the image size does not match its RGBA bytes"))
+  }
+}
+
+// tao's clipboard only supports text, so the other formats use the native toolkit directly.
+#[cfg(any(
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+mod imp {
+  use super::*;
+  use gtk::{
+    gdk,
+    gdk_pixbuf::{Colorspace, Pixbuf},
+    glib,
+    prelude::*,
+  };
+
+  const TEXT_TARGETS: &[&str] = &[
+    "text/plain;charset=utf-8",
+    "UTF8_STRING",
+    "STRING",
+    "TEXT",
+    "text/plain",
+  ];
+
+  pub const NATIVE_CHANGE_EVENTS: bool = true;
+
+  pub fn change_count() -> Option<u64> {
+    None
+  }
+
+  fn clipboard() -> gtk::Clipboard {
+    gtk::Clipboard::get(&gdk::SELECTION_CLIPBOARD)
+  }
+
+  pub fn connect_change_listener(listener: ClipboardChangeListener) {
+    clipboard().connect_owner_change(move |clipboard, _| {
+      if listener.is_active() {
+        let listener = listener.clone();
+        clipboard.request_targets(move |_, targets| {
+          listener.emit(
+            targets
+              .iter()
+              .map(|target| target.name().to_string())
+              .collect(),
+          );
+        });
+      }
+    });
+  }
+
+  pub fn write_image(image: ClipboardImage) -> Result<()> {
+    check_image_size(&image)?;
+    let ClipboardImage {
+      rgba,
+      width,
+      height,
+    } = image;
+    // gdk-pixbuf uses signed dimensions and row strides
+    let (width, height, rowstride) = match (
+      i32::try_from(width),
+      i32::try_from(height),
+      i32::try_from(width).ok().and_then(|w| w.checked_mul(4)),
+    ) {
+      (Ok(width), Ok(height), Some(rowstride)) => (width, height, rowstride),
+      _ => return Err(error("the image is too large")),
+    };
+    let pixbuf = Pixbuf::from_bytes(
+      &glib::Bytes::from_owned(rgba),
+      Colorspace::Rgb,
+      true,
+      8,
+      width,
+      height,
+      rowstride,
+    );
+    clipboard().set_image(&pixbuf);
+    Ok(())
+  }
+
+  pub fn read_image() -> Result<Option<ClipboardImage>> {
+    Ok(clipboard().wait_for_image().map(|pixbuf| {
+      let width = pixbuf.width() as usize;
+      let height = pixbuf.height() as usize;
+      let rowstride = pixbuf.rowstride() as usize;
+      let channels = pixbuf.n_channels() as usize;
+      // SAFETY: the pixbuf is owned by this function and the pixels are only read.
+      let pixels = unsafe { pixbuf.pixels() };
+      let mut rgba = Vec::with_capacity(width * height * 4);
+      for y in 0..height {
+        let row = &pixels[y * rowstride..];
+        for x in 0..width {
+          let pixel = &row[x * channels..(x + 1) * channels];
+          rgba.extend_from_slice(&pixel[..3]);
+          rgba.push(if channels == 4 { pixel[3] } else { u8::MAX });
+        }
+      }
+      ClipboardImage {
+        rgba,
+        width: width as u32,
+        height: height as u32,
+      }
+    }))
+  }
+
+  pub fn write_html(html: String, alt_text: Option<String>) -> Result<()> {
+    let mut formats = vec![ClipboardFormat {
+      mime_type: "text/html".into(),
+      data: html.into_bytes(),
+    }];
+    if let Some(alt_text) = alt_text {
+      for target in TEXT_TARGETS {
+        formats.push(ClipboardFormat {
+          mime_type: (*target).into(),
+          data: alt_text.clone().into_bytes(),
+        });
+      }
+    }
+    write_formats(formats)
+  }
+
+  pub fn read_html() -> Result<Option<String>> {
+    read_format("text/html".into())
+      .map(|data| data.map(|data| String::from_utf8_lossy(&data).into_owned()))
+  }
+
+  pub fn write_files(paths: Vec<PathBuf>) -> Result<()> {
+    let mut uris = Vec::new();
+    for path in paths {
+      uris.push(
+        glib::filename_to_uri(path, None)
+          .map_err(error)?
+          .to_string(),
+      );
+    }
+    write_formats(vec![
+      ClipboardFormat {
+        mime_type: "text/uri-list".into(),
+        data: uris.join("\r\n").into_bytes(),
+      },
+      // used by the GNOME file managers to paste the files
+      ClipboardFormat {
+        mime_type: "x-special/gnome-copied-files".into(),
+        data: format!("copy\n{}", uris.join("\n")).into_bytes(),
+      },
+    ])
+  }
+
+  pub fn read_files() -> Result<Option<Vec<PathBuf>>> {
+    let uris = clipboard().wait_for_uris();
+    if uris.is_empty() {
+      Ok(None)
+    } else {
+      Ok(Some(
+        uris
+          .iter()
+          .filter_map(|uri| glib::filename_from_uri(uri).ok())
+          .map(|(path, _)| path)
+          .collect(),
+      ))
+    }
+  }
+
+  pub fn write_formats(formats: Vec<ClipboardFormat>) -> Result<()> {
+    let targets = formats
+      .iter()
+      .enumerate()
+      .map(|(i, format)| {
+        gtk::TargetEntry::new(&format.mime_type, gtk::TargetFlags::empty(), i as u32)
+      })
+      .collect::<Vec<_>>();
+    let written = clipboard().set_with_data(&targets, move |_, selection, info| {
+      if let Some(format) = formats.get(info as usize) {
+        selection.set(&selection.target(), 8, &format.data);
+      }
+    });
+    if written {
+      Ok(())
+    } else {
+      Err(error("failed to set the clipboard content"))
+    }
+  }
+
+  pub fn read_format(mime_type: String) -> Result<Option<Vec<u8>>> {
+    Ok(
+      clipboard()
+        .wait_for_contents(&gdk::Atom::intern(&mime_type))
+        .filter(|selection| selection.length() >= 0)
+        .map(|selection| selection.data()),
+    )
+  }
+
+  pub fn available_formats() -> Result<Vec<String>> {
+    Ok(
+      clipboard()
+        .wait_for_targets()
+        .unwrap_or_default()
+        .iter()
+        .map(|target| target.name().to_string())
+        .collect(),
+    )
+  }
+
+  pub fn clear() -> Result<()> {
+    clipboard().clear();
+    Ok(())
+  }
+}
+
+#[cfg(any(windows, target_os = "macos"))]
+mod imp {
+  use super::*;
+
+  pub const NATIVE_CHANGE_EVENTS: bool = false;
+
+  pub use native::change_count;
+
+  fn clipboard() -> Result<arboard::Clipboard> {
+    arboard::Clipboard::new().map_err(error)
+  }
+
+  fn optional<T>(result: std::result::Result<T, arboard::Error>) -> Result<Option<T>> {
+    match result {
+      Ok(value) => Ok(Some(value)),
+      Err(arboard::Error::ContentNotAvailable) => Ok(None),
+      Err(e) => Err(error(e)),
+    }
+  }
+
+  pub fn connect_change_listener(_listener: ClipboardChangeListener) {}
+
+  pub fn write_image(image: ClipboardImage) -> Result<()> {
+    check_image_size(&image)?;
+    clipboard()?
+      .set_image(arboard::ImageData {
+        width: image.width as usize,
+        height: image.height as usize,
+        bytes: image.rgba.into(),
+      })
+      .map_err(error)
+  }
+
+  pub fn read_image() -> Result<Option<ClipboardImage>> {
+    optional(clipboard()?.get_image()).map(|image| {
+      image.map(|image| ClipboardImage {
+        width: image.width as u32,
+        height: image.height as u32,
+        rgba: image.bytes.into_owned(),
+      })
+    })
+  }
+
+  pub fn write_html(html: String, alt_text: Option<String>) -> Result<()> {
+    clipboard()?.set_html(html, alt_text).map_err(error)
+  }
+
+  pub fn read_html() -> Result<Option<String>> {
+    // arboard can't tell a missing HTML payload from a read failure on Windows
+    if !native::available_formats()?
+      .iter()
+      .any(|format| format == "text/html")
+    {
+      return Ok(None);
+    }
+    optional(clipboard()?.get().html())
+  }
+
+  pub fn write_files(paths: Vec<PathBuf>) -> Result<()> {
+    clipboard()?.set().file_list(&paths).map_err(error)
+  }
+
+  pub fn read_files() -> Result<Option<Vec<PathBuf>>> {
+    optional(clipboard()?.get().file_list()).map(|paths| paths.filter(|paths| !paths.is_empty()))
+  }
+
+  pub fn write_formats(_formats: Vec<ClipboardFormat>) -> Result<()> {
+    unsupported()
+  }
+
+  pub fn read_format(mime_type: String) -> Result<Option<Vec<u8>>> {
+    // these payloads are stored in platform specific encodings, so they are converted like on Linux
+    match mime_type.as_str() {
+      "text/plain" => optional(clipboard()?.get_text()).map(|text| text.map(String::into_bytes)),
+      "text/html" => read_html().map(|html| html.map(String::into_bytes)),
+      "text/uri-list" => read_files().map(|paths| {
+        paths.map(|paths| {
+          paths
+            .into_iter()
+            .filter_map(|path| wry::webview::Url::from_file_path(path).ok())
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .join("\r\n")
+            .into_bytes()
+        })
+      }),
+      mime_type => native::read_format(mime_type),
+    }
+  }
+
+  pub fn available_formats() -> Result<Vec<String>> {
+    native::available_formats()
+  }
+
+  pub fn clear() -> Result<()> {
+    clipboard()?.clear().map_err(error)
+  }
+
+  #[cfg(windows)]
+  mod native {
+    use super::super::{error, Result};
+    use windows::{
+      core::HSTRING,
+      Win32::{
+        Foundation::HWND,
+        System::{
+          DataExchange::{
+            CloseClipboard, EnumClipboardFormats, GetClipboardData, GetClipboardFormatNameW,
+            GetClipboardSequenceNumber, IsClipboardFormatAvailable, OpenClipboard,
+            RegisterClipboardFormatW,
+          },
+          Memory::{GlobalLock, GlobalSize, GlobalUnlock},
+          SystemServices::{CF_HDROP, CF_UNICODETEXT},
+        },
+      },
+    };
+
+    /// Keeps the clipboard open until dropped.
+    struct OpenClipboardGuard;
+
+    impl OpenClipboardGuard {
+      fn open() -> Result<Self> {
+        // other apps keep the clipboard open while they read or write it
+        for _ in 0..10 {
+          if unsafe { OpenClipboard(HWND(0)) }.as_bool() {
+            return Ok(Self);
+          }
+          std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        Err(error(std::io::Error::last_os_error()))
+      }
+    }
+
+    impl Drop for OpenClipboardGuard {
+      fn drop(&mut self) {
+        unsafe { CloseClipboard() };
+      }
+    }
+
+    pub fn change_count() -> Option<u64> {
+      // zero means the sequence number is not available to this process
+      match unsafe { GetClipboardSequenceNumber() } {
+        0 => None,
+        count => Some(count.into()),
+      }
+    }
+
+    fn mime_type(format: u32) -> Option<String> {
+      if format == CF_UNICODETEXT.0 {
+        return Some("text/plain".into());
+      }
+      if format == CF_HDROP.0 {
+        return Some("text/uri-list".into());
+      }
+      // only registered formats have a name, the other predefined formats are skipped
+      let mut name = [0u16; 256];
+      let len = unsafe { GetClipboardFormatNameW(format, &mut name) };
+      if len <= 0 {
+        return None;
+      }
+      let name = String::from_utf16_lossy(&name[..len as usize]);
+      Some(match name.as_str() {
+        "HTML Format" => "text/html".into(),
+        "PNG" => "image/png".into(),
+        _ => name,
+      })
+    }
+
+    pub fn available_formats() -> Result<Vec<String>> {
+      let _clipboard = OpenClipboardGuard::open()?;
+      let mut formats = Vec::new();
+      let mut format = 0;
+      loop {
+        format = unsafe { EnumClipboardFormats(format) };
+        if format == 0 {
+          break;
+        }
+        if let Some(mime_type) = mime_type(format) {
+          if !formats.contains(&mime_type) {
+            formats.push(mime_type);
+          }
+        }
+      }
+      Ok(formats)
+    }
+
+    pub fn read_format(mime_type: &str) -> Result<Option<Vec<u8>>> {
+      let name = match mime_type {
+        "image/png" => "PNG",
+        mime_type => mime_type,
+      };
+      let format = unsafe { RegisterClipboardFormatW(&HSTRING::from(name)) };
+      if format == 0 {
+        return Err(error(std::io::Error::last_os_error()));
+      }
+      let _clipboard = OpenClipboardGuard::open()?;
+      if !unsafe { IsClipboardFormatAvailable(format) }.as_bool() {
+        return Ok(None);
+      }
+      let handle = unsafe { GetClipboardData(format) }.map_err(error)?;
+      unsafe {
+        let data = GlobalLock(handle.0);
+        if data.is_null() {
+          return Err(error(std::io::Error::last_os_error()));
+        }
+        let bytes = std::slice::from_raw_parts(data as *const u8, GlobalSize(handle.0)).to_vec();
+        GlobalUnlock(handle.0);
+        Ok(Some(bytes))
+      }
+    }
+  }
+
+  #[cfg(target_os = "macos")]
+  mod native {
+    use super::super::Result;
+    use cocoa::{
+      base::{id, nil},
+      foundation::{NSArray, NSString},
+    };
+    use objc::*;
+    use std::ffi::CStr;
+
+    fn pasteboard() -> id {
+      unsafe { msg_send![class!(NSPasteboard), generalPasteboard] }
+    }
+
+    pub fn change_count() -> Option<u64> {
+      let count: isize = unsafe { msg_send![pasteboard(), changeCount] };
+      Some(count as u64)
+    }
+
+    fn mime_type(uti: id) -> Option<String> {
+      unsafe {
+        let uti_str = CStr::from_ptr(uti.UTF8String()).to_string_lossy();
+        match uti_str.as_ref() {
+          "public.utf8-plain-text" => return Some("text/plain".into()),
+          "public.file-url" => return Some("text/uri-list".into()),
+          _ => (),
+        }
+        let mime_type = UTTypeCopyPreferredTagWithClass(uti, kUTTagClassMIMEType);
+        if mime_type == nil {
+          return None;
+        }
+        let name = CStr::from_ptr(mime_type.UTF8String())
+          .to_string_lossy()
+          .into_owned();
+        CFRelease(mime_type);
+        Some(name)
+      }
+    }
+
+    pub fn available_formats() -> Result<Vec<String>> {
+      let mut formats = Vec::new();
+      unsafe {
+        let types: id = msg_send![pasteboard(), types];
+        if types == nil {
+          return Ok(formats);
+        }
+        for i in 0..types.count() {
+          if let Some(mime_type) = mime_type(types.objectAtIndex(i)) {
+            if !formats.contains(&mime_type) {
+              formats.push(mime_type);
+            }
+          }
+        }
+      }
+      Ok(formats)
+    }
+
+    pub fn read_format(mime_type: &str) -> Result<Option<Vec<u8>>> {
+      unsafe {
+        let mime_type = NSString::alloc(nil).init_str(mime_type);
+        let uti = UTTypeCreatePreferredIdentifierForTag(kUTTagClassMIMEType, mime_type, nil);
+        let _: () = msg_send![mime_type, release];
+        if uti == nil {
+          return Ok(None);
+        }
+        let data: id = msg_send![pasteboard(), dataForType: uti];
+        CFRelease(uti);
+        if data == nil {
+          return Ok(None);
+        }
+        let bytes: *const u8 = msg_send![data, bytes];
+        let len: usize = msg_send![data, length];
+        if len == 0 {
+          return Ok(Some(Vec::new()));
+        }
+        Ok(Some(std::slice::from_raw_parts(bytes, len).to_vec()))
+      }
+    }
+
+    #[link(name = "CoreServices", kind = "framework")]
+    extern "C" {
+      static kUTTagClassMIMEType: id;
+      fn UTTypeCreatePreferredIdentifierForTag(tag_class: id, tag: id, conforming_to: id) -> id;
+      fn UTTypeCopyPreferredTagWithClass(uti: id, tag_class: id) -> id;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+      fn CFRelease(cf: id);
+    }
+  }
+}
+
+#[cfg(not(any(
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd",
+  windows,
+  target_os = "macos"
+)))]
+mod imp {
+  use super::*;
+
+  pub const NATIVE_CHANGE_EVENTS: bool = false;
+
+  pub fn change_count() -> Option<u64> {
+    None
+  }
+
+  pub fn connect_change_listener(_listener: ClipboardChangeListener) {}
+
+  pub fn write_image(image: ClipboardImage) -> Result<()> {
+    check_image_size(&image)?;
+    unsupported()
+  }
+
+  pub fn read_image() -> Result<Option<ClipboardImage>> {
+    unsupported()
+  }
+
+  pub fn write_html(_html: String, _alt_text: Option<String>) -> Result<()> {
+    unsupported()
+  }
+
+  pub fn read_html() -> Result<Option<String>> {
+    unsupported()
+  }
+
+  pub fn write_files(_paths: Vec<PathBuf>) -> Result<()> {
+    unsupported()
+  }
+
+  pub fn read_files() -> Result<Option<Vec<PathBuf>>> {
+    unsupported()
+  }
+
+  pub fn write_formats(_formats: Vec<ClipboardFormat>) -> Result<()> {
+    unsupported()
+  }
+
+  pub fn read_format(_mime_type: String) -> Result<Option<Vec<u8>>> {
+    unsupported()
+  }
+
+  pub fn available_formats() -> Result<Vec<String>> {
+    unsupported()
+  }
+
+  pub fn clear() -> Result<()> {
+    unsupported()
   }
 }
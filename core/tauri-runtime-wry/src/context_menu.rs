@@ -0,0 +1,392 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Popup context menus. tao only supports context menus on the system tray,
+//! so they are built with the native toolkit directly.
+
+use tauri_runtime::{
+  menu::{ContextMenu, MenuHash},
+  window::{dpi::Position, MenuEvent},
+};
+use wry::application::window::Window;
+
+use crate::WindowMenuEventListeners;
+
+// context menus are not implemented on mobile
+#[allow(dead_code)]
+fn emit(listeners: &WindowMenuEventListeners, menu_item_id: MenuHash) {
+  let event = MenuEvent { menu_item_id };
+  for handler in listeners.lock().unwrap().values() {
+    handler(&event);
+  }
+}
+
+#[cfg(any(
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+mod imp {
+  use super::*;
+  use gtk::{gdk, prelude::*};
+  use tauri_runtime::{
+    menu::{MenuEntry, MenuItem},
+    window::dpi::LogicalPosition,
+  };
+  use wry::application::platform::unix::WindowExtUnix;
+
+  fn to_gtk_menu(items: Vec<MenuEntry>, listeners: &WindowMenuEventListeners) -> gtk::Menu {
+    let menu = gtk::Menu::new();
+    for entry in items {
+      match entry {
+        MenuEntry::CustomItem(c) => {
+          let item = if c.selected {
+            let item = gtk::CheckMenuItem::with_label(&c.title);
+            item.set_active(true);
+            item.upcast::<gtk::MenuItem>()
+          } else {
+            gtk::MenuItem::with_label(&c.title)
+          };
+          item.set_sensitive(c.enabled);
+          let listeners = listeners.clone();
+          let menu_item_id = c.id;
+          item.connect_activate(move |_| emit(&listeners, menu_item_id));
+          menu.append(&item);
+        }
+        MenuEntry::NativeItem(MenuItem::Separator) => {
+          menu.append(&gtk::SeparatorMenuItem::new());
+        }
+        // other native items are implemented by tao for the menu bar only
+        MenuEntry::NativeItem(_) => {}
+        MenuEntry::Submenu(submenu) => {
+          let item = gtk::MenuItem::with_label(&submenu.title);
+          item.set_sensitive(submenu.enabled);
+          item.set_submenu(Some(&to_gtk_menu(submenu.inner.items, listeners)));
+          menu.append(&item);
+        }
+      }
+    }
+    menu
+  }
+
+  pub fn popup(
+    window: &Window,
+    menu: ContextMenu,
+    position: Option<Position>,
+    listeners: &WindowMenuEventListeners,
+    on_close: Box<dyn FnOnce() + Send>,
+  ) {
+    let gtk_window = window.gtk_window();
+    let menu = to_gtk_menu(menu.items, listeners);
+    menu.set_attach_widget(Some(gtk_window));
+    let on_close = std::cell::Cell::new(Some(on_close));
+    menu.connect_deactivate(move |_| {
+      if let Some(on_close) = on_close.take() {
+        // the menu is deactivated before the clicked item is activated
+        gtk::glib::idle_add_local_once(on_close);
+      }
+    });
+    menu.show_all();
+    match (position, gtk_window.window()) {
+      (Some(position), Some(gdk_window)) => {
+        // gtk works with logical coordinates
+        let position: LogicalPosition<i32> = position.to_logical(window.scale_factor());
+        menu.popup_at_rect(
+          &gdk_window,
+          &gdk::Rectangle::new(position.x, position.y, 1, 1),
+          gdk::Gravity::NorthWest,
+          gdk::Gravity::NorthWest,
+          None,
+        );
+      }
+      _ => menu.popup_at_pointer(None),
+    }
+  }
+}
+
+#[cfg(windows)]
+mod imp {
+  use super::*;
+  use tauri_runtime::{
+    menu::{MenuEntry, MenuItem},
+    window::dpi::PhysicalPosition,
+  };
+  use windows::{
+    core::PCWSTR,
+    Win32::{
+      Foundation::{HWND, POINT},
+      Graphics::Gdi::ClientToScreen,
+      UI::WindowsAndMessaging::{
+        AppendMenuW, CreatePopupMenu, DestroyMenu, GetCursorPos, SetForegroundWindow,
+        TrackPopupMenu, HMENU, MF_CHECKED, MF_GRAYED, MF_POPUP, MF_SEPARATOR, MF_STRING,
+        TPM_LEFTALIGN, TPM_RETURNCMD, TPM_TOPALIGN,
+      },
+    },
+  };
+  use wry::application::platform::windows::WindowExtWindows;
+
+  fn encode_wide(string: &str) -> Vec<u16> {
+    string.encode_utf16().chain(std::iter::once(0)).collect()
+  }
+
+  // the command of an item is its index in `commands` plus one, since 0 means the menu was dismissed
+  unsafe fn append_items(menu: HMENU, items: Vec<MenuEntry>, commands: &mut Vec<MenuHash>) {
+    for entry in items {
+      match entry {
+        MenuEntry::CustomItem(c) => {
+          commands.push(c.id);
+          let mut flags = MF_STRING;
+          if !c.enabled {
+            flags |= MF_GRAYED;
+          }
+          if c.selected {
+            flags |= MF_CHECKED;
+          }
+          let title = encode_wide(&c.title);
+          AppendMenuW(menu, flags, commands.len(), PCWSTR(title.as_ptr()));
+        }
+        MenuEntry::NativeItem(MenuItem::Separator) => {
+          AppendMenuW(menu, MF_SEPARATOR, 0, PCWSTR::null());
+        }
+        // other native items are implemented by tao for the menu bar only
+        MenuEntry::NativeItem(_) => {}
+        MenuEntry::Submenu(submenu) => {
+          if let Ok(popup) = CreatePopupMenu() {
+            append_items(popup, submenu.inner.items, commands);
+            let flags = if submenu.enabled {
+              MF_POPUP
+            } else {
+              MF_POPUP | MF_GRAYED
+            };
+            let title = encode_wide(&submenu.title);
+            AppendMenuW(menu, flags, popup.0 as usize, PCWSTR(title.as_ptr()));
+          }
+        }
+      }
+    }
+  }
+
+  pub fn popup(
+    window: &Window,
+    menu: ContextMenu,
+    position: Option<Position>,
+    listeners: &WindowMenuEventListeners,
+    on_close: Box<dyn FnOnce() + Send>,
+  ) {
+    let hwnd = HWND(window.hwnd() as _);
+    unsafe {
+      let hmenu = match CreatePopupMenu() {
+        Ok(hmenu) => hmenu,
+        Err(_) => {
+          on_close();
+          return;
+        }
+      };
+      let mut commands = Vec::new();
+      append_items(hmenu, menu.items, &mut commands);
+
+      let mut point = POINT::default();
+      match position {
+        Some(position) => {
+          let position: PhysicalPosition<i32> = position.to_physical(window.scale_factor());
+          point.x = position.x;
+          point.y = position.y;
+          ClientToScreen(hwnd, &mut point);
+        }
+        None => {
+          GetCursorPos(&mut point);
+        }
+      }
+
+      // the menu is only dismissed by clicking outside of it when the window is in the foreground
+      SetForegroundWindow(hwnd);
+      // blocks until the menu is closed and returns the command of the clicked item
+      let command = TrackPopupMenu(
+        hmenu,
+        TPM_LEFTALIGN | TPM_TOPALIGN | TPM_RETURNCMD,
+        point.x,
+        point.y,
+        0,
+        hwnd,
+        std::ptr::null(),
+      );
+      // destroys the submenus too
+      DestroyMenu(hmenu);
+
+      if let Some(menu_item_id) = (command.0 as usize)
+        .checked_sub(1)
+        .and_then(|index| commands.get(index))
+      {
+        emit(listeners, *menu_item_id);
+      }
+      on_close();
+    }
+  }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+  use super::*;
+  use cocoa::{
+    base::{id, nil, BOOL, NO, YES},
+    foundation::{NSPoint, NSRect, NSString},
+  };
+  use objc::{
+    declare::ClassDecl,
+    runtime::{Class, Object, Sel},
+    *,
+  };
+  use std::sync::Once;
+  use tauri_runtime::{
+    menu::{MenuEntry, MenuItem},
+    window::dpi::LogicalPosition,
+  };
+  use wry::application::platform::macos::WindowExtMacOS;
+
+  // the menu is modal, so the tag of the clicked item is stored and read when it is closed
+  extern "C" fn item_clicked(this: &mut Object, _: Sel, item: id) {
+    unsafe {
+      let tag: isize = msg_send![item, tag];
+      this.set_ivar::<isize>("clicked", tag);
+    }
+  }
+
+  fn target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+      let mut decl = ClassDecl::new("TauriContextMenuTarget", class!(NSObject)).unwrap();
+      decl.add_ivar::<isize>("clicked");
+      unsafe {
+        decl.add_method(
+          sel!(itemClicked:),
+          item_clicked as extern "C" fn(&mut Object, Sel, id),
+        );
+      }
+      decl.register();
+    });
+    Class::get("TauriContextMenuTarget").unwrap()
+  }
+
+  unsafe fn ns_string(string: &str) -> id {
+    let string: id = NSString::alloc(nil).init_str(string);
+    msg_send![string, autorelease]
+  }
+
+  // the tag of an item is its index in `commands` plus one, since 0 means the menu was dismissed
+  unsafe fn to_ns_menu(items: Vec<MenuEntry>, target: id, commands: &mut Vec<MenuHash>) -> id {
+    let menu: id = msg_send![class!(NSMenu), new];
+    let menu: id = msg_send![menu, autorelease];
+    let _: () = msg_send![menu, setAutoenablesItems: NO];
+    for entry in items {
+      match entry {
+        MenuEntry::CustomItem(c) => {
+          commands.push(c.id);
+          let item: id = msg_send![class!(NSMenuItem), alloc];
+          let item: id = msg_send![item, initWithTitle: ns_string(&c.title) action: sel!(itemClicked:) keyEquivalent: ns_string("")];
+          let _: () = msg_send![item, setTarget: target];
+          let _: () = msg_send![item, setTag: commands.len() as isize];
+          let _: () = msg_send![item, setEnabled: if c.enabled { YES } else { NO }];
+          let _: () = msg_send![item, setState: if c.selected { 1isize } else { 0isize }];
+          let _: () = msg_send![menu, addItem: item];
+          let _: () = msg_send![item, release];
+        }
+        MenuEntry::NativeItem(MenuItem::Separator) => {
+          let item: id = msg_send![class!(NSMenuItem), separatorItem];
+          let _: () = msg_send![menu, addItem: item];
+        }
+        // other native items are implemented by tao for the menu bar only
+        MenuEntry::NativeItem(_) => {}
+        MenuEntry::Submenu(submenu) => {
+          let title = ns_string(&submenu.title);
+          let item: id = msg_send![class!(NSMenuItem), alloc];
+          let item: id =
+            msg_send![item, initWithTitle: title action: nil keyEquivalent: ns_string("")];
+          let inner = to_ns_menu(submenu.inner.items, target, commands);
+          let _: () = msg_send![inner, setTitle: title];
+          let _: () = msg_send![item, setSubmenu: inner];
+          let _: () = msg_send![item, setEnabled: if submenu.enabled { YES } else { NO }];
+          let _: () = msg_send![menu, addItem: item];
+          let _: () = msg_send![item, release];
+        }
+      }
+    }
+    menu
+  }
+
+  pub fn popup(
+    window: &Window,
+    menu: ContextMenu,
+    position: Option<Position>,
+    listeners: &WindowMenuEventListeners,
+    on_close: Box<dyn FnOnce() + Send>,
+  ) {
+    unsafe {
+      let ns_window = window.ns_window() as id;
+      // wry replaces the content view of the window with the webview
+      let view: id = msg_send![ns_window, contentView];
+      let target: id = msg_send![target_class(), new];
+      (*target).set_ivar::<isize>("clicked", 0);
+      let mut commands = Vec::new();
+      let ns_menu = to_ns_menu(menu.items, target, &mut commands);
+
+      let location = match position {
+        Some(position) => {
+          let position: LogicalPosition<f64> = position.to_logical(window.scale_factor());
+          let flipped: BOOL = msg_send![view, isFlipped];
+          if flipped == YES {
+            NSPoint::new(position.x, position.y)
+          } else {
+            let frame: NSRect = msg_send![view, frame];
+            NSPoint::new(position.x, frame.size.height - position.y)
+          }
+        }
+        None => {
+          let location: NSPoint = msg_send![ns_window, mouseLocationOutsideOfEventStream];
+          msg_send![view, convertPoint: location fromView: nil]
+        }
+      };
+
+      // blocks until the menu is closed
+      let _: BOOL =
+        msg_send![ns_menu, popUpMenuPositioningItem: nil atLocation: location inView: view];
+
+      let clicked = *(*target).get_ivar::<isize>("clicked");
+      let _: () = msg_send![target, release];
+      if let Some(menu_item_id) = (clicked as usize)
+        .checked_sub(1)
+        .and_then(|index| commands.get(index))
+      {
+        emit(listeners, *menu_item_id);
+      }
+      on_close();
+    }
+  }
+}
+
+#[cfg(not(any(
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd",
+  windows,
+  target_os = "macos"
+)))]
+mod imp {
+  use super::*;
+
+  pub fn popup(
+    _window: &Window,
+    _menu: ContextMenu,
+    _position: Option<Position>,
+    _listeners: &WindowMenuEventListeners,
+    on_close: Box<dyn FnOnce() + Send>,
+  ) {
+    on_close();
+  }
+}
+
+pub use imp::popup;
@@ -7,15 +7,18 @@
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle, RawDisplayHandle};
 use tauri_runtime::{
   http::{header::CONTENT_TYPE, Request as HttpRequest, RequestParts, Response as HttpResponse},
-  menu::{AboutMetadata, CustomMenuItem, Menu, MenuEntry, MenuHash, MenuId, MenuItem, MenuUpdate},
+  menu::{
+    AboutMetadata, ContextMenu, CustomMenuItem, Menu, MenuEntry, MenuHash, MenuId, MenuItem,
+    MenuStructureUpdate, MenuUpdate,
+  },
   monitor::Monitor,
   webview::{WebviewIpcHandler, WindowBuilder, WindowBuilderBase},
   window::{
     dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Position, Size},
     CursorIcon, DetachedWindow, FileDropEvent, JsEventListenerKey, PendingWindow, WindowEvent,
   },
-  DeviceEventFilter, Dispatch, Error, EventLoopProxy, ExitRequestedEventAction, Icon, Result,
-  RunEvent, RunIteration, Runtime, RuntimeHandle, UserAttentionType, UserEvent,
+  DeviceEventFilter, Dispatch, Error, EventLoopProxy, ExitRequestedEventAction, FindInPageOptions,
+  Icon, Result, RunEvent, RunIteration, Runtime, RuntimeHandle, UserAttentionType, UserEvent,
 };
 
 use tauri_runtime::window::MenuEvent;
@@ -103,6 +106,8 @@ type FileDropHandler = dyn Fn(&Window, WryFileDropEvent) -> bool + 'static;
 #[cfg(all(desktop, feature = "system-tray"))]
 pub use tauri_runtime::TrayId;
 
+mod context_menu;
+mod url_changed;
 #[cfg(desktop)]
 mod webview;
 #[cfg(desktop)]
@@ -1103,6 +1108,12 @@ pub enum WindowMessage {
   SetIgnoreCursorEvents(bool),
   DragWindow,
   UpdateMenuItem(u16, MenuUpdate),
+  SetMenu(Menu),
+  UpdateMenuStructure(
+    MenuStructureUpdate<MenuEntry>,
+    Sender<Result<Vec<MenuHash>>>,
+  ),
+  PopupMenu(ContextMenu, Option<Position>, Box<dyn FnOnce() + Send>),
   RequestRedraw,
 }
 
@@ -1112,6 +1123,8 @@ pub enum WebviewMessage {
   #[allow(dead_code)]
   WebviewEvent(WebviewEvent),
   Print,
+  Zoom(Sender<f64>),
+  SetZoom(f64),
 }
 
 #[allow(dead_code)]
@@ -1125,6 +1138,10 @@ pub enum WebviewEvent {
 pub enum TrayMessage {
   UpdateItem(u16, MenuUpdate),
   UpdateMenu(SystemTrayMenu),
+  UpdateMenuStructure(
+    MenuStructureUpdate<SystemTrayMenuEntry>,
+    Sender<Result<Vec<MenuHash>>>,
+  ),
   UpdateIcon(Icon),
   #[cfg(target_os = "macos")]
   UpdateIconAsTemplate(bool),
@@ -1251,6 +1268,15 @@ impl<T: UserEvent> Dispatch<T> for WryDispatcher<T> {
     window_getter!(self, WindowMessage::Url)
   }
 
+  fn zoom(&self) -> Result<f64> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Webview(self.window_id, WebviewMessage::Zoom(tx))
+    )
+  }
+
   fn scale_factor(&self) -> Result<f64> {
     window_getter!(self, WindowMessage::ScaleFactor)
   }
@@ -1358,6 +1384,59 @@ impl<T: UserEvent> Dispatch<T> for WryDispatcher<T> {
     )
   }
 
+  // wry does not expose a navigation API, so we rely on the DOM location and history APIs
+  fn navigate(&self, url: Url) -> Result<()> {
+    self.eval_script(format!(
+      "window.location.href = '{}'",
+      url.as_str().replace('\\', "\\\\").replace('\'', "\\'")
+    ))
+  }
+
+  fn reload(&self) -> Result<()> {
+    self.eval_script("window.location.reload()")
+  }
+
+  fn go_back(&self) -> Result<()> {
+    self.eval_script("window.history.back()")
+  }
+
+  fn go_forward(&self) -> Result<()> {
+    self.eval_script("window.history.forward()")
+  }
+
+  fn set_zoom(&self, scale_factor: f64) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(self.window_id, WebviewMessage::SetZoom(scale_factor)),
+    )
+  }
+
+  // the search state is kept on the page so the next and previous matches can be selected later
+  fn find_in_page(&self, text: String, options: FindInPageOptions) -> Result<()> {
+    let FindInPageOptions {
+      case_sensitive,
+      wrap_around,
+    } = options;
+    let text = serde_json::to_string(&text)?;
+    self.eval_script(format!(
+      "(function () {{ window.__TAURI_FIND__ = {{ text: {text}, caseSensitive: {case_sensitive}, wrapAround: {wrap_around} }}; window.getSelection().removeAllRanges(); window.find({text}, {case_sensitive}, false, {wrap_around}) }})()"
+    ))
+  }
+
+  fn find_next(&self) -> Result<()> {
+    self.eval_script(find_again_script(false))
+  }
+
+  fn find_previous(&self) -> Result<()> {
+    self.eval_script(find_again_script(true))
+  }
+
+  fn clear_find(&self) -> Result<()> {
+    self.eval_script(
+      "(function () { window.__TAURI_FIND__ = null; window.getSelection().removeAllRanges() })()",
+    )
+  }
+
   fn request_user_attention(&self, request_type: Option<UserAttentionType>) -> Result<()> {
     send_user_message(
       &self.context,
@@ -1600,11 +1679,39 @@ impl<T: UserEvent> Dispatch<T> for WryDispatcher<T> {
       Message::Window(self.window_id, WindowMessage::UpdateMenuItem(id, update)),
     )
   }
+
+  fn set_menu(&self, menu: Menu) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Window(self.window_id, WindowMessage::SetMenu(menu)),
+    )
+  }
+
+  fn update_menu_structure(&self, update: MenuStructureUpdate<MenuEntry>) -> Result<Vec<MenuHash>> {
+    window_getter!(self, |tx| WindowMessage::UpdateMenuStructure(update, tx))?
+  }
+
+  fn popup_menu<F: FnOnce() + Send + 'static>(
+    &self,
+    menu: ContextMenu,
+    position: Option<Position>,
+    on_close: F,
+  ) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Window(
+        self.window_id,
+        WindowMessage::PopupMenu(menu, position, Box::new(on_close)),
+      ),
+    )
+  }
 }
 
 #[derive(Clone)]
 enum WindowHandle {
   Webview {
+    // declared before the webview so it is dropped first
+    _url_changed: Arc<url_changed::Listener>,
     inner: Arc<WebView>,
     context_store: WebContextStore,
     // the key of the WebContext if it's not shared
@@ -1619,6 +1726,7 @@ impl Drop for WindowHandle {
       inner,
       context_store,
       context_key,
+      ..
     } = self
     {
       if Arc::get_mut(inner).is_some() {
@@ -1659,8 +1767,12 @@ pub struct WindowWrapper {
   label: String,
   inner: Option<WindowHandle>,
   menu_items: Option<HashMap<u16, WryCustomMenuItem>>,
+  // the current window menu, used to rebuild it on structure updates
+  menu: Option<Menu>,
   window_event_listeners: WindowEventListeners,
   menu_event_listeners: WindowMenuEventListeners,
+  // wry does not expose a getter for the webview zoom level
+  zoom: f64,
 }
 
 impl fmt::Debug for WindowWrapper {
@@ -1909,7 +2021,11 @@ impl<T: UserEvent> Wry<T> {
     #[allow(clippy::redundant_clone)]
     let clipboard_manager_handle = ClipboardManagerWrapper {
       context: context.clone(),
+      change_listener: Default::default(),
+      poll_watcher: Default::default(),
     };
+    #[cfg(feature = "clipboard")]
+    connect_change_listener(clipboard_manager_handle.change_listener.clone());
 
     Ok(Self {
       context,
@@ -2024,7 +2140,7 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
     if let Some(l) = system_tray.on_event.take() {
       listeners.push(Arc::new(l));
     }
-    let (tray, items) = create_tray(WryTrayId(id), system_tray, &self.event_loop)?;
+    let (tray, items, menu) = create_tray(WryTrayId(id), system_tray, &self.event_loop)?;
     self
       .context
       .main_thread
@@ -2038,6 +2154,7 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
           tray: Arc::new(Mutex::new(Some(tray))),
           listeners: Arc::new(Mutex::new(listeners)),
           items: Arc::new(Mutex::new(items)),
+          menu: Arc::new(Mutex::new(menu)),
         },
       );
 
@@ -2289,9 +2406,16 @@ fn handle_user_message<T: UserEvent>(
     },
     Message::Window(id, window_message) => {
       if let WindowMessage::UpdateMenuItem(item_id, update) = window_message {
-        if let Some(menu_items) = windows.borrow_mut().get_mut(&id).map(|w| &mut w.menu_items) {
-          if let Some(menu_items) = menu_items.as_mut() {
-            let item = menu_items.get_mut(&item_id).expect("menu item not found");
+        if let Some(window) = windows.borrow_mut().get_mut(&id) {
+          if let Some(menu) = window.menu.as_mut() {
+            menu.update_item(item_id, &update);
+          }
+          // the item might have been removed by a structure update
+          if let Some(item) = window
+            .menu_items
+            .as_mut()
+            .and_then(|items| items.get_mut(&item_id))
+          {
             match update {
               MenuUpdate::SetEnabled(enabled) => item.set_enabled(enabled),
               MenuUpdate::SetTitle(title) => item.set_title(&title),
@@ -2303,6 +2427,29 @@ fn handle_user_message<T: UserEvent>(
             }
           }
         }
+      } else if let WindowMessage::SetMenu(menu) = window_message {
+        if let Some(window) = windows.borrow_mut().get_mut(&id) {
+          if let Some(handle) = window.inner.as_ref() {
+            let mut menu_items = HashMap::new();
+            handle.set_menu(Some(to_wry_menu(&mut menu_items, menu.clone())));
+            window.menu_items.replace(menu_items);
+            window.menu.replace(menu);
+          }
+        }
+      } else if let WindowMessage::UpdateMenuStructure(update, tx) = window_message {
+        if let Some(window) = windows.borrow_mut().get_mut(&id) {
+          let removed = match (window.menu.as_mut(), window.inner.as_ref()) {
+            (Some(menu), Some(handle)) => menu.update_structure(update).map(|removed| {
+              // tao menus can't be modified in place, so the whole menu bar is replaced
+              let mut menu_items = HashMap::new();
+              handle.set_menu(Some(to_wry_menu(&mut menu_items, menu.clone())));
+              window.menu_items.replace(menu_items);
+              removed
+            }),
+            _ => None,
+          };
+          tx.send(removed.ok_or(Error::MenuEntryNotFound)).unwrap();
+        }
       } else {
         let w = windows.borrow().get(&id).map(|w| {
           (
@@ -2508,6 +2655,15 @@ fn handle_user_message<T: UserEvent>(
             WindowMessage::UpdateMenuItem(_id, _update) => {
               // already handled
             }
+            WindowMessage::SetMenu(_menu) => {
+              // already handled
+            }
+            WindowMessage::UpdateMenuStructure(_update, _tx) => {
+              // already handled
+            }
+            WindowMessage::PopupMenu(menu, position, on_close) => {
+              context_menu::popup(&window, menu, position, &menu_event_listeners, on_close);
+            }
             WindowMessage::RequestRedraw => {
               window.request_redraw();
             }
@@ -2532,6 +2688,18 @@ fn handle_user_message<T: UserEvent>(
           let _ = webview.print();
         }
       }
+      WebviewMessage::Zoom(tx) => {
+        tx.send(windows.borrow().get(&id).map(|w| w.zoom).unwrap_or(1.0))
+          .unwrap();
+      }
+      WebviewMessage::SetZoom(scale_factor) => {
+        if let Some(window) = windows.borrow_mut().get_mut(&id) {
+          if let Some(WindowHandle::Webview { inner: webview, .. }) = &window.inner {
+            webview.zoom(scale_factor);
+            window.zoom = scale_factor;
+          }
+        }
+      }
       WebviewMessage::WebviewEvent(event) => {
         let window_event_listeners = windows
           .borrow()
@@ -2569,8 +2737,10 @@ fn handle_user_message<T: UserEvent>(
             label,
             inner: Some(WindowHandle::Window(w.clone())),
             menu_items: Default::default(),
+            menu: Default::default(),
             window_event_listeners: Default::default(),
             menu_event_listeners: Default::default(),
+            zoom: 1.0,
           },
         );
         sender.send(Ok(Arc::downgrade(&w))).unwrap();
@@ -2589,13 +2759,14 @@ fn handle_user_message<T: UserEvent>(
           listeners.push(Arc::new(l));
         }
         match create_tray(WryTrayId(tray_id), tray, event_loop) {
-          Ok((tray, items)) => {
+          Ok((tray, items, menu)) => {
             trays.insert(
               tray_id,
               TrayContext {
                 tray: Arc::new(Mutex::new(Some(tray))),
                 listeners: Arc::new(Mutex::new(listeners)),
                 items: Arc::new(Mutex::new(items)),
+                menu: Arc::new(Mutex::new(menu)),
               },
             );
 
@@ -2609,25 +2780,46 @@ fn handle_user_message<T: UserEvent>(
       } else if let Some(tray_context) = trays.get(&tray_id) {
         match tray_message {
           TrayMessage::UpdateItem(menu_id, update) => {
+            if let Some(menu) = &mut *tray_context.menu.lock().unwrap() {
+              menu.update_item(menu_id, &update);
+            }
             let mut tray = tray_context.items.as_ref().lock().unwrap();
-            let item = tray.get_mut(&menu_id).expect("menu item not found");
-            match update {
-              MenuUpdate::SetEnabled(enabled) => item.set_enabled(enabled),
-              MenuUpdate::SetTitle(title) => item.set_title(&title),
-              MenuUpdate::SetSelected(selected) => item.set_selected(selected),
-              #[cfg(target_os = "macos")]
-              MenuUpdate::SetNativeImage(image) => {
-                item.set_native_image(NativeImageWrapper::from(image).0)
+            // the item might have been removed by a structure update
+            if let Some(item) = tray.get_mut(&menu_id) {
+              match update {
+                MenuUpdate::SetEnabled(enabled) => item.set_enabled(enabled),
+                MenuUpdate::SetTitle(title) => item.set_title(&title),
+                MenuUpdate::SetSelected(selected) => item.set_selected(selected),
+                #[cfg(target_os = "macos")]
+                MenuUpdate::SetNativeImage(image) => {
+                  item.set_native_image(NativeImageWrapper::from(image).0)
+                }
               }
             }
           }
           TrayMessage::UpdateMenu(menu) => {
             if let Some(tray) = &mut *tray_context.tray.lock().unwrap() {
               let mut items = HashMap::new();
-              tray.set_menu(&to_wry_context_menu(&mut items, menu));
+              tray.set_menu(&to_wry_context_menu(&mut items, menu.clone()));
               *tray_context.items.lock().unwrap() = items;
+              tray_context.menu.lock().unwrap().replace(menu);
             }
           }
+          TrayMessage::UpdateMenuStructure(update, tx) => {
+            let removed = match (
+              &mut *tray_context.tray.lock().unwrap(),
+              &mut *tray_context.menu.lock().unwrap(),
+            ) {
+              (Some(tray), Some(menu)) => menu.update_structure(update).map(|removed| {
+                let mut items = HashMap::new();
+                tray.set_menu(&to_wry_context_menu(&mut items, menu.clone()));
+                *tray_context.items.lock().unwrap() = items;
+                removed
+              }),
+              _ => None,
+            };
+            tx.send(removed.ok_or(Error::MenuEntryNotFound)).unwrap();
+          }
           TrayMessage::UpdateIcon(icon) => {
             if let Some(tray) = &mut *tray_context.tray.lock().unwrap() {
               if let Ok(icon) = TrayIcon::try_from(icon) {
@@ -2659,6 +2851,7 @@ fn handle_user_message<T: UserEvent>(
             *tray_context.tray.lock().unwrap() = None;
             tray_context.listeners.lock().unwrap().clear();
             tray_context.items.lock().unwrap().clear();
+            tray_context.menu.lock().unwrap().take();
             tx.send(Ok(())).unwrap();
           }
         }
@@ -3068,6 +3261,7 @@ fn create_webview<T: UserEvent>(
   }
 
   let is_window_transparent = window_builder.inner.window.transparent;
+  let menu = window_builder.menu.clone();
   let menu_items = if let Some(menu) = window_builder.menu {
     let mut menu_items = HashMap::new();
     let menu = to_wry_menu(&mut menu_items, menu);
@@ -3176,6 +3370,8 @@ fn create_webview<T: UserEvent>(
     .build()
     .map_err(|e| Error::CreateWebview(Box::new(e)))?;
 
+  let url_changed = url_changed::listen(&webview, window_event_listeners.clone())?;
+
   #[cfg(windows)]
   {
     let controller = webview.controller();
@@ -3212,6 +3408,7 @@ fn create_webview<T: UserEvent>(
   Ok(WindowWrapper {
     label,
     inner: Some(WindowHandle::Webview {
+      _url_changed: Arc::new(url_changed),
       inner: Arc::new(webview),
       context_store: web_context_store.clone(),
       context_key: if automation_enabled {
@@ -3221,11 +3418,20 @@ fn create_webview<T: UserEvent>(
       },
     }),
     menu_items,
+    menu,
     window_event_listeners,
     menu_event_listeners: Default::default(),
+    zoom: 1.0,
   })
 }
 
+// selects the next or previous match of the search started by `Dispatch::find_in_page`.
+fn find_again_script(backwards: bool) -> String {
+  format!(
+    "(function () {{ const f = window.__TAURI_FIND__; if (f) {{ window.find(f.text, f.caseSensitive, {backwards}, f.wrapAround) }} }})()"
+  )
+}
+
 /// Create a wry ipc handler from a tauri ipc handler.
 fn create_ipc_handler<T: UserEvent>(
   context: Context<T>,
@@ -4,8 +4,8 @@
 
 pub use tauri_runtime::{
   menu::{
-    Menu, MenuEntry, MenuItem, MenuUpdate, Submenu, SystemTrayMenu, SystemTrayMenuEntry,
-    SystemTrayMenuItem, TrayHandle,
+    Menu, MenuEntry, MenuItem, MenuStructureUpdate, MenuUpdate, Submenu, SystemTrayMenu,
+    SystemTrayMenuEntry, SystemTrayMenuItem, TrayHandle,
   },
   Icon, SystemTrayEvent,
 };
@@ -43,18 +43,22 @@ pub type GlobalSystemTrayEventListeners = Arc<Mutex<Vec<Arc<GlobalSystemTrayEven
 pub type SystemTrayEventHandler = Box<dyn Fn(&SystemTrayEvent) + Send>;
 pub type SystemTrayEventListeners = Arc<Mutex<Vec<Arc<SystemTrayEventHandler>>>>;
 pub type SystemTrayItems = Arc<Mutex<HashMap<u16, WryCustomMenuItem>>>;
+pub type SystemTrayMenuState = Arc<Mutex<Option<SystemTrayMenu>>>;
 
 #[derive(Clone, Default)]
 pub struct TrayContext {
   pub tray: Arc<Mutex<Option<WrySystemTray>>>,
   pub listeners: SystemTrayEventListeners,
   pub items: SystemTrayItems,
+  // the current tray menu, used to rebuild it on structure updates
+  pub menu: SystemTrayMenuState,
 }
 
 impl fmt::Debug for TrayContext {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     f.debug_struct("TrayContext")
       .field("items", &self.items)
+      .field("menu", &self.menu)
       .finish()
   }
 }
@@ -89,7 +93,11 @@ pub fn create_tray<T>(
   id: WryTrayId,
   system_tray: SystemTray,
   event_loop: &EventLoopWindowTarget<T>,
-) -> crate::Result<(WrySystemTray, HashMap<u16, WryCustomMenuItem>)> {
+) -> crate::Result<(
+  WrySystemTray,
+  HashMap<u16, WryCustomMenuItem>,
+  Option<SystemTrayMenu>,
+)> {
   let icon = TrayIcon::try_from(system_tray.icon.expect("tray icon not set"))?;
 
   let mut items = HashMap::new();
@@ -99,6 +107,7 @@ pub fn create_tray<T>(
     icon.0,
     system_tray
       .menu
+      .clone()
       .map(|menu| to_wry_context_menu(&mut items, menu)),
   )
   .with_id(id);
@@ -122,7 +131,7 @@ pub fn create_tray<T>(
     .build(event_loop)
     .map_err(|e| Error::SystemTray(Box::new(e)))?;
 
-  Ok((tray, items))
+  Ok((tray, items, system_tray.menu))
 }
 
 #[derive(Debug, Clone)]
@@ -154,6 +163,18 @@ impl<T: UserEvent> TrayHandle for SystemTrayHandle<T> {
       .map_err(|_| Error::FailedToSendMessage)
   }
 
+  fn update_menu_structure(
+    &self,
+    update: MenuStructureUpdate<SystemTrayMenuEntry>,
+  ) -> Result<Vec<MenuHash>> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    send_user_message(
+      &self.context,
+      Message::Tray(self.id, TrayMessage::UpdateMenuStructure(update, tx)),
+    )?;
+    rx.recv().map_err(|_| Error::FailedToReceiveMessage)?
+  }
+
   #[cfg(target_os = "macos")]
   fn set_icon_as_template(&self, is_template: bool) -> tauri_runtime::Result<()> {
     self
@@ -0,0 +1,166 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Emits [`WindowEvent::UrlChanged`] when the URL of the main frame changes.
+//!
+//! The navigation handler can't be used for this: it runs before the navigation is committed,
+//! for cancelled navigations and, on Linux and Windows, for the navigations of iframes.
+
+use tauri_runtime::{window::WindowEvent, Result};
+use wry::webview::{Url, WebView};
+
+use crate::WindowEventListeners;
+
+/// Observes the URL changes of a webview until it is dropped.
+///
+/// It must be dropped before the webview.
+pub struct Listener {
+  #[cfg(target_os = "macos")]
+  webview: cocoa::base::id,
+  #[cfg(target_os = "macos")]
+  observer: cocoa::base::id,
+}
+
+#[cfg(target_os = "macos")]
+impl Drop for Listener {
+  fn drop(&mut self) {
+    use cocoa::{base::nil, foundation::NSString};
+    use objc::*;
+
+    // the observer must be removed before the webview is deallocated
+    unsafe {
+      let key_path = NSString::alloc(nil).init_str("URL");
+      let _: () = msg_send![self.webview, removeObserver: self.observer forKeyPath: key_path];
+      let _: () = msg_send![key_path, release];
+      let _: () = msg_send![self.observer, release];
+    }
+  }
+}
+
+fn emit(listeners: &WindowEventListeners, url: &str) {
+  if let Ok(url) = Url::parse(url) {
+    let event = WindowEvent::UrlChanged(url);
+    let listeners = listeners.lock().unwrap();
+    for listener in listeners.values() {
+      listener(&event);
+    }
+  }
+}
+
+#[cfg(target_os = "linux")]
+pub fn listen(webview: &WebView, listeners: WindowEventListeners) -> Result<Listener> {
+  use webkit2gtk::{LoadEvent, WebViewExt};
+  use wry::webview::WebviewExtUnix;
+
+  webview
+    .webview()
+    .connect_load_changed(move |webview, event| {
+      if let LoadEvent::Committed = event {
+        if let Some(uri) = webview.uri() {
+          emit(&listeners, &uri);
+        }
+      }
+    });
+  Ok(Listener {})
+}
+
+#[cfg(windows)]
+pub fn listen(webview: &WebView, listeners: WindowEventListeners) -> Result<Listener> {
+  use webview2_com::{take_pwstr, SourceChangedEventHandler};
+  use windows::{core::PWSTR, Win32::System::WinRT::EventRegistrationToken};
+  use wry::webview::WebviewExtWindows;
+
+  let mut token = EventRegistrationToken::default();
+  unsafe {
+    webview.controller().CoreWebView2().and_then(|webview| {
+      webview.add_SourceChanged(
+        &SourceChangedEventHandler::create(Box::new(move |webview, _| {
+          if let Some(webview) = webview {
+            let mut uri = PWSTR::null();
+            webview.Source(&mut uri)?;
+            emit(&listeners, &take_pwstr(uri));
+          }
+          Ok(())
+        })),
+        &mut token,
+      )
+    })
+  }
+  .map_err(|e| tauri_runtime::Error::CreateWebview(Box::new(e)))?;
+  Ok(Listener {})
+}
+
+#[cfg(target_os = "macos")]
+pub fn listen(webview: &WebView, listeners: WindowEventListeners) -> Result<Listener> {
+  use cocoa::{
+    base::{id, nil},
+    foundation::NSString,
+  };
+  use objc::{
+    declare::ClassDecl,
+    runtime::{Class, Object, Sel},
+    *,
+  };
+  use std::{ffi::CStr, os::raw::c_void, sync::Once};
+  use wry::webview::WebviewExtMacOS;
+
+  type Handler = Box<dyn Fn(&str)>;
+
+  extern "C" fn observe_value(
+    this: &Object,
+    _: Sel,
+    _key_path: id,
+    object: id,
+    _change: id,
+    _context: *mut c_void,
+  ) {
+    unsafe {
+      let url: id = msg_send![object, URL];
+      if url == nil {
+        return;
+      }
+      let url: id = msg_send![url, absoluteString];
+      let url = CStr::from_ptr(url.UTF8String()).to_string_lossy();
+      let handler = *this.get_ivar::<*mut c_void>("handler") as *mut Handler;
+      (*handler)(&url);
+    }
+  }
+
+  extern "C" fn dealloc(this: &Object, _: Sel) {
+    unsafe {
+      let handler = *this.get_ivar::<*mut c_void>("handler") as *mut Handler;
+      drop(Box::from_raw(handler));
+      let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+  }
+
+  static REGISTER: Once = Once::new();
+  REGISTER.call_once(|| unsafe {
+    let mut decl = ClassDecl::new("TauriUrlObserver", class!(NSObject)).unwrap();
+    decl.add_ivar::<*mut c_void>("handler");
+    decl.add_method(
+      sel!(observeValueForKeyPath:ofObject:change:context:),
+      observe_value as extern "C" fn(&Object, Sel, id, id, id, *mut c_void),
+    );
+    decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&Object, Sel));
+    decl.register();
+  });
+
+  let handler: Handler = Box::new(move |url| emit(&listeners, url));
+  unsafe {
+    let webview = webview.webview();
+    let observer: id = msg_send![Class::get("TauriUrlObserver").unwrap(), new];
+    (*observer).set_ivar::<*mut c_void>("handler", Box::into_raw(Box::new(handler)) as *mut c_void);
+    let key_path = NSString::alloc(nil).init_str("URL");
+    let _: () = msg_send![webview, addObserver: observer forKeyPath: key_path options: 0usize context: std::ptr::null_mut::<c_void>()];
+    let _: () = msg_send![key_path, release];
+    // the observer is removed and released when the listener is dropped
+    Ok(Listener { webview, observer })
+  }
+}
+
+#[cfg(not(any(target_os = "linux", windows, target_os = "macos")))]
+pub fn listen(_webview: &WebView, _listeners: WindowEventListeners) -> Result<Listener> {
+  Ok(Listener {})
+}
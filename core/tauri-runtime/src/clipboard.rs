@@ -0,0 +1,162 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+  fmt,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+  thread,
+  time::Duration,
+};
+
+use crate::{ClipboardImage, ClipboardManager};
+
+/// A handler called with the MIME types available in the clipboard when its content changes.
+pub type ClipboardChangeHandler = Box<dyn Fn(Vec<String>) + Send>;
+
+/// The clipboard change handler shared between a runtime and its clipboard watcher.
+#[derive(Clone, Default)]
+pub struct ClipboardChangeListener(Arc<Mutex<Option<ClipboardChangeHandler>>>);
+
+impl fmt::Debug for ClipboardChangeListener {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ClipboardChangeListener")
+      .field("active", &self.0.lock().unwrap().is_some())
+      .finish()
+  }
+}
+
+impl ClipboardChangeListener {
+  /// Sets the handler, replacing the previous one.
+  pub fn set(&self, handler: ClipboardChangeHandler) {
+    self.0.lock().unwrap().replace(handler);
+  }
+
+  /// Removes the handler.
+  pub fn clear(&self) {
+    self.0.lock().unwrap().take();
+  }
+
+  /// Whether a handler is set.
+  pub fn is_active(&self) -> bool {
+    self.0.lock().unwrap().is_some()
+  }
+
+  /// Calls the handler, if any. The handler lock is held during the call.
+  pub fn emit(&self, formats: Vec<String>) {
+    if let Some(handler) = &*self.0.lock().unwrap() {
+      handler(formats);
+    }
+  }
+}
+
+#[derive(PartialEq)]
+struct Snapshot {
+  formats: Vec<String>,
+  text: Option<String>,
+  image: Option<ClipboardImage>,
+}
+
+impl Snapshot {
+  /// Returns `None` if the clipboard can't be read, e.g. when the event loop is closed.
+  fn read<C: ClipboardManager>(clipboard: &C) -> Option<Self> {
+    let text = clipboard.read_text().ok()?;
+    let image = clipboard.read_image().ok().flatten();
+    let formats = clipboard.available_formats().unwrap_or_else(|_| {
+      // the runtime can't list the formats, so only report the ones we read
+      let mut formats = Vec::new();
+      if text.is_some() {
+        formats.push("text/plain".into());
+      }
+      if image.is_some() {
+        formats.push("image/png".into());
+      }
+      formats
+    });
+    Some(Self {
+      formats,
+      text,
+      image,
+    })
+  }
+}
+
+/// Watches the clipboard by polling it on a background thread.
+///
+/// Used by runtimes without native clipboard change notifications.
+/// When the runtime provides the clipboard change count, only the count is polled and every change is detected.
+/// Otherwise the text, the image and the list of formats are compared, so changes to other formats
+/// that keep the same MIME types are not detected. The thread stops when the watcher is dropped.
+#[derive(Debug)]
+pub struct ClipboardPollWatcher {
+  stop: Arc<AtomicBool>,
+}
+
+impl ClipboardPollWatcher {
+  /// The default polling interval.
+  pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+  /// Starts polling the clipboard, calling the listener when its content changes.
+  ///
+  /// `change_count` returns a number that changes whenever the clipboard content changes,
+  /// such as the sequence number on Windows or the pasteboard change count on macOS,
+  /// or `None` if the platform doesn't have one.
+  ///
+  /// The clipboard is checked once before this function returns, so any later change is reported.
+  pub fn start<C, F>(
+    clipboard: C,
+    change_count: F,
+    interval: Duration,
+    listener: ClipboardChangeListener,
+  ) -> Self
+  where
+    C: ClipboardManager + 'static,
+    F: Fn() -> Option<u64> + Send + 'static,
+  {
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_ = stop.clone();
+    let mut last_count = change_count();
+    let mut last = if last_count.is_none() {
+      Snapshot::read(&clipboard)
+    } else {
+      None
+    };
+    thread::spawn(move || {
+      loop {
+        thread::sleep(interval);
+        if stop_.load(Ordering::Relaxed) {
+          break;
+        }
+        if let Some(count) = change_count() {
+          // the content is only read when the count says it changed
+          if last_count
+            .replace(count)
+            .map_or(false, |last| last != count)
+          {
+            if let Ok(formats) = clipboard.available_formats() {
+              listener.emit(formats);
+            }
+          }
+        } else if let Some(current) = Snapshot::read(&clipboard) {
+          if last.as_ref() != Some(&current) {
+            // the first successful read is the baseline, not a change
+            if last.is_some() {
+              listener.emit(current.formats.clone());
+            }
+            last.replace(current);
+          }
+        }
+      }
+    });
+    Self { stop }
+  }
+}
+
+impl Drop for ClipboardPollWatcher {
+  fn drop(&mut self) {
+    self.stop.store(true, Ordering::Relaxed);
+  }
+}
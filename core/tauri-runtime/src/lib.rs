@@ -13,6 +13,9 @@ use tauri_utils::Theme;
 use url::Url;
 use uuid::Uuid;
 
+/// Clipboard change watchers.
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
 pub mod http;
 /// Create window and system tray menus.
 pub mod menu;
@@ -199,6 +202,18 @@ pub enum UserAttentionType {
   Informational,
 }
 
+/// Options for the find in page API.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindInPageOptions {
+  /// Whether the search is case sensitive or not.
+  #[serde(default)]
+  pub case_sensitive: bool,
+  /// Whether the search continues from the beginning of the page when reaching the end.
+  #[serde(default)]
+  pub wrap_around: bool,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(tag = "type")]
 pub enum DeviceEventFilter {
@@ -245,6 +260,9 @@ pub enum Error {
   /// Failed to load window icon.
   #[error("invalid icon: {0}")]
   InvalidIcon(Box<dyn std::error::Error + Send + Sync>),
+  /// An entry referenced by a menu structure update does not exist.
+  #[error("menu entry not found")]
+  MenuEntryNotFound,
   /// Failed to get monitor on window operation.
   #[error("failed to get monitor")]
   FailedToGetMonitor,
@@ -266,6 +284,10 @@ pub enum Error {
   Infallible(#[from] std::convert::Infallible),
   #[error("the event loop has been closed")]
   EventLoopClosed,
+  /// Clipboard error.
+  #[cfg(feature = "clipboard")]
+  #[error("clipboard error: {0}")]
+  Clipboard(Box<dyn std::error::Error + Send + Sync>),
 }
 
 /// Result type.
@@ -412,13 +434,68 @@ pub trait GlobalShortcutManager: Debug + Clone + Send + Sync {
   fn unregister(&mut self, accelerator: &str) -> Result<()>;
 }
 
+/// An image stored in the clipboard.
+#[cfg(feature = "clipboard")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardImage {
+  /// RGBA bytes of the image.
+  pub rgba: Vec<u8>,
+  /// Image width.
+  pub width: u32,
+  /// Image height.
+  pub height: u32,
+}
+
+/// A clipboard payload identified by its MIME type.
+#[cfg(feature = "clipboard")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardFormat {
+  /// The MIME type of the payload, e.g. `application/json`.
+  pub mime_type: String,
+  /// The payload.
+  pub data: Vec<u8>,
+}
+
 /// Clipboard manager.
+///
+/// ## Platform-specific
+///
+/// - **Windows / macOS:** [`Self::write_formats`] is unsupported and returns an error.
+/// [`Self::read_format`] and [`Self::available_formats`] only know the payloads with a MIME type:
+/// text, HTML, file lists, PNG images and the formats the system maps to a MIME type
+/// (on Windows, the formats registered with a MIME type as their name).
 #[cfg(feature = "clipboard")]
 pub trait ClipboardManager: Debug + Clone + Send + Sync {
   /// Writes the text into the clipboard as plain text.
   fn write_text<T: Into<String>>(&mut self, text: T) -> Result<()>;
   /// Read the content in the clipboard as plain text.
   fn read_text(&self) -> Result<Option<String>>;
+  /// Writes the image into the clipboard.
+  fn write_image(&mut self, image: ClipboardImage) -> Result<()>;
+  /// Read the content in the clipboard as an image.
+  fn read_image(&self) -> Result<Option<ClipboardImage>>;
+  /// Writes the HTML into the clipboard, with an optional plain text alternative.
+  fn write_html(&mut self, html: String, alt_text: Option<String>) -> Result<()>;
+  /// Read the content in the clipboard as HTML.
+  fn read_html(&self) -> Result<Option<String>>;
+  /// Writes the list of file paths into the clipboard.
+  fn write_files(&mut self, paths: Vec<std::path::PathBuf>) -> Result<()>;
+  /// Read the content in the clipboard as a list of file paths.
+  fn read_files(&self) -> Result<Option<Vec<std::path::PathBuf>>>;
+  /// Writes the payloads into the clipboard, replacing its content.
+  fn write_formats(&mut self, formats: Vec<ClipboardFormat>) -> Result<()>;
+  /// Read the clipboard payload with the given MIME type.
+  fn read_format(&self, mime_type: String) -> Result<Option<Vec<u8>>>;
+  /// The MIME types available in the clipboard.
+  fn available_formats(&self) -> Result<Vec<String>>;
+  /// Clears the clipboard.
+  fn clear(&mut self) -> Result<()>;
+  /// Calls the handler with the MIME types available in the clipboard whenever its content changes.
+  ///
+  /// Replaces the handler set by a previous call. The handler must not call [`Self::watch`] or [`Self::unwatch`].
+  fn watch<F: Fn(Vec<String>) + Send + 'static>(&mut self, handler: F) -> Result<()>;
+  /// Stops watching the clipboard.
+  fn unwatch(&mut self) -> Result<()>;
 }
 
 pub trait EventLoopProxy<T: UserEvent>: Debug + Clone + Send + Sync {
@@ -548,6 +625,9 @@ pub trait Dispatch<T: UserEvent>: Debug + Clone + Send + Sync + Sized + 'static
   /// Returns the webview's current URL.
   fn url(&self) -> Result<Url>;
 
+  /// Returns the webview's current zoom level.
+  fn zoom(&self) -> Result<f64>;
+
   /// Returns the scale factor that can be used to map logical pixels to physical pixels, and vice versa.
   fn scale_factor(&self) -> Result<f64>;
 
@@ -626,6 +706,35 @@ pub trait Dispatch<T: UserEvent>: Debug + Clone + Send + Sync + Sized + 'static
   /// Opens the dialog to prints the contents of the webview.
   fn print(&self) -> Result<()>;
 
+  /// Navigates the webview to the given URL.
+  fn navigate(&self, url: Url) -> Result<()>;
+
+  /// Reloads the current page of the webview.
+  fn reload(&self) -> Result<()>;
+
+  /// Navigates back to the previous page of the webview history.
+  fn go_back(&self) -> Result<()>;
+
+  /// Navigates forward to the next page of the webview history.
+  fn go_forward(&self) -> Result<()>;
+
+  /// Sets the webview zoom level, where `1.0` is the default size.
+  fn set_zoom(&self, scale_factor: f64) -> Result<()>;
+
+  /// Searches the webview content for the given text and selects its first match.
+  ///
+  /// Runtimes without a native find API may search the main document only.
+  fn find_in_page(&self, text: String, options: FindInPageOptions) -> Result<()>;
+
+  /// Selects the next match of the current [`Self::find_in_page`] search.
+  fn find_next(&self) -> Result<()>;
+
+  /// Selects the previous match of the current [`Self::find_in_page`] search.
+  fn find_previous(&self) -> Result<()>;
+
+  /// Clears the current [`Self::find_in_page`] search and its selection.
+  fn clear_find(&self) -> Result<()>;
+
   /// Requests user attention to the window.
   ///
   /// Providing `None` will unset the request for user attention.
@@ -731,4 +840,28 @@ pub trait Dispatch<T: UserEvent>: Debug + Clone + Send + Sync + Sized + 'static
 
   /// Applies the specified `update` to the menu item associated with the given `id`.
   fn update_menu_item(&self, id: u16, update: menu::MenuUpdate) -> Result<()>;
+
+  /// Replaces the window menu.
+  fn set_menu(&self, menu: menu::Menu) -> Result<()>;
+
+  /// Inserts, moves or removes entries of the window menu.
+  ///
+  /// Returns the ids of the removed custom items,
+  /// or [`Error::MenuEntryNotFound`] if an entry referenced by the update does not exist.
+  fn update_menu_structure(
+    &self,
+    update: menu::MenuStructureUpdate<menu::MenuEntry>,
+  ) -> Result<Vec<menu::MenuHash>>;
+
+  /// Shows the context menu at the given position, relative to the window's client area.
+  ///
+  /// If `position` is `None`, the menu is shown at the cursor position.
+  /// Item clicks are sent to the window menu event listeners.
+  /// `on_close` is called once the menu is closed, after the event of the clicked item was sent.
+  fn popup_menu<F: FnOnce() + Send + 'static>(
+    &self,
+    menu: menu::ContextMenu,
+    position: Option<Position>,
+    on_close: F,
+  ) -> Result<()>;
 }
@@ -146,10 +146,201 @@ pub enum MenuUpdate {
   SetNativeImage(NativeImage),
 }
 
+/// A reference to an entry of a menu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MenuEntryRef {
+  /// The custom item with the given id.
+  Item(MenuHash),
+  /// The submenu with the given title.
+  Submenu(String),
+}
+
+impl MenuEntryRef {
+  /// References the custom item with the given id.
+  pub fn item<I: AsRef<str>>(id: I) -> Self {
+    Self::Item(CustomMenuItem::hash(id.as_ref()))
+  }
+
+  /// References the submenu with the given title.
+  pub fn submenu<T: Into<String>>(title: T) -> Self {
+    Self::Submenu(title.into())
+  }
+}
+
+/// The position where a menu entry is inserted or moved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MenuPosition {
+  /// Before the referenced entry, in the same menu.
+  Before(MenuEntryRef),
+  /// After the referenced entry, in the same menu.
+  After(MenuEntryRef),
+  /// At the end of the submenu with the given title, or at the end of the root menu if `None`.
+  End(Option<String>),
+}
+
+/// A change to the structure of a menu.
+///
+/// `E` is the entry type of the menu, [`MenuEntry`] for window menus
+/// and [`SystemTrayMenuEntry`] for system tray menus.
+///
+/// ## Platform-specific
+///
+/// - **Linux / Windows / macOS**: tao can't patch a menu in place, so the whole menu is rebuilt
+///   and set again on every update, which can make an open menu or the menu bar flicker.
+#[derive(Debug, Clone)]
+pub enum MenuStructureUpdate<E> {
+  /// Inserts a new entry at the given position.
+  Insert(E, MenuPosition),
+  /// Moves an existing entry to the given position, keeping its state.
+  Move(MenuEntryRef, MenuPosition),
+  /// Removes an existing entry.
+  Remove(MenuEntryRef),
+}
+
+/// Tree operations shared by window menus and system tray menus.
+trait MenuTree: Clone {
+  type Entry;
+
+  fn entries(&mut self) -> &mut Vec<Self::Entry>;
+  fn submenu(entry: &mut Self::Entry) -> Option<(&str, &mut Self)>;
+  fn custom_item(entry: &mut Self::Entry) -> Option<&mut CustomMenuItem>;
+
+  fn matches(entry: &mut Self::Entry, target: &MenuEntryRef) -> bool {
+    match target {
+      MenuEntryRef::Item(id) => matches!(Self::custom_item(entry), Some(i) if i.id == *id),
+      MenuEntryRef::Submenu(title) => matches!(Self::submenu(entry), Some((t, _)) if t == title),
+    }
+  }
+
+  fn find_item(&mut self, id: MenuHash) -> Option<&mut CustomMenuItem> {
+    for entry in self.entries() {
+      if Self::matches(entry, &MenuEntryRef::Item(id)) {
+        return Self::custom_item(entry);
+      }
+      if let Some(item) = Self::submenu(entry).and_then(|(_, menu)| menu.find_item(id)) {
+        return Some(item);
+      }
+    }
+    None
+  }
+
+  // collects the ids of the custom items of the entry, including the items of its submenus
+  fn item_ids(entry: &mut Self::Entry, ids: &mut Vec<MenuHash>) {
+    if let Some(item) = Self::custom_item(entry) {
+      ids.push(item.id);
+    } else if let Some((_, menu)) = Self::submenu(entry) {
+      for entry in menu.entries() {
+        Self::item_ids(entry, ids);
+      }
+    }
+  }
+
+  fn take(&mut self, target: &MenuEntryRef) -> Option<Self::Entry> {
+    let entries = self.entries();
+    if let Some(index) = entries.iter_mut().position(|e| Self::matches(e, target)) {
+      return Some(entries.remove(index));
+    }
+    entries
+      .iter_mut()
+      .find_map(|e| Self::submenu(e).and_then(|(_, menu)| menu.take(target)))
+  }
+
+  // returns the entry back if the position was not found
+  fn insert(&mut self, entry: Self::Entry, position: &MenuPosition) -> Option<Self::Entry> {
+    let entries = self.entries();
+    match position {
+      MenuPosition::End(None) => {
+        entries.push(entry);
+        return None;
+      }
+      MenuPosition::End(Some(title)) => {
+        let target = MenuEntryRef::Submenu(title.clone());
+        if let Some(index) = entries.iter_mut().position(|e| Self::matches(e, &target)) {
+          Self::submenu(&mut entries[index])
+            .unwrap()
+            .1
+            .entries()
+            .push(entry);
+          return None;
+        }
+      }
+      MenuPosition::Before(target) | MenuPosition::After(target) => {
+        if let Some(index) = entries.iter_mut().position(|e| Self::matches(e, target)) {
+          let index = match position {
+            MenuPosition::After(_) => index + 1,
+            _ => index,
+          };
+          entries.insert(index, entry);
+          return None;
+        }
+      }
+    }
+    let mut entry = Some(entry);
+    for e in entries.iter_mut() {
+      if let Some((_, menu)) = Self::submenu(e) {
+        entry = menu.insert(entry.take().unwrap(), position);
+        if entry.is_none() {
+          break;
+        }
+      }
+    }
+    entry
+  }
+
+  fn apply_update(&mut self, id: MenuHash, update: &MenuUpdate) -> bool {
+    if let Some(item) = self.find_item(id) {
+      match update {
+        MenuUpdate::SetEnabled(enabled) => item.enabled = *enabled,
+        MenuUpdate::SetTitle(title) => item.title = title.clone(),
+        MenuUpdate::SetSelected(selected) => item.selected = *selected,
+        #[cfg(target_os = "macos")]
+        MenuUpdate::SetNativeImage(image) => item.native_image = Some(image.clone()),
+      }
+      true
+    } else {
+      false
+    }
+  }
+
+  // returns the ids of the removed items, or `None` if a referenced entry was not found
+  fn apply_structure_update(
+    &mut self,
+    update: MenuStructureUpdate<Self::Entry>,
+  ) -> Option<Vec<MenuHash>> {
+    // work on a copy so a missing target leaves the menu untouched
+    let mut menu = self.clone();
+    let mut removed = Vec::new();
+    let updated = match update {
+      MenuStructureUpdate::Insert(entry, position) => menu.insert(entry, &position).is_none(),
+      MenuStructureUpdate::Move(target, position) => match menu.take(&target) {
+        Some(entry) => menu.insert(entry, &position).is_none(),
+        None => false,
+      },
+      MenuStructureUpdate::Remove(target) => match menu.take(&target) {
+        Some(mut entry) => {
+          Self::item_ids(&mut entry, &mut removed);
+          true
+        }
+        None => false,
+      },
+    };
+    if updated {
+      *self = menu;
+      Some(removed)
+    } else {
+      None
+    }
+  }
+}
+
 pub trait TrayHandle: fmt::Debug + Clone + Send + Sync {
   fn set_icon(&self, icon: crate::Icon) -> crate::Result<()>;
   fn set_menu(&self, menu: crate::menu::SystemTrayMenu) -> crate::Result<()>;
   fn update_item(&self, id: u16, update: MenuUpdate) -> crate::Result<()>;
+  fn update_menu_structure(
+    &self,
+    update: MenuStructureUpdate<SystemTrayMenuEntry>,
+  ) -> crate::Result<Vec<MenuHash>>;
   #[cfg(target_os = "macos")]
   fn set_icon_as_template(&self, is_template: bool) -> crate::Result<()>;
   #[cfg(target_os = "macos")]
@@ -354,6 +545,46 @@ impl Menu {
     self.items.push(MenuEntry::Submenu(submenu));
     self
   }
+
+  /// Applies the [`MenuUpdate`] to the custom item with the given id.
+  ///
+  /// Returns `false` if the item was not found.
+  pub fn update_item(&mut self, id: MenuHash, update: &MenuUpdate) -> bool {
+    self.apply_update(id, update)
+  }
+
+  /// Applies the [`MenuStructureUpdate`] to the menu.
+  ///
+  /// Returns the ids of the custom items removed from the menu,
+  /// or `None` and leaves the menu untouched if a referenced entry was not found.
+  pub fn update_structure(
+    &mut self,
+    update: MenuStructureUpdate<MenuEntry>,
+  ) -> Option<Vec<MenuHash>> {
+    self.apply_structure_update(update)
+  }
+}
+
+impl MenuTree for Menu {
+  type Entry = MenuEntry;
+
+  fn entries(&mut self) -> &mut Vec<MenuEntry> {
+    &mut self.items
+  }
+
+  fn submenu(entry: &mut MenuEntry) -> Option<(&str, &mut Self)> {
+    match entry {
+      MenuEntry::Submenu(s) => Some((&s.title, &mut s.inner)),
+      _ => None,
+    }
+  }
+
+  fn custom_item(entry: &mut MenuEntry) -> Option<&mut CustomMenuItem> {
+    match entry {
+      MenuEntry::CustomItem(c) => Some(c),
+      _ => None,
+    }
+  }
 }
 
 /// A custom menu item.
@@ -423,6 +654,47 @@ impl CustomMenuItem {
   }
 }
 
+/// A context menu, shown as a popup on top of a window.
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
+pub struct ContextMenu {
+  pub items: Vec<MenuEntry>,
+}
+
+impl ContextMenu {
+  /// Creates a new context menu.
+  pub fn new() -> Self {
+    Default::default()
+  }
+
+  /// Adds the custom menu item to the context menu.
+  #[must_use]
+  pub fn add_item(mut self, item: CustomMenuItem) -> Self {
+    self.items.push(MenuEntry::CustomItem(item));
+    self
+  }
+
+  /// Adds a native item to the context menu.
+  #[must_use]
+  pub fn add_native_item(mut self, item: MenuItem) -> Self {
+    self.items.push(MenuEntry::NativeItem(item));
+    self
+  }
+
+  /// Adds an entry with submenu.
+  #[must_use]
+  pub fn add_submenu(mut self, submenu: Submenu) -> Self {
+    self.items.push(MenuEntry::Submenu(submenu));
+    self
+  }
+}
+
+impl From<Menu> for ContextMenu {
+  fn from(menu: Menu) -> Self {
+    Self { items: menu.items }
+  }
+}
+
 /// A system tray menu.
 #[derive(Debug, Default, Clone)]
 #[non_exhaustive]
@@ -475,6 +747,46 @@ impl SystemTrayMenu {
     self.items.push(SystemTrayMenuEntry::Submenu(submenu));
     self
   }
+
+  /// Applies the [`MenuUpdate`] to the custom item with the given id.
+  ///
+  /// Returns `false` if the item was not found.
+  pub fn update_item(&mut self, id: MenuHash, update: &MenuUpdate) -> bool {
+    self.apply_update(id, update)
+  }
+
+  /// Applies the [`MenuStructureUpdate`] to the menu.
+  ///
+  /// Returns the ids of the custom items removed from the menu,
+  /// or `None` and leaves the menu untouched if a referenced entry was not found.
+  pub fn update_structure(
+    &mut self,
+    update: MenuStructureUpdate<SystemTrayMenuEntry>,
+  ) -> Option<Vec<MenuHash>> {
+    self.apply_structure_update(update)
+  }
+}
+
+impl MenuTree for SystemTrayMenu {
+  type Entry = SystemTrayMenuEntry;
+
+  fn entries(&mut self) -> &mut Vec<SystemTrayMenuEntry> {
+    &mut self.items
+  }
+
+  fn submenu(entry: &mut SystemTrayMenuEntry) -> Option<(&str, &mut Self)> {
+    match entry {
+      SystemTrayMenuEntry::Submenu(s) => Some((&s.title, &mut s.inner)),
+      _ => None,
+    }
+  }
+
+  fn custom_item(entry: &mut SystemTrayMenuEntry) -> Option<&mut CustomMenuItem> {
+    match entry {
+      SystemTrayMenuEntry::CustomItem(c) => Some(c),
+      _ => None,
+    }
+  }
 }
 
 /// An entry on the system tray menu.
@@ -745,3 +1057,155 @@ pub enum MenuItem {
   ///
   Separator,
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn item(id: &str) -> CustomMenuItem {
+    CustomMenuItem::new(id, id.to_uppercase())
+  }
+
+  fn hash(id: &str) -> MenuHash {
+    CustomMenuItem::hash(id)
+  }
+
+  // describes the menu as the item ids, with submenus as `title[..]`
+  fn layout(menu: &Menu) -> String {
+    menu
+      .items
+      .iter()
+      .map(|entry| match entry {
+        MenuEntry::CustomItem(c) => c.id_str.clone(),
+        MenuEntry::Submenu(s) => format!("{}[{}]", s.title, layout(&s.inner)),
+        MenuEntry::NativeItem(_) => "-".into(),
+      })
+      .collect::<Vec<_>>()
+      .join(" ")
+  }
+
+  fn menu() -> Menu {
+    Menu::new()
+      .add_item(item("a"))
+      .add_native_item(MenuItem::Separator)
+      .add_submenu(Submenu::new(
+        "Edit",
+        Menu::new()
+          .add_item(item("b"))
+          .add_submenu(Submenu::new("Nested", Menu::new().add_item(item("c")))),
+      ))
+  }
+
+  #[test]
+  fn insert_entries() {
+    let mut menu = menu();
+    assert_eq!(
+      menu.update_structure(MenuStructureUpdate::Insert(
+        item("d").into(),
+        MenuPosition::Before(MenuEntryRef::item("b")),
+      )),
+      Some(Vec::new())
+    );
+    assert!(menu
+      .update_structure(MenuStructureUpdate::Insert(
+        item("e").into(),
+        MenuPosition::After(MenuEntryRef::item("c")),
+      ))
+      .is_some());
+    assert!(menu
+      .update_structure(MenuStructureUpdate::Insert(
+        item("f").into(),
+        MenuPosition::End(Some("Edit".into())),
+      ))
+      .is_some());
+    assert!(menu
+      .update_structure(MenuStructureUpdate::Insert(
+        item("g").into(),
+        MenuPosition::End(None),
+      ))
+      .is_some());
+    assert_eq!(layout(&menu), "a - Edit[d b Nested[c e] f] g");
+  }
+
+  #[test]
+  fn move_entries_keeps_state() {
+    let mut menu = menu();
+    assert!(menu.update_item(hash("c"), &MenuUpdate::SetSelected(true)));
+    assert!(menu
+      .update_structure(MenuStructureUpdate::Move(
+        MenuEntryRef::item("c"),
+        MenuPosition::Before(MenuEntryRef::item("a")),
+      ))
+      .is_some());
+    assert!(menu
+      .update_structure(MenuStructureUpdate::Move(
+        MenuEntryRef::submenu("Nested"),
+        MenuPosition::End(None),
+      ))
+      .is_some());
+    assert_eq!(layout(&menu), "c a - Edit[b] Nested[]");
+    match &menu.items[0] {
+      MenuEntry::CustomItem(c) => assert!(c.selected),
+      _ => panic!("expected a custom item"),
+    }
+  }
+
+  #[test]
+  fn missing_target_leaves_menu_untouched() {
+    let mut menu = menu();
+    let updates = vec![
+      MenuStructureUpdate::Insert(
+        item("d").into(),
+        MenuPosition::After(MenuEntryRef::item("missing")),
+      ),
+      MenuStructureUpdate::Insert(item("d").into(), MenuPosition::End(Some("Missing".into()))),
+      MenuStructureUpdate::Move(MenuEntryRef::item("missing"), MenuPosition::End(None)),
+      // the destination is removed along with the moved submenu
+      MenuStructureUpdate::Move(
+        MenuEntryRef::submenu("Edit"),
+        MenuPosition::End(Some("Nested".into())),
+      ),
+      MenuStructureUpdate::Remove(MenuEntryRef::submenu("Missing")),
+    ];
+    for update in updates {
+      assert_eq!(menu.update_structure(update), None);
+      assert_eq!(layout(&menu), layout(&self::menu()));
+    }
+    assert!(!menu.update_item(hash("missing"), &MenuUpdate::SetEnabled(false)));
+  }
+
+  #[test]
+  fn remove_entries_returns_removed_ids() {
+    let mut menu = menu();
+    assert_eq!(
+      menu.update_structure(MenuStructureUpdate::Remove(MenuEntryRef::item("a"))),
+      Some(vec![hash("a")])
+    );
+    assert_eq!(
+      menu.update_structure(MenuStructureUpdate::Remove(MenuEntryRef::submenu("Edit"))),
+      Some(vec![hash("b"), hash("c")])
+    );
+    assert_eq!(layout(&menu), "-");
+  }
+
+  #[test]
+  fn system_tray_menu_structure() {
+    let mut menu = SystemTrayMenu::new()
+      .add_item(item("a"))
+      .add_submenu(SystemTraySubmenu::new(
+        "More",
+        SystemTrayMenu::new().add_item(item("b")),
+      ));
+    assert!(menu
+      .update_structure(MenuStructureUpdate::Insert(
+        SystemTrayMenuEntry::CustomItem(item("c")),
+        MenuPosition::Before(MenuEntryRef::item("b")),
+      ))
+      .is_some());
+    assert_eq!(
+      menu.update_structure(MenuStructureUpdate::Remove(MenuEntryRef::submenu("More"))),
+      Some(vec![hash("c"), hash("b")])
+    );
+    assert_eq!(menu.items.len(), 1);
+  }
+}
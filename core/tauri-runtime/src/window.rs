@@ -64,6 +64,10 @@ pub enum WindowEvent {
   ///
   /// Applications might wish to react to this to change the theme of the content of the window when the system changes the window theme.
   ThemeChanged(Theme),
+  /// The URL of the main frame of the webview changed.
+  ///
+  /// Emitted when a navigation is committed, so cancelled navigations and iframes do not trigger it.
+  UrlChanged(Url),
 }
 
 /// The file drop event payload.
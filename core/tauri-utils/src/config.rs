@@ -605,6 +605,82 @@ fn default_allow_downgrades() -> bool {
   true
 }
 
+/// A custom URL scheme the application registers itself as the handler of, e.g. `myapp://`.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DeepLinkProtocol {
+  /// The URL schemes to register, without the `://` suffix, e.g. `["myapp", "myapp-dev"]`.
+  pub schemes: Vec<String>,
+  /// The name of the protocol, shown by the system when asking which application should open a link.
+  /// Defaults to the product name.
+  pub name: Option<String>,
+}
+
+/// The role of the application regarding a file type, see <https://developer.apple.com/documentation/bundleresources/information_property_list/cfbundledocumenttypes/cfbundletyperole>.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum BundleTypeRole {
+  /// The app can read and edit the files.
+  Editor,
+  /// The app can read and display the files, but can't edit them.
+  Viewer,
+  /// The app provides runtime services for the files.
+  Shell,
+  /// The app is a Quick Look generator for the files.
+  QLGenerator,
+  /// The app doesn't handle the files, it only declares the type.
+  None,
+}
+
+impl Default for BundleTypeRole {
+  fn default() -> Self {
+    Self::Editor
+  }
+}
+
+impl Display for BundleTypeRole {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Self::Editor => "Editor",
+        Self::Viewer => "Viewer",
+        Self::Shell => "Shell",
+        Self::QLGenerator => "QLGenerator",
+        Self::None => "None",
+      }
+    )
+  }
+}
+
+/// A file type the application is registered to open.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FileAssociation {
+  /// The file extensions, without the leading dot, e.g. `["ourproj"]`.
+  pub ext: Vec<String>,
+  /// The MIME type of the files.
+  ///
+  /// Defaults to `application/x-{ext}` on Linux, where it is required to register the association.
+  #[serde(alias = "mime-type")]
+  pub mime_type: Option<String>,
+  /// The human readable description of the file type, e.g. `Our Project`.
+  pub description: Option<String>,
+  /// Path to the icon of the file type, relative to the tauri directory.
+  ///
+  /// Must be an `.icns` file on macOS and an `.ico` file on Windows,
+  /// use the platform specific configuration files to set both. Not supported on Linux.
+  pub icon: Option<PathBuf>,
+  /// The app's role for the file type. Only used on macOS.
+  #[serde(default)]
+  pub role: BundleTypeRole,
+}
+
 /// Configuration for tauri-bundler.
 #[skip_serializing_none]
 #[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
@@ -671,6 +747,18 @@ pub struct BundleConfig {
   /// Configuration for the Windows bundle.
   #[serde(default)]
   pub windows: WindowsConfig,
+  /// The custom URL schemes handled by the application.
+  ///
+  /// The schemes are registered with the system by the Linux bundles,
+  /// and the URLs the app is launched with are delivered as [`RunEvent::Opened`](https://docs.rs/tauri/latest/tauri/enum.RunEvent.html#variant.Opened).
+  #[serde(alias = "deep-link-protocols")]
+  pub deep_link_protocols: Option<Vec<DeepLinkProtocol>>,
+  /// The file types the application is registered to open.
+  ///
+  /// The paths of the files the app is launched with are delivered as `file://` URLs
+  /// in [`RunEvent::Opened`](https://docs.rs/tauri/latest/tauri/enum.RunEvent.html#variant.Opened).
+  #[serde(alias = "file-associations")]
+  pub file_associations: Option<Vec<FileAssociation>>,
 }
 
 /// A CLI argument definition.
@@ -960,6 +1048,13 @@ pub struct WindowConfig {
   /// so if you use this method, you also need to disable these components by yourself if you want.
   #[serde(default, alias = "additional-browser-args")]
   pub additional_browser_args: Option<String>,
+  /// Whether the size, position and maximized state of the window are saved when it changes,
+  /// and restored the next time a window with the same label is created.
+  ///
+  /// The state is stored in the `.window-state.json` file of the app config directory.
+  /// The saved position is only restored if the window is still on one of the available monitors.
+  #[serde(default, alias = "restore-state")]
+  pub restore_state: bool,
 }
 
 impl Default for WindowConfig {
@@ -995,6 +1090,7 @@ impl Default for WindowConfig {
       accept_first_mouse: false,
       tabbing_identifier: None,
       additional_browser_args: None,
+      restore_state: false,
     }
   }
 }
@@ -1289,6 +1385,11 @@ pub struct FsAllowlistConfig {
   /// The access scope for the filesystem APIs.
   #[serde(default)]
   pub scope: FsAllowlistScope,
+  /// Whether paths allowed on the filesystem scope at runtime are persisted across app restarts.
+  ///
+  /// The grants are stored in the app data directory and can be listed and revoked with `Manager::scope_grants`.
+  #[serde(default, alias = "persist-scope")]
+  pub persist_scope: bool,
   /// Use this flag to enable all file system API features.
   #[serde(default)]
   pub all: bool,
@@ -1325,6 +1426,7 @@ impl Allowlist for FsAllowlistConfig {
   fn all_features() -> Vec<&'static str> {
     let allowlist = Self {
       scope: Default::default(),
+      persist_scope: false,
       all: false,
       read_file: true,
       write_file: true,
@@ -1458,6 +1560,27 @@ pub struct WindowAllowlistConfig {
   /// Allows opening the system dialog to print the window content.
   #[serde(default)]
   pub print: bool,
+  /// Allows navigating the webview to a new URL.
+  #[serde(default)]
+  pub navigate: bool,
+  /// Allows reloading the webview content.
+  #[serde(default)]
+  pub reload: bool,
+  /// Allows navigating back in the webview history.
+  #[serde(default, alias = "go-back")]
+  pub go_back: bool,
+  /// Allows navigating forward in the webview history.
+  #[serde(default, alias = "go-forward")]
+  pub go_forward: bool,
+  /// Allows changing the webview zoom level.
+  #[serde(default, alias = "set-zoom")]
+  pub set_zoom: bool,
+  /// Allows searching text in the webview content.
+  #[serde(default, alias = "find-in-page")]
+  pub find_in_page: bool,
+  /// Allows showing native context menus.
+  #[serde(default, alias = "popup-menu")]
+  pub popup_menu: bool,
 }
 
 impl Allowlist for WindowAllowlistConfig {
@@ -1494,6 +1617,13 @@ impl Allowlist for WindowAllowlistConfig {
       set_ignore_cursor_events: true,
       start_dragging: true,
       print: true,
+      navigate: true,
+      reload: true,
+      go_back: true,
+      go_forward: true,
+      set_zoom: true,
+      find_in_page: true,
+      popup_menu: true,
     };
     let mut features = allowlist.to_features();
     features.push("window-all");
@@ -1565,6 +1695,13 @@ impl Allowlist for WindowAllowlistConfig {
       );
       check_feature!(self, features, start_dragging, "window-start-dragging");
       check_feature!(self, features, print, "window-print");
+      check_feature!(self, features, navigate, "window-navigate");
+      check_feature!(self, features, reload, "window-reload");
+      check_feature!(self, features, go_back, "window-go-back");
+      check_feature!(self, features, go_forward, "window-go-forward");
+      check_feature!(self, features, set_zoom, "window-set-zoom");
+      check_feature!(self, features, find_in_page, "window-find-in-page");
+      check_feature!(self, features, popup_menu, "window-popup-menu");
       features
     }
   }
@@ -1729,6 +1866,10 @@ pub struct ShellAllowlistConfig {
   /// Open URL with the user's default application.
   #[serde(default)]
   pub open: ShellAllowlistOpen,
+  /// Enable spawning commands in a pseudo terminal, for programs that need a TTY.
+  /// The commands must still be allowed by the `execute` or `sidecar` flags and the scope.
+  #[serde(default)]
+  pub pty: bool,
 }
 
 impl Allowlist for ShellAllowlistConfig {
@@ -1739,6 +1880,7 @@ impl Allowlist for ShellAllowlistConfig {
       execute: true,
       sidecar: true,
       open: ShellAllowlistOpen::Flag(true),
+      pty: true,
     };
     let mut features = allowlist.to_features();
     features.push("shell-all");
@@ -1752,6 +1894,7 @@ impl Allowlist for ShellAllowlistConfig {
       let mut features = Vec::new();
       check_feature!(self, features, execute, "shell-execute");
       check_feature!(self, features, sidecar, "shell-sidecar");
+      check_feature!(self, features, pty, "shell-pty");
 
       if !matches!(self.open, ShellAllowlistOpen::Flag(false)) {
         features.push("shell-open")
@@ -1785,6 +1928,9 @@ pub struct DialogAllowlistConfig {
   /// Allows the API to show a dialog window with Ok/Cancel buttons.
   #[serde(default)]
   pub confirm: bool,
+  /// Allows the API to show a dialog window with custom buttons, a text input and a checkbox.
+  #[serde(default)]
+  pub custom: bool,
 }
 
 impl Allowlist for DialogAllowlistConfig {
@@ -1796,6 +1942,7 @@ impl Allowlist for DialogAllowlistConfig {
       message: true,
       ask: true,
       confirm: true,
+      custom: true,
     };
     let mut features = allowlist.to_features();
     features.push("dialog-all");
@@ -1812,6 +1959,7 @@ impl Allowlist for DialogAllowlistConfig {
       check_feature!(self, features, message, "dialog-message");
       check_feature!(self, features, ask, "dialog-ask");
       check_feature!(self, features, confirm, "dialog-confirm");
+      check_feature!(self, features, custom, "dialog-custom");
       features
     }
   }
@@ -2087,6 +2235,36 @@ pub struct ClipboardAllowlistConfig {
   /// Enables the clipboard's `readText` API.
   #[serde(default, alias = "readText")]
   pub read_text: bool,
+  /// Enables the clipboard's `writeImage` API.
+  #[serde(default, alias = "writeImage")]
+  pub write_image: bool,
+  /// Enables the clipboard's `readImage` API.
+  #[serde(default, alias = "readImage")]
+  pub read_image: bool,
+  /// Enables the clipboard's `writeHtml` API.
+  #[serde(default, alias = "writeHtml")]
+  pub write_html: bool,
+  /// Enables the clipboard's `readHtml` API.
+  #[serde(default, alias = "readHtml")]
+  pub read_html: bool,
+  /// Enables the clipboard's `writeFiles` API.
+  #[serde(default, alias = "writeFiles")]
+  pub write_files: bool,
+  /// Enables the clipboard's `readFiles` API.
+  #[serde(default, alias = "readFiles")]
+  pub read_files: bool,
+  /// Enables the clipboard's `writeData` API.
+  #[serde(default, alias = "writeData")]
+  pub write_data: bool,
+  /// Enables the clipboard's `readData` and `availableFormats` APIs.
+  #[serde(default, alias = "readData")]
+  pub read_data: bool,
+  /// Enables the clipboard's `clear` API.
+  #[serde(default)]
+  pub clear: bool,
+  /// Enables the clipboard change events.
+  #[serde(default)]
+  pub watch: bool,
 }
 
 impl Allowlist for ClipboardAllowlistConfig {
@@ -2095,6 +2273,16 @@ impl Allowlist for ClipboardAllowlistConfig {
       all: false,
       write_text: true,
       read_text: true,
+      write_image: true,
+      read_image: true,
+      write_html: true,
+      read_html: true,
+      write_files: true,
+      read_files: true,
+      write_data: true,
+      read_data: true,
+      clear: true,
+      watch: true,
     };
     let mut features = allowlist.to_features();
     features.push("clipboard-all");
@@ -2108,6 +2296,226 @@ impl Allowlist for ClipboardAllowlistConfig {
       let mut features = Vec::new();
       check_feature!(self, features, write_text, "clipboard-write-text");
       check_feature!(self, features, read_text, "clipboard-read-text");
+      check_feature!(self, features, write_image, "clipboard-write-image");
+      check_feature!(self, features, read_image, "clipboard-read-image");
+      check_feature!(self, features, write_html, "clipboard-write-html");
+      check_feature!(self, features, read_html, "clipboard-read-html");
+      check_feature!(self, features, write_files, "clipboard-write-files");
+      check_feature!(self, features, read_files, "clipboard-read-files");
+      check_feature!(self, features, write_data, "clipboard-write-data");
+      check_feature!(self, features, read_data, "clipboard-read-data");
+      check_feature!(self, features, clear, "clipboard-clear");
+      check_feature!(self, features, watch, "clipboard-watch");
+      features
+    }
+  }
+}
+
+/// Allowlist for the archive APIs.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ArchiveAllowlistConfig {
+  /// Use this flag to enable all archive APIs.
+  #[serde(default)]
+  pub all: bool,
+  /// Enables the archive's `extract` API.
+  #[serde(default)]
+  pub extract: bool,
+  /// Enables the archive's `create` API.
+  #[serde(default)]
+  pub create: bool,
+}
+
+impl Allowlist for ArchiveAllowlistConfig {
+  fn all_features() -> Vec<&'static str> {
+    let allowlist = Self {
+      all: false,
+      extract: true,
+      create: true,
+    };
+    let mut features = allowlist.to_features();
+    features.push("archive-all");
+    features
+  }
+
+  fn to_features(&self) -> Vec<&'static str> {
+    if self.all {
+      vec!["archive-all"]
+    } else {
+      let mut features = Vec::new();
+      check_feature!(self, features, extract, "archive-extract");
+      check_feature!(self, features, create, "archive-create");
+      features
+    }
+  }
+}
+
+/// Allowlist for the menu APIs.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MenuAllowlistConfig {
+  /// Use this flag to enable all menu APIs.
+  #[serde(default)]
+  pub all: bool,
+  /// Enables the menu's `setWindowMenu` API.
+  #[serde(default, alias = "set-window-menu")]
+  pub set_window_menu: bool,
+  /// Enables the menu's `setTrayMenu` API.
+  #[serde(default, alias = "set-tray-menu")]
+  pub set_tray_menu: bool,
+  /// Enables the menu's `updateWindowMenuItem` and `updateTrayMenuItem` APIs.
+  #[serde(default, alias = "update-item")]
+  pub update_item: bool,
+}
+
+impl Allowlist for MenuAllowlistConfig {
+  fn all_features() -> Vec<&'static str> {
+    let allowlist = Self {
+      all: false,
+      set_window_menu: true,
+      set_tray_menu: true,
+      update_item: true,
+    };
+    let mut features = allowlist.to_features();
+    features.push("menu-all");
+    features
+  }
+
+  fn to_features(&self) -> Vec<&'static str> {
+    if self.all {
+      vec!["menu-all"]
+    } else {
+      let mut features = Vec::new();
+      check_feature!(self, features, set_window_menu, "menu-set-window-menu");
+      check_feature!(self, features, set_tray_menu, "menu-set-tray-menu");
+      check_feature!(self, features, update_item, "menu-update-item");
+      features
+    }
+  }
+}
+
+/// Allowlist for the secrets APIs.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SecretsAllowlistConfig {
+  /// Use this flag to enable all secrets APIs.
+  #[serde(default)]
+  pub all: bool,
+  /// Enables the secrets' `getSecret` API.
+  #[serde(default)]
+  pub get: bool,
+  /// Enables the secrets' `setSecret` API.
+  #[serde(default)]
+  pub set: bool,
+  /// Enables the secrets' `deleteSecret` API.
+  #[serde(default)]
+  pub delete: bool,
+}
+
+impl Allowlist for SecretsAllowlistConfig {
+  fn all_features() -> Vec<&'static str> {
+    let allowlist = Self {
+      all: false,
+      get: true,
+      set: true,
+      delete: true,
+    };
+    let mut features = allowlist.to_features();
+    features.push("secrets-all");
+    features
+  }
+
+  fn to_features(&self) -> Vec<&'static str> {
+    if self.all {
+      vec!["secrets-all"]
+    } else {
+      let mut features = Vec::new();
+      check_feature!(self, features, get, "secrets-get");
+      check_feature!(self, features, set, "secrets-set");
+      check_feature!(self, features, delete, "secrets-delete");
+      features
+    }
+  }
+}
+
+/// Allowlist for the store APIs.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StoreAllowlistConfig {
+  /// Use this flag to enable all store APIs.
+  #[serde(default)]
+  pub all: bool,
+  /// Enables the store's `get`, `has` and `entries` APIs.
+  #[serde(default)]
+  pub get: bool,
+  /// Enables the store's `set` API.
+  #[serde(default)]
+  pub set: bool,
+  /// Enables the store's `remove` API.
+  #[serde(default)]
+  pub remove: bool,
+}
+
+impl Allowlist for StoreAllowlistConfig {
+  fn all_features() -> Vec<&'static str> {
+    let allowlist = Self {
+      all: false,
+      get: true,
+      set: true,
+      remove: true,
+    };
+    let mut features = allowlist.to_features();
+    features.push("store-all");
+    features
+  }
+
+  fn to_features(&self) -> Vec<&'static str> {
+    if self.all {
+      vec!["store-all"]
+    } else {
+      let mut features = Vec::new();
+      check_feature!(self, features, get, "store-get");
+      check_feature!(self, features, set, "store-set");
+      check_feature!(self, features, remove, "store-remove");
+      features
+    }
+  }
+}
+
+/// Allowlist for the log APIs.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LogAllowlistConfig {
+  /// Use this flag to enable all log APIs.
+  #[serde(default)]
+  pub all: bool,
+  /// Enables the log's `trace`, `debug`, `info`, `warn` and `error` APIs.
+  #[serde(default)]
+  pub write: bool,
+}
+
+impl Allowlist for LogAllowlistConfig {
+  fn all_features() -> Vec<&'static str> {
+    let allowlist = Self {
+      all: false,
+      write: true,
+    };
+    let mut features = allowlist.to_features();
+    features.push("log-all");
+    features
+  }
+
+  fn to_features(&self) -> Vec<&'static str> {
+    if self.all {
+      vec!["log-all"]
+    } else {
+      let mut features = Vec::new();
+      check_feature!(self, features, write, "log-write");
       features
     }
   }
@@ -2209,6 +2617,21 @@ pub struct AllowlistConfig {
   /// App APIs allowlist.
   #[serde(default)]
   pub app: AppAllowlistConfig,
+  /// Archive APIs allowlist.
+  #[serde(default)]
+  pub archive: ArchiveAllowlistConfig,
+  /// Menu APIs allowlist.
+  #[serde(default)]
+  pub menu: MenuAllowlistConfig,
+  /// Secrets APIs allowlist.
+  #[serde(default)]
+  pub secrets: SecretsAllowlistConfig,
+  /// Log APIs allowlist.
+  #[serde(default)]
+  pub log: LogAllowlistConfig,
+  /// Store APIs allowlist.
+  #[serde(default)]
+  pub store: StoreAllowlistConfig,
 }
 
 impl Allowlist for AllowlistConfig {
@@ -2227,6 +2650,11 @@ impl Allowlist for AllowlistConfig {
     features.extend(ProcessAllowlistConfig::all_features());
     features.extend(ClipboardAllowlistConfig::all_features());
     features.extend(AppAllowlistConfig::all_features());
+    features.extend(ArchiveAllowlistConfig::all_features());
+    features.extend(MenuAllowlistConfig::all_features());
+    features.extend(SecretsAllowlistConfig::all_features());
+    features.extend(LogAllowlistConfig::all_features());
+    features.extend(StoreAllowlistConfig::all_features());
     features
   }
 
@@ -2248,6 +2676,11 @@ impl Allowlist for AllowlistConfig {
       features.extend(self.process.to_features());
       features.extend(self.clipboard.to_features());
       features.extend(self.app.to_features());
+      features.extend(self.archive.to_features());
+      features.extend(self.menu.to_features());
+      features.extend(self.secrets.to_features());
+      features.extend(self.log.to_features());
+      features.extend(self.store.to_features());
       features
     }
   }
@@ -3150,6 +3583,7 @@ mod build {
       let accept_first_mouse = self.accept_first_mouse;
       let tabbing_identifier = opt_str_lit(self.tabbing_identifier.as_ref());
       let additional_browser_args = opt_str_lit(self.additional_browser_args.as_ref());
+      let restore_state = self.restore_state;
 
       literal_struct!(
         tokens,
@@ -3183,7 +3617,8 @@ mod build {
         hidden_title,
         accept_first_mouse,
         tabbing_identifier,
-        additional_browser_args
+        additional_browser_args,
+        restore_state
       );
     }
   }
@@ -3359,6 +3794,20 @@ mod build {
       let macos = quote!(Default::default());
       let external_bin = opt_vec_str_lit(self.external_bin.as_ref());
       let windows = &self.windows;
+      let deep_link_protocols = opt_lit(
+        self
+          .deep_link_protocols
+          .as_ref()
+          .map(|protocols| vec_lit(protocols, identity))
+          .as_ref(),
+      );
+      let file_associations = opt_lit(
+        self
+          .file_associations
+          .as_ref()
+          .map(|associations| vec_lit(associations, identity))
+          .as_ref(),
+      );
 
       literal_struct!(
         tokens,
@@ -3377,7 +3826,39 @@ mod build {
         deb,
         macos,
         external_bin,
-        windows
+        windows,
+        deep_link_protocols,
+        file_associations
+      );
+    }
+  }
+
+  impl ToTokens for DeepLinkProtocol {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let schemes = vec_lit(&self.schemes, str_lit);
+      let name = opt_str_lit(self.name.as_ref());
+
+      literal_struct!(tokens, DeepLinkProtocol, schemes, name);
+    }
+  }
+
+  impl ToTokens for FileAssociation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let ext = vec_lit(&self.ext, str_lit);
+      // only the extensions are used at runtime
+      let mime_type = quote!(None);
+      let description = quote!(None);
+      let icon = quote!(None);
+      let role = quote!(Default::default());
+
+      literal_struct!(
+        tokens,
+        FileAssociation,
+        ext,
+        mime_type,
+        description,
+        icon,
+        role
       );
     }
   }
@@ -3586,7 +4067,8 @@ mod build {
   impl ToTokens for FsAllowlistConfig {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let scope = &self.scope;
-      tokens.append_all(quote! { ::tauri::utils::config::FsAllowlistConfig { scope: #scope, ..Default::default() } })
+      let persist_scope = self.persist_scope;
+      tokens.append_all(quote! { ::tauri::utils::config::FsAllowlistConfig { scope: #scope, persist_scope: #persist_scope, ..Default::default() } })
     }
   }
 
@@ -3794,6 +4276,8 @@ mod test {
         macos: Default::default(),
         external_bin: None,
         windows: Default::default(),
+        deep_link_protocols: None,
+        file_associations: None,
       },
       cli: None,
       updater: UpdaterConfig {
@@ -97,11 +97,18 @@ fn main() {
       "set-ignore-cursor-events",
       "start-dragging",
       "print",
+      "navigate",
+      "reload",
+      "go-back",
+      "go-forward",
+      "set-zoom",
+      "find-in-page",
+      "popup-menu",
     ],
     api_all,
   );
 
-  alias_module("shell", &["execute", "sidecar", "open"], api_all);
+  alias_module("shell", &["execute", "sidecar", "open", "pty"], api_all);
   // helper for the command module macro
   let shell_script = has_feature("shell-execute") || has_feature("shell-sidecar");
   alias("shell_script", shell_script);
@@ -110,7 +117,7 @@ fn main() {
   if !mobile {
     alias_module(
       "dialog",
-      &["open", "save", "message", "ask", "confirm"],
+      &["open", "save", "message", "ask", "confirm", "custom"],
       api_all,
     );
   }
@@ -130,10 +137,41 @@ fn main() {
 
   alias_module("process", &["relaunch", "exit"], api_all);
 
-  alias_module("clipboard", &["write-text", "read-text"], api_all);
+  alias_module(
+    "clipboard",
+    &[
+      "write-text",
+      "read-text",
+      "write-image",
+      "read-image",
+      "write-html",
+      "read-html",
+      "write-files",
+      "read-files",
+      "write-data",
+      "read-data",
+      "clear",
+      "watch",
+    ],
+    api_all,
+  );
 
   alias_module("app", &["show", "hide"], api_all);
 
+  alias_module("archive", &["extract", "create"], api_all);
+
+  alias_module(
+    "menu",
+    &["set-window-menu", "set-tray-menu", "update-item"],
+    api_all,
+  );
+
+  alias_module("secrets", &["get", "set", "delete"], api_all);
+
+  alias_module("log", &["write"], api_all);
+
+  alias_module("store", &["get", "set", "remove"], api_all);
+
   let checked_features_out_path =
     Path::new(&std::env::var("OUT_DIR").unwrap()).join("checked_features");
   std::fs::write(
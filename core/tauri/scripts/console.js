@@ -0,0 +1,55 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+;(function () {
+  var levels = {
+    trace: 'trace',
+    debug: 'debug',
+    log: 'info',
+    info: 'info',
+    warn: 'warn',
+    error: 'error'
+  }
+  // the logging of an IPC failure must not be sent to the IPC again
+  var logging = false
+
+  function stringify(arg) {
+    if (typeof arg === 'string') {
+      return arg
+    }
+    if (arg instanceof Error) {
+      return arg.stack || String(arg)
+    }
+    try {
+      return JSON.stringify(arg)
+    } catch (_) {
+      return String(arg)
+    }
+  }
+
+  Object.keys(levels).forEach(function (method) {
+    var original = console[method]
+    console[method] = function () {
+      original.apply(console, arguments)
+      if (logging) {
+        return
+      }
+      logging = true
+      try {
+        window
+          .__TAURI_INVOKE__('tauri', {
+            __tauriModule: 'Log',
+            message: {
+              cmd: 'log',
+              level: levels[method],
+              message: Array.prototype.map.call(arguments, stringify).join(' ')
+            }
+          })
+          .catch(function () {})
+      } finally {
+        logging = false
+      }
+    }
+  })
+})()
@@ -0,0 +1,349 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Launching the app when the user logs in.
+//!
+//! The app is registered with an XDG autostart entry on Linux, a launch agent on macOS
+//! and a value on the `HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Run` registry key on Windows.
+
+use std::path::PathBuf;
+
+/// Manages launching the app when the user logs in.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tauri::api::autostart::AutoStart;
+///
+/// tauri::Builder::default()
+///   .setup(|app| {
+///     let autostart = AutoStart::new(&app.config().tauri.bundle.identifier)?
+///       .args(["--minimized"]);
+///     if !autostart.is_enabled()? {
+///       autostart.enable()?;
+///     }
+///     Ok(())
+///   });
+/// ```
+#[derive(Debug, Clone)]
+pub struct AutoStart {
+  identifier: String,
+  executable: PathBuf,
+  args: Vec<String>,
+}
+
+impl AutoStart {
+  /// Creates the autostart manager of the current executable.
+  ///
+  /// The identifier names the autostart entry, use the bundle identifier of the app
+  /// so it doesn't conflict with other apps.
+  pub fn new(identifier: impl Into<String>) -> crate::api::Result<Self> {
+    // the executable of an AppImage is inside a temporary mount point
+    #[cfg(target_os = "linux")]
+    let executable = match std::env::var_os("APPIMAGE") {
+      Some(appimage) => PathBuf::from(appimage),
+      None => std::env::current_exe()?,
+    };
+    #[cfg(not(target_os = "linux"))]
+    let executable = std::env::current_exe()?;
+
+    Ok(Self {
+      identifier: identifier.into(),
+      executable,
+      args: Vec::new(),
+    })
+  }
+
+  /// Sets the arguments the app is launched with at login, e.g. `--minimized`.
+  #[must_use]
+  pub fn args<I, S>(mut self, args: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    self.args = args.into_iter().map(Into::into).collect();
+    self
+  }
+
+  /// Registers the app to launch at login, updating the arguments of an existing registration.
+  pub fn enable(&self) -> crate::api::Result<()> {
+    imp::enable(self)
+  }
+
+  /// Unregisters the app from launching at login. Does nothing if it is not registered.
+  pub fn disable(&self) -> crate::api::Result<()> {
+    imp::disable(self)
+  }
+
+  /// Whether the app is registered to launch at login.
+  pub fn is_enabled(&self) -> crate::api::Result<bool> {
+    imp::is_enabled(self)
+  }
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+mod imp {
+  use super::AutoStart;
+  use std::{fs, io, path::PathBuf};
+
+  fn entry_path(autostart: &AutoStart) -> crate::api::Result<PathBuf> {
+    let config_dir = dirs_next::config_dir().ok_or_else(|| {
+      crate::api::Error::Path("failed to resolve the user config directory".into())
+    })?;
+    Ok(
+      config_dir
+        .join("autostart")
+        .join(format!("{}.desktop", autostart.identifier)),
+    )
+  }
+
+  /// Quotes an argument of the `Exec` key of a desktop entry.
+  ///
+  /// Backslashes are escaped twice since the value is unescaped as a string
+  /// before the arguments are unquoted, and `%` is escaped from the field codes.
+  fn quote(arg: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in arg.chars() {
+      match c {
+        '"' | '`' | '$' => quoted.push('\\'),
+        '\\' => quoted.push_str("\\\\\\"),
+        '%' => quoted.push('%'),
+        _ => {}
+      }
+      quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+  }
+
+  pub fn enable(autostart: &AutoStart) -> crate::api::Result<()> {
+    let path = entry_path(autostart)?;
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+    let exec = std::iter::once(autostart.executable.to_string_lossy().into_owned())
+      .chain(autostart.args.iter().cloned())
+      .map(|arg| quote(&arg))
+      .collect::<Vec<_>>()
+      .join(" ");
+    let name = autostart
+      .executable
+      .file_stem()
+      .map(|stem| stem.to_string_lossy().into_owned())
+      .unwrap_or_else(|| autostart.identifier.clone());
+    // For more information about the format of this file, see
+    // https://specifications.freedesktop.org/autostart-spec/autostart-spec-latest.html
+    fs::write(
+      path,
+      format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec={}\nTerminal=false\nX-GNOME-Autostart-enabled=true\n",
+        name, exec
+      ),
+    )?;
+    Ok(())
+  }
+
+  pub fn disable(autostart: &AutoStart) -> crate::api::Result<()> {
+    match fs::remove_file(entry_path(autostart)?) {
+      Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+      _ => Ok(()),
+    }
+  }
+
+  pub fn is_enabled(autostart: &AutoStart) -> crate::api::Result<bool> {
+    Ok(entry_path(autostart)?.exists())
+  }
+
+  #[cfg(test)]
+  mod tests {
+    #[test]
+    fn quote() {
+      assert_eq!(super::quote("/opt/my app/app"), "\"/opt/my app/app\"");
+      assert_eq!(super::quote("--name=\"$x\""), "\"--name=\\\"\\$x\\\"\"");
+      assert_eq!(super::quote("C:\\dir"), "\"C:\\\\\\\\dir\"");
+      assert_eq!(super::quote("--progress=100%"), "\"--progress=100%%\"");
+    }
+  }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+  use super::AutoStart;
+  use std::{fs, io, path::PathBuf};
+
+  fn agent_path(autostart: &AutoStart) -> crate::api::Result<PathBuf> {
+    let home_dir = dirs_next::home_dir()
+      .ok_or_else(|| crate::api::Error::Path("failed to resolve the home directory".into()))?;
+    Ok(
+      home_dir
+        .join("Library/LaunchAgents")
+        .join(format!("{}.plist", autostart.identifier)),
+    )
+  }
+
+  fn escape(value: &str) -> String {
+    value
+      .replace('&', "&amp;")
+      .replace('<', "&lt;")
+      .replace('>', "&gt;")
+  }
+
+  pub fn enable(autostart: &AutoStart) -> crate::api::Result<()> {
+    let path = agent_path(autostart)?;
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+    let program_arguments = std::iter::once(autostart.executable.to_string_lossy().into_owned())
+      .chain(autostart.args.iter().cloned())
+      .map(|arg| format!("    <string>{}</string>\n", escape(&arg)))
+      .collect::<String>();
+    fs::write(
+      path,
+      format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+  <key>Label</key>
+  <string>{}</string>
+  <key>ProgramArguments</key>
+  <array>
+{}  </array>
+  <key>RunAtLoad</key>
+  <true/>
+</dict>
+</plist>
+"#,
+        escape(&autostart.identifier),
+        program_arguments
+      ),
+    )?;
+    Ok(())
+  }
+
+  pub fn disable(autostart: &AutoStart) -> crate::api::Result<()> {
+    match fs::remove_file(agent_path(autostart)?) {
+      Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+      _ => Ok(()),
+    }
+  }
+
+  pub fn is_enabled(autostart: &AutoStart) -> crate::api::Result<bool> {
+    Ok(agent_path(autostart)?.exists())
+  }
+}
+
+#[cfg(windows)]
+mod imp {
+  use super::AutoStart;
+  use std::{ffi::OsStr, io, os::windows::ffi::OsStrExt};
+
+  use windows::{
+    core::PCWSTR,
+    Win32::{
+      Foundation::{ERROR_FILE_NOT_FOUND, ERROR_SUCCESS, WIN32_ERROR},
+      System::Registry::{
+        RegDeleteKeyValueW, RegGetValueW, RegSetKeyValueW, HKEY_CURRENT_USER, REG_SZ, RRF_RT_REG_SZ,
+      },
+    },
+  };
+
+  const RUN_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Run";
+
+  fn encode(value: impl AsRef<OsStr>) -> Vec<u16> {
+    value
+      .as_ref()
+      .encode_wide()
+      .chain(std::iter::once(0))
+      .collect()
+  }
+
+  fn check(error: WIN32_ERROR) -> crate::api::Result<()> {
+    if error == ERROR_SUCCESS {
+      Ok(())
+    } else {
+      Err(io::Error::from_raw_os_error(error.0 as i32).into())
+    }
+  }
+
+  /// Quotes an argument following the rules of `CommandLineToArgvW`.
+  fn quote(arg: &str) -> String {
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0;
+    for c in arg.chars() {
+      match c {
+        '\\' => backslashes += 1,
+        '"' => {
+          quoted.extend(std::iter::repeat('\\').take(backslashes * 2 + 1));
+          backslashes = 0;
+        }
+        _ => {
+          quoted.extend(std::iter::repeat('\\').take(backslashes));
+          backslashes = 0;
+        }
+      }
+      if c != '\\' {
+        quoted.push(c);
+      }
+    }
+    quoted.extend(std::iter::repeat('\\').take(backslashes * 2));
+    quoted.push('"');
+    quoted
+  }
+
+  pub fn enable(autostart: &AutoStart) -> crate::api::Result<()> {
+    let command = std::iter::once(autostart.executable.to_string_lossy().into_owned())
+      .chain(autostart.args.iter().cloned())
+      .map(|arg| quote(&arg))
+      .collect::<Vec<_>>()
+      .join(" ");
+    let data = encode(command);
+    let key = encode(RUN_KEY);
+    let name = encode(&autostart.identifier);
+    check(unsafe {
+      RegSetKeyValueW(
+        HKEY_CURRENT_USER,
+        PCWSTR(key.as_ptr()),
+        PCWSTR(name.as_ptr()),
+        REG_SZ.0,
+        data.as_ptr() as *const _,
+        (data.len() * std::mem::size_of::<u16>()) as u32,
+      )
+    })
+  }
+
+  pub fn disable(autostart: &AutoStart) -> crate::api::Result<()> {
+    let key = encode(RUN_KEY);
+    let name = encode(&autostart.identifier);
+    match unsafe {
+      RegDeleteKeyValueW(
+        HKEY_CURRENT_USER,
+        PCWSTR(key.as_ptr()),
+        PCWSTR(name.as_ptr()),
+      )
+    } {
+      ERROR_FILE_NOT_FOUND => Ok(()),
+      error => check(error),
+    }
+  }
+
+  pub fn is_enabled(autostart: &AutoStart) -> crate::api::Result<bool> {
+    let key = encode(RUN_KEY);
+    let name = encode(&autostart.identifier);
+    match unsafe {
+      RegGetValueW(
+        HKEY_CURRENT_USER,
+        PCWSTR(key.as_ptr()),
+        PCWSTR(name.as_ptr()),
+        RRF_RT_REG_SZ,
+        std::ptr::null_mut(),
+        std::ptr::null_mut(),
+        std::ptr::null_mut(),
+      )
+    } {
+      ERROR_FILE_NOT_FOUND => Ok(false),
+      error => check(error).map(|_| true),
+    }
+  }
+}
@@ -37,7 +37,7 @@ mod clapfix {
 use clapfix::{ClapCommand as App, ErrorExt};
 
 /// The resolution of a argument match.
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Clone, Serialize)]
 #[non_exhaustive]
 pub struct ArgData {
   /// - [`Value::Bool`] if it's a flag,
@@ -51,7 +51,7 @@ pub struct ArgData {
 }
 
 /// The matched subcommand.
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Clone, Serialize)]
 #[non_exhaustive]
 pub struct SubcommandMatches {
   /// The subcommand name.
@@ -61,7 +61,7 @@ pub struct SubcommandMatches {
 }
 
 /// The argument matches of a command.
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Clone, Serialize)]
 #[non_exhaustive]
 pub struct Matches {
   /// Data structure mapping each found arg with its resolution.
@@ -98,13 +98,29 @@ impl Matches {
 ///   });
 /// ```
 pub fn get_matches(cli: &CliConfig, package_info: &PackageInfo) -> crate::api::Result<Matches> {
+  get_matches_from(cli, package_info, std::env::args_os())
+}
+
+/// Gets the argument matches of the CLI definition for the given arguments, starting with the executable path.
+///
+/// Use it to parse the arguments of another launch of the app, such as the ones forwarded
+/// by [`RunEvent::SecondInstance`](crate::RunEvent#variant.SecondInstance).
+pub fn get_matches_from<I, T>(
+  cli: &CliConfig,
+  package_info: &PackageInfo,
+  args: I,
+) -> crate::api::Result<Matches>
+where
+  I: IntoIterator<Item = T>,
+  T: Into<std::ffi::OsString> + Clone,
+{
   let about = cli
     .description()
     .unwrap_or(&package_info.description.to_string())
     .to_string();
   let version = &*package_info.version.to_string();
   let app = get_app(package_info, version, &package_info.name, Some(&about), cli);
-  match app.try_get_matches() {
+  match app.try_get_matches_from(args) {
     Ok(matches) => Ok(get_matches_internal(cli, &matches)),
     Err(e) => match ErrorExt::kind(&e) {
       ErrorKind::DisplayHelp => {
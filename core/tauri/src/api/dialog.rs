@@ -10,6 +10,8 @@
 
 pub use nonblocking::*;
 
+use serde::Serialize;
+
 #[cfg(not(target_os = "linux"))]
 macro_rules! run_dialog {
   ($e:expr, $h: ident) => {{
@@ -221,6 +223,243 @@ impl From<MessageDialogKind> for rfd::MessageLevel {
   }
 }
 
+macro_rules! custom_dialog_builder {
+  () => {
+    /// A builder for dialogs with custom buttons, a text input and a checkbox.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS:** At most two buttons are supported, and the text input and checkbox are unsupported.
+    ///   Showing a dialog with unsupported options returns an error.
+    pub struct CustomDialogBuilder(super::CustomDialog);
+
+    impl CustomDialogBuilder {
+      /// Creates a new custom dialog builder.
+      pub fn new(title: impl AsRef<str>, message: impl AsRef<str>) -> Self {
+        Self(super::CustomDialog::new(title.as_ref(), message.as_ref()))
+      }
+
+      /// Set parent windows explicitly (optional)
+      ///
+      /// ## Platform-specific
+      ///
+      /// - **Linux:** Unsupported, use [`Self::parent_window`] instead.
+      #[allow(unused_variables, unused_mut)]
+      pub fn parent<W: raw_window_handle::HasRawWindowHandle>(mut self, parent: &W) -> Self {
+        #[cfg(not(target_os = "linux"))]
+        {
+          self.0.fallback = self.0.fallback.set_parent(parent);
+        }
+        self
+      }
+
+      /// Sets the parent window of the dialog.
+      #[allow(unused_mut)]
+      pub fn parent_window<R: Runtime>(mut self, window: &Window<R>) -> Self {
+        #[cfg(target_os = "linux")]
+        {
+          self.0.parent = window.gtk_window().ok().map(super::GtkParent);
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+          self.0.fallback = self.0.fallback.set_parent(window);
+        }
+        self
+      }
+
+      /// Adds a button to the dialog. The response reports the index of the clicked button.
+      ///
+      /// A single `OK` button is shown if no buttons are added.
+      pub fn button(mut self, label: impl Into<String>) -> Self {
+        self.0.buttons.push(label.into());
+        self
+      }
+
+      /// Adds the buttons to the dialog, e.g. `["Save", "Don't Save", "Cancel"]`.
+      pub fn buttons<I: IntoIterator<Item = S>, S: Into<String>>(mut self, labels: I) -> Self {
+        self.0.buttons.extend(labels.into_iter().map(Into::into));
+        self
+      }
+
+      /// Shows a text input with the given default value.
+      pub fn input(mut self, default_value: impl Into<String>) -> Self {
+        self.0.input.replace(default_value.into());
+        self
+      }
+
+      /// Shows a checkbox, e.g. "Don't ask again".
+      pub fn checkbox(mut self, label: impl Into<String>, checked: bool) -> Self {
+        self.0.checkbox.replace((label.into(), checked));
+        self
+      }
+
+      /// Set type of a dialog.
+      pub fn kind(mut self, kind: MessageDialogKind) -> Self {
+        self.0.kind = kind;
+        self
+      }
+    }
+  };
+}
+
+/// The response of a custom dialog.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomDialogResponse {
+  /// The index of the clicked button, or `None` if the dialog was dismissed.
+  pub button: Option<usize>,
+  /// The text input value, if the dialog has a text input.
+  pub input: Option<String>,
+  /// Whether the checkbox is checked.
+  pub checked: bool,
+}
+
+/// The parent window of a custom dialog.
+#[cfg(target_os = "linux")]
+struct GtkParent(gtk::ApplicationWindow);
+
+// the window is only used by the dialog, which runs on the main thread
+#[cfg(target_os = "linux")]
+unsafe impl Send for GtkParent {}
+
+struct CustomDialog {
+  title: String,
+  message: String,
+  kind: MessageDialogKind,
+  buttons: Vec<String>,
+  input: Option<String>,
+  checkbox: Option<(String, bool)>,
+  #[cfg(target_os = "linux")]
+  parent: Option<GtkParent>,
+  /// The rfd dialog used on platforms without a native implementation, holding the parent window.
+  #[cfg(not(target_os = "linux"))]
+  fallback: rfd::MessageDialog,
+}
+
+impl CustomDialog {
+  fn new(title: &str, message: &str) -> Self {
+    Self {
+      title: title.into(),
+      message: message.into(),
+      kind: MessageDialogKind::Info,
+      buttons: Vec::new(),
+      input: None,
+      checkbox: None,
+      #[cfg(target_os = "linux")]
+      parent: None,
+      #[cfg(not(target_os = "linux"))]
+      fallback: rfd::MessageDialog::new(),
+    }
+  }
+
+  #[cfg(target_os = "linux")]
+  fn show(self) -> CustomDialogResponse {
+    use gtk::prelude::*;
+
+    let dialog = gtk::MessageDialog::new(
+      self.parent.as_ref().map(|parent| &parent.0),
+      gtk::DialogFlags::MODAL,
+      match self.kind {
+        MessageDialogKind::Info => gtk::MessageType::Info,
+        MessageDialogKind::Warning => gtk::MessageType::Warning,
+        MessageDialogKind::Error => gtk::MessageType::Error,
+      },
+      gtk::ButtonsType::None,
+      &self.message,
+    );
+    dialog.set_title(&self.title);
+
+    let buttons = if self.buttons.is_empty() {
+      vec!["OK".to_string()]
+    } else {
+      self.buttons
+    };
+    for (i, label) in buttons.iter().enumerate() {
+      dialog.add_button(label, gtk::ResponseType::Other(i as u16));
+    }
+    dialog.set_default_response(gtk::ResponseType::Other(0));
+
+    let content_area = dialog.content_area();
+    let entry = self.input.map(|value| {
+      let entry = gtk::Entry::new();
+      entry.set_text(&value);
+      entry.set_activates_default(true);
+      content_area.pack_start(&entry, false, false, 0);
+      entry
+    });
+    let checkbox = self.checkbox.map(|(label, checked)| {
+      let checkbox = gtk::CheckButton::with_label(&label);
+      checkbox.set_active(checked);
+      content_area.pack_start(&checkbox, false, false, 0);
+      checkbox
+    });
+
+    dialog.show_all();
+    let response = dialog.run();
+    let response = CustomDialogResponse {
+      button: match response {
+        gtk::ResponseType::Other(i) => Some(i as usize),
+        _ => None,
+      },
+      input: entry.map(|entry| entry.text().to_string()),
+      checked: checkbox.map(|c| c.is_active()).unwrap_or_default(),
+    };
+    dialog.close();
+    response
+  }
+
+  /// Returns an error if the dialog uses options the platform can't show.
+  fn check_supported(&self) -> crate::api::Result<()> {
+    #[cfg(not(target_os = "linux"))]
+    {
+      let unsupported = if self.buttons.len() > 2 {
+        Some("more than two buttons")
+      } else if self.input.is_some() {
+        Some("a text input")
+      } else if self.checkbox.is_some() {
+        Some("a checkbox")
+      } else {
+        None
+      };
+      if let Some(option) = unsupported {
+        return Err(crate::api::Error::Dialog(format!(
+          "custom dialogs with {} are not supported on this platform",
+          option
+        )));
+      }
+    }
+    Ok(())
+  }
+
+  #[cfg(not(target_os = "linux"))]
+  fn show(self) -> CustomDialogResponse {
+    let mut buttons = self.buttons.into_iter();
+    let (buttons, cancellable) = match (buttons.next(), buttons.next()) {
+      (None, _) => (rfd::MessageButtons::Ok, false),
+      (Some(ok), None) => (rfd::MessageButtons::OkCustom(ok), false),
+      (Some(ok), Some(cancel)) => (rfd::MessageButtons::OkCancelCustom(ok, cancel), true),
+    };
+    let ok = self
+      .fallback
+      .set_title(&self.title)
+      .set_description(&self.message)
+      .set_level(self.kind.into())
+      .set_buttons(buttons)
+      .show();
+    CustomDialogResponse {
+      button: if ok {
+        Some(0)
+      } else if cancellable {
+        Some(1)
+      } else {
+        None
+      },
+      input: None,
+      checked: false,
+    }
+  }
+}
+
 /// Blocking interfaces for the dialog APIs.
 ///
 /// The blocking APIs will block the current thread to execute instead of relying on callback closures,
@@ -229,13 +468,14 @@ impl From<MessageDialogKind> for rfd::MessageLevel {
 /// **NOTE:** You cannot block the main thread when executing the dialog APIs, so you must use the [`crate::api::dialog`] methods instead.
 /// Examples of main thread context are the [`crate::App::run`] closure and non-async commands.
 pub mod blocking {
-  use super::{MessageDialogButtons, MessageDialogKind};
+  use super::{CustomDialogResponse, MessageDialogButtons, MessageDialogKind};
   use crate::{Runtime, Window};
   use std::path::{Path, PathBuf};
   use std::sync::mpsc::sync_channel;
 
   file_dialog_builder!();
   message_dialog_builder!();
+  custom_dialog_builder!();
 
   impl FileDialogBuilder {
     /// Shows the dialog to select a single file.
@@ -372,6 +612,63 @@ pub mod blocking {
     }
   }
 
+  impl CustomDialogBuilder {
+    /// Shows the dialog and waits for it to be closed.
+    ///
+    /// Returns an error without showing the dialog if the platform doesn't support its options.
+    pub fn show(self) -> crate::api::Result<CustomDialogResponse> {
+      self.0.check_supported()?;
+      let (tx, rx) = sync_channel(1);
+      let f = move |response| {
+        tx.send(response).unwrap();
+      };
+      let dialog = self.0;
+      run_dialog!(dialog.show(), f);
+      Ok(rx.recv().unwrap())
+    }
+  }
+
+  /// Displays a dialog with a message, a text input and "ok" and "cancel" buttons and wait for it to be closed.
+  ///
+  /// Returns the input value if the "ok" button was clicked.
+  ///
+  /// This is a blocking operation,
+  /// and should *NOT* be used when running on the main thread context.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / macOS:** Unsupported, returns an error.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::api::dialog::blocking::prompt;
+  /// # let app = tauri::Builder::default().build(tauri::generate_context!("test/fixture/src-tauri/tauri.conf.json")).unwrap();
+  /// # let window = tauri::Manager::get_window(&app, "main").unwrap();
+  /// let name = prompt(Some(&window), "Tauri", "What is your name?", "")?;
+  /// // do something with `name`
+  /// # Ok::<(), tauri::api::Error>(())
+  /// ```
+  pub fn prompt<R: Runtime>(
+    parent_window: Option<&Window<R>>,
+    title: impl AsRef<str>,
+    message: impl AsRef<str>,
+    default_value: impl Into<String>,
+  ) -> crate::api::Result<Option<String>> {
+    let mut builder = CustomDialogBuilder::new(title, message)
+      .buttons(["OK", "Cancel"])
+      .input(default_value);
+    if let Some(window) = parent_window {
+      builder = builder.parent_window(window);
+    }
+    let response = builder.show()?;
+    Ok(if response.button == Some(0) {
+      response.input
+    } else {
+      None
+    })
+  }
+
   /// Displays a dialog with a message and an optional title with a "yes" and a "no" button and wait for it to be closed.
   ///
   /// This is a blocking operation,
@@ -463,11 +760,12 @@ pub mod blocking {
 }
 
 mod nonblocking {
-  use super::{MessageDialogButtons, MessageDialogKind};
+  use super::{CustomDialogResponse, MessageDialogButtons, MessageDialogKind};
   use crate::{Runtime, Window};
   use std::path::{Path, PathBuf};
 
   file_dialog_builder!();
+  custom_dialog_builder!();
   message_dialog_builder!();
 
   impl FileDialogBuilder {
@@ -610,6 +908,65 @@ mod nonblocking {
     }
   }
 
+  impl CustomDialogBuilder {
+    /// Shows the dialog, calling the closure with the response when it is closed.
+    ///
+    /// Returns an error without showing the dialog if the platform doesn't support its options.
+    pub fn show<F: FnOnce(CustomDialogResponse) + Send + 'static>(
+      self,
+      f: F,
+    ) -> crate::api::Result<()> {
+      self.0.check_supported()?;
+      let dialog = self.0;
+      run_dialog!(dialog.show(), f);
+      Ok(())
+    }
+  }
+
+  /// Displays a non-blocking dialog with a message, a text input and "ok" and "cancel" buttons.
+  ///
+  /// The closure is called with the input value if the "ok" button was clicked.
+  ///
+  /// This is not a blocking operation,
+  /// and should be used when running on the main thread to avoid deadlocks with the event loop.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / macOS:** Unsupported, returns an error without calling the closure.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::api::dialog::prompt;
+  /// # let app = tauri::Builder::default().build(tauri::generate_context!("test/fixture/src-tauri/tauri.conf.json")).unwrap();
+  /// # let window = tauri::Manager::get_window(&app, "main").unwrap();
+  /// prompt(Some(&window), "Tauri", "What is your name?", "", |name| {
+  ///   // do something with `name`
+  /// })?;
+  /// # Ok::<(), tauri::api::Error>(())
+  /// ```
+  pub fn prompt<R: Runtime, F: FnOnce(Option<String>) + Send + 'static>(
+    parent_window: Option<&Window<R>>,
+    title: impl AsRef<str>,
+    message: impl AsRef<str>,
+    default_value: impl Into<String>,
+    f: F,
+  ) -> crate::api::Result<()> {
+    let mut builder = CustomDialogBuilder::new(title, message)
+      .buttons(["OK", "Cancel"])
+      .input(default_value);
+    if let Some(window) = parent_window {
+      builder = builder.parent_window(window);
+    }
+    builder.show(|response| {
+      f(if response.button == Some(0) {
+        response.input
+      } else {
+        None
+      })
+    })
+  }
+
   /// Displays a non-blocking dialog with a message and an optional title with a "yes" and a "no" button.
   ///
   /// This is not a blocking operation,
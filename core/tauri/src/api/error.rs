@@ -67,10 +67,28 @@ pub enum Error {
   #[cfg(feature = "fs-extract-api")]
   #[error("Failed to extract: {0}")]
   Extract(String),
+  /// Path not allowed by the scope.
+  #[error("path not allowed on the configured scope: {0}")]
+  PathNotAllowed(std::path::PathBuf),
+  /// Error from the credential store of the operating system.
+  #[cfg(all(desktop, feature = "secrets-api"))]
+  #[cfg_attr(doc_cfg, doc(cfg(all(desktop, feature = "secrets-api"))))]
+  #[error("secret store error: {0}")]
+  Secrets(String),
   /// Notification error.
   #[cfg(notification_all)]
   #[error(transparent)]
   Notification(#[from] notify_rust::error::Error),
+  /// Notification actions are not supported on this platform.
+  #[cfg(all(desktop, feature = "notification"))]
+  #[cfg_attr(doc_cfg, doc(cfg(all(desktop, feature = "notification"))))]
+  #[error("notification actions are not supported on this platform")]
+  NotificationActionsUnsupported,
+  /// Too many notifications are waiting for their events.
+  #[cfg(all(desktop, feature = "notification"))]
+  #[cfg_attr(doc_cfg, doc(cfg(all(desktop, feature = "notification"))))]
+  #[error("too many notifications are waiting for their events")]
+  TooManyNotificationListeners,
   /// Url error.
   #[error(transparent)]
   Url(#[from] url::ParseError),
@@ -4,6 +4,8 @@
 
 //! Types and functions related to file operations.
 
+#[cfg(feature = "fs-archive-api")]
+mod compress;
 #[cfg(feature = "fs-extract-api")]
 mod extract;
 mod file_move;
@@ -13,6 +15,8 @@ use std::{
   path::{Display, Path},
 };
 
+#[cfg(feature = "fs-archive-api")]
+pub use compress::*;
 #[cfg(feature = "fs-extract-api")]
 pub use extract::*;
 pub use file_move::*;
@@ -0,0 +1,268 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use super::{ArchiveFormat, Compression};
+
+use ignore::WalkBuilder;
+use std::{
+  fs,
+  io::{self, Seek, Write},
+  path::{Path, PathBuf},
+};
+
+/// A file or directory that is going to be written to the archive.
+struct CompressEntry {
+  source: PathBuf,
+  name: PathBuf,
+  is_dir: bool,
+  /// Symbolic links are stored as links, so they can't add files from outside of the added paths.
+  is_symlink: bool,
+  size: u64,
+}
+
+/// The compress manager to create archives from files and directories.
+#[derive(Debug)]
+pub struct Compress {
+  archive_format: ArchiveFormat,
+  paths: Vec<(PathBuf, PathBuf)>,
+}
+
+impl Compress {
+  /// Creates a new archive with the given format.
+  pub fn new(archive_format: ArchiveFormat) -> Self {
+    Self {
+      archive_format,
+      paths: Vec::new(),
+    }
+  }
+
+  /// Adds a file or directory to the archive, stored under `name`.
+  ///
+  /// Directories are added recursively. Symbolic links are stored as links, their targets are not added.
+  #[must_use]
+  pub fn add<P: Into<PathBuf>, N: Into<PathBuf>>(mut self, path: P, name: N) -> Self {
+    self.paths.push((path.into(), name.into()));
+    self
+  }
+
+  /// Lists the files and directories that are going to be written to the archive,
+  /// including the content of the added directories.
+  pub fn files(&self) -> crate::api::Result<Vec<PathBuf>> {
+    Ok(
+      self
+        .entries()?
+        .into_iter()
+        .map(|entry| entry.source)
+        .collect(),
+    )
+  }
+
+  /// Writes the archive to the given writer.
+  pub fn write_to<W: Write + Seek>(&self, writer: W) -> crate::api::Result<()> {
+    self.write_to_with(writer, |_, _| Ok(()))
+  }
+
+  /// Same as [`Self::write_to`], but calls `on_entry` before each entry is written.
+  ///
+  /// The callback receives the path of the file being added to the archive
+  /// and the amount of bytes written so far. Returning an error aborts the operation.
+  pub fn write_to_with<W: Write + Seek, F: FnMut(&Path, u64) -> crate::api::Result<()>>(
+    &self,
+    writer: W,
+    mut on_entry: F,
+  ) -> crate::api::Result<()> {
+    let entries = self.entries()?;
+    match self.archive_format {
+      ArchiveFormat::Tar(None) => {
+        write_tar(writer, &entries, &mut on_entry)?.flush()?;
+      }
+      ArchiveFormat::Tar(Some(Compression::Gz)) => {
+        let encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+        write_tar(encoder, &entries, &mut on_entry)?.finish()?;
+      }
+      ArchiveFormat::Zip => {
+        let mut archive = zip::ZipWriter::new(writer);
+        let mut written = 0;
+        for entry in &entries {
+          on_entry(&entry.source, written)?;
+          #[allow(unused_mut)]
+          let mut options = zip::write::FileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .large_file(entry.size > u32::MAX as u64);
+          #[cfg(unix)]
+          {
+            use std::os::unix::fs::PermissionsExt;
+            options =
+              options.unix_permissions(fs::symlink_metadata(&entry.source)?.permissions().mode());
+          }
+          let name = zip_entry_name(&entry.name);
+          if entry.is_dir {
+            archive.add_directory(name, options)?;
+          } else if entry.is_symlink {
+            let target = fs::read_link(&entry.source)?;
+            archive.add_symlink(name, target.to_string_lossy(), options)?;
+          } else {
+            archive.start_file(name, options)?;
+            io::copy(&mut fs::File::open(&entry.source)?, &mut archive)?;
+            written += entry.size;
+          }
+        }
+        archive.finish()?.flush()?;
+      }
+    }
+    Ok(())
+  }
+
+  // Lists all files and directories that are going to be added to the archive.
+  fn entries(&self) -> crate::api::Result<Vec<CompressEntry>> {
+    let mut entries = Vec::new();
+    for (path, name) in &self.paths {
+      // a symbolic link to a directory is not walked
+      let metadata = fs::symlink_metadata(path)?;
+      if metadata.is_dir() {
+        // standard filters would skip hidden and ignored files
+        let walkdir = WalkBuilder::new(path).standard_filters(false).build();
+        for entry in walkdir {
+          let entry = entry?;
+          let entry_name = name.join(entry.path().strip_prefix(path)?);
+          // the root directory is not stored when the directory is added without a name
+          if entry_name.components().next().is_none() {
+            continue;
+          }
+          // the walker doesn't follow links, so this is the metadata of the link itself
+          let metadata = entry.metadata()?;
+          entries.push(CompressEntry {
+            source: entry.path().to_path_buf(),
+            name: entry_name,
+            is_dir: metadata.is_dir(),
+            is_symlink: metadata.file_type().is_symlink(),
+            size: metadata.len(),
+          });
+        }
+      } else {
+        entries.push(CompressEntry {
+          source: path.clone(),
+          name: name.clone(),
+          is_dir: false,
+          is_symlink: metadata.file_type().is_symlink(),
+          size: metadata.len(),
+        });
+      }
+    }
+    Ok(entries)
+  }
+}
+
+fn write_tar<W: Write, F: FnMut(&Path, u64) -> crate::api::Result<()>>(
+  writer: W,
+  entries: &[CompressEntry],
+  on_entry: &mut F,
+) -> crate::api::Result<W> {
+  let mut builder = tar::Builder::new(writer);
+  builder.follow_symlinks(false);
+  let mut written = 0;
+  for entry in entries {
+    on_entry(&entry.source, written)?;
+    if entry.is_dir {
+      builder.append_dir(&entry.name, &entry.source)?;
+    } else {
+      builder.append_path_with_name(&entry.source, &entry.name)?;
+      if !entry.is_symlink {
+        written += entry.size;
+      }
+    }
+  }
+  builder.into_inner().map_err(Into::into)
+}
+
+// Zip entries always use `/` as separator.
+fn zip_entry_name(name: &Path) -> String {
+  name
+    .components()
+    .map(|c| c.as_os_str().to_string_lossy())
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+#[cfg(test)]
+mod test {
+  use super::Compress;
+  use crate::api::file::{ArchiveFormat, Compression, Extract};
+  use std::fs;
+
+  #[test]
+  fn create_and_extract() {
+    let dir = tempfile::tempdir().unwrap();
+    let source = dir.path().join("source");
+    fs::create_dir_all(source.join("nested")).unwrap();
+    fs::write(source.join("file.txt"), "archived").unwrap();
+    fs::write(source.join("nested").join(".hidden"), [0; 1024]).unwrap();
+
+    for (format, name) in [
+      (ArchiveFormat::Zip, "archive.zip"),
+      (ArchiveFormat::Tar(None), "archive.tar"),
+      (ArchiveFormat::Tar(Some(Compression::Gz)), "archive.tar.gz"),
+    ] {
+      let archive = dir.path().join(name);
+      Compress::new(format)
+        .add(&source, "source")
+        .write_to(fs::File::create(&archive).unwrap())
+        .unwrap();
+
+      let destination = dir.path().join(format!("{name}-extracted"));
+      Extract::from_cursor(fs::File::open(&archive).unwrap(), format)
+        .extract_into(&destination)
+        .unwrap();
+      assert_eq!(
+        fs::read_to_string(destination.join("source").join("file.txt")).unwrap(),
+        "archived"
+      );
+      assert!(destination
+        .join("source")
+        .join("nested")
+        .join(".hidden")
+        .exists());
+
+      let limited = Extract::from_cursor(fs::File::open(&archive).unwrap(), format)
+        .max_size(512)
+        .extract_into(&dir.path().join(format!("{name}-limited")));
+      assert!(limited.is_err());
+    }
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn stores_symlinks_as_links() {
+    let dir = tempfile::tempdir().unwrap();
+    let outside = dir.path().join("outside.txt");
+    fs::write(&outside, "secret").unwrap();
+    let source = dir.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    std::os::unix::fs::symlink(&outside, source.join("link")).unwrap();
+
+    let archive = |format| {
+      let mut archive = std::io::Cursor::new(Vec::new());
+      Compress::new(format)
+        .add(&source, "")
+        .add(source.join("link"), "root-link")
+        .write_to(&mut archive)
+        .unwrap();
+      archive.into_inner()
+    };
+
+    let tar = archive(ArchiveFormat::Tar(None));
+    assert!(!tar.windows(6).any(|w| w == b"secret"));
+
+    let zip = archive(ArchiveFormat::Zip);
+    assert!(!zip.windows(6).any(|w| w == b"secret"));
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(zip)).unwrap();
+    for name in ["link", "root-link"] {
+      let mut entry = zip.by_name(name).unwrap();
+      assert_eq!(entry.unix_mode().unwrap() & 0o170000, 0o120000);
+      let mut target = String::new();
+      std::io::Read::read_to_string(&mut entry, &mut target).unwrap();
+      assert_eq!(target, outside.to_string_lossy());
+    }
+  }
+}
@@ -6,7 +6,7 @@ use std::{
   borrow::Cow,
   fs,
   io::{self, Cursor, Read, Seek},
-  path::{self, Path, PathBuf},
+  path::{self, Component, Path, PathBuf},
 };
 
 /// The archive reader.
@@ -136,6 +136,7 @@ pub struct Extract<'a, R: Read + Seek> {
   reader: ArchiveReader<R>,
   archive_format: ArchiveFormat,
   tar_archive: Option<tar::Archive<&'a mut ArchiveReader<R>>>,
+  max_size: Option<u64>,
 }
 
 impl<'a, R: std::fmt::Debug + Read + Seek> std::fmt::Debug for Extract<'a, R> {
@@ -143,6 +144,7 @@ impl<'a, R: std::fmt::Debug + Read + Seek> std::fmt::Debug for Extract<'a, R> {
     f.debug_struct("Extract")
       .field("reader", &self.reader)
       .field("archive_format", &self.archive_format)
+      .field("max_size", &self.max_size)
       .finish()
   }
 }
@@ -168,9 +170,20 @@ impl<'a, R: Read + Seek> Extract<'a, R> {
       },
       archive_format,
       tar_archive: None,
+      max_size: None,
     }
   }
 
+  /// Sets the maximum amount of bytes that [`Self::extract_into`] is allowed to write.
+  ///
+  /// The extraction fails once the uncompressed content exceeds this limit,
+  /// which protects against decompression bombs.
+  #[must_use]
+  pub fn max_size(mut self, max_size: u64) -> Self {
+    self.max_size.replace(max_size);
+    self
+  }
+
   /// Reads the archive content.
   pub fn with_files<
     E: Into<crate::api::Error>,
@@ -227,11 +240,36 @@ impl<'a, R: Read + Seek> Extract<'a, R> {
   /// Extract an entire source archive into a specified path. If the source is a single compressed
   /// file and not an archive, it will be extracted into a file with the same name inside of
   /// `into_dir`.
+  ///
+  /// Entries that would be written outside of `into_dir` (e.g. `../file` or absolute paths) are rejected.
   pub fn extract_into(&mut self, into_dir: &path::Path) -> crate::api::Result<()> {
+    self.extract_into_with(into_dir, |_, _| Ok(()))
+  }
+
+  /// Same as [`Self::extract_into`], but calls `on_entry` before each entry is written.
+  ///
+  /// The callback receives the path where the entry is going to be extracted
+  /// and the amount of bytes extracted so far. Returning an error aborts the extraction.
+  pub fn extract_into_with<F: FnMut(&Path, u64) -> crate::api::Result<()>>(
+    &mut self,
+    into_dir: &path::Path,
+    mut on_entry: F,
+  ) -> crate::api::Result<()> {
+    let max_size = self.max_size;
+    let mut extracted = 0;
+    fs::create_dir_all(into_dir)?;
+
     match self.archive_format {
       ArchiveFormat::Tar(_) => {
         let mut archive = tar::Archive::new(&mut self.reader);
-        archive.unpack(into_dir)?;
+        for entry in archive.entries()? {
+          let mut entry = entry?;
+          let out_path = enclosed_path(into_dir, &entry.path()?)?;
+          on_entry(&out_path, extracted)?;
+          // the tar reader never reads more than the size declared on the entry header
+          extracted = checked_size(extracted, entry.size(), max_size)?;
+          entry.unpack_in(into_dir)?;
+        }
       }
 
       ArchiveFormat::Zip => {
@@ -245,7 +283,8 @@ impl<'a, R: Read + Seek> Extract<'a, R> {
             // such as: τê▒Σ║ñµÿô.app/, that does not work as expected.
             // Here we require the file name must be a valid UTF-8.
             let file_name = String::from_utf8(file.name_raw().to_vec())?;
-            let out_path = into_dir.join(file_name);
+            let out_path = enclosed_path(into_dir, Path::new(&file_name))?;
+            on_entry(&out_path, extracted)?;
             if file.is_dir() {
               fs::create_dir_all(&out_path)?;
             } else {
@@ -253,7 +292,14 @@ impl<'a, R: Read + Seek> Extract<'a, R> {
                 fs::create_dir_all(out_path_parent)?;
               }
               let mut out_file = fs::File::create(&out_path)?;
-              io::copy(&mut file, &mut out_file)?;
+              // the uncompressed size declared on the zip entry can't be trusted
+              // so we stop reading as soon as the limit is exceeded
+              let limit = max_size.map_or(u64::MAX, |max| max.saturating_sub(extracted));
+              let written = io::copy(
+                &mut (&mut file).take(limit.saturating_add(1)),
+                &mut out_file,
+              )?;
+              extracted = checked_size(extracted, written, max_size)?;
             }
             // Get and Set permissions
             #[cfg(unix)]
@@ -271,6 +317,36 @@ impl<'a, R: Read + Seek> Extract<'a, R> {
   }
 }
 
+// Joins `path` to `base`, rejecting components that would escape `base` (zip slip).
+fn enclosed_path(base: &Path, path: &Path) -> crate::api::Result<PathBuf> {
+  let mut out_path = base.to_path_buf();
+  for component in path.components() {
+    match component {
+      Component::Normal(c) => out_path.push(c),
+      Component::CurDir => {}
+      _ => {
+        return Err(crate::api::Error::Extract(format!(
+          "entry `{}` would be extracted outside of `{}`",
+          path.display(),
+          base.display()
+        )))
+      }
+    }
+  }
+  Ok(out_path)
+}
+
+// Adds `size` to the `extracted` amount of bytes, failing if it exceeds `max_size`.
+fn checked_size(extracted: u64, size: u64, max_size: Option<u64>) -> crate::api::Result<u64> {
+  let extracted = extracted.saturating_add(size);
+  match max_size {
+    Some(max_size) if extracted > max_size => Err(crate::api::Error::Extract(format!(
+      "archive content exceeds the maximum size of {max_size} bytes"
+    ))),
+    _ => Ok(extracted),
+  }
+}
+
 fn set_perms(
   dst: &Path,
   f: Option<&mut std::fs::File>,
@@ -4,6 +4,9 @@
 
 //! The Tauri API interface.
 
+#[cfg(all(desktop, feature = "autostart"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(desktop, feature = "autostart"))))]
+pub mod autostart;
 #[cfg(all(desktop, feature = "dialog"))]
 #[cfg_attr(doc_cfg, doc(cfg(all(desktop, feature = "dialog"))))]
 pub mod dialog;
@@ -15,6 +18,9 @@ pub mod http;
 pub mod ipc;
 pub mod path;
 pub mod process;
+#[cfg(all(desktop, feature = "secrets-api"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(desktop, feature = "secrets-api"))))]
+pub mod secrets;
 #[cfg(feature = "shell-open-api")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "shell-open-api")))]
 pub mod shell;
@@ -4,8 +4,106 @@
 
 //! Types and functions related to desktop notifications.
 
+use serde::{Deserialize, Serialize};
+
 #[cfg(windows)]
 use std::path::MAIN_SEPARATOR as SEP;
+use std::time::{Duration, SystemTime};
+
+/// The event name used to emit [`NotificationEvent`]s to the frontend.
+#[cfg(any(
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+pub(crate) const NOTIFICATION_EVENT: &str = "tauri://notification";
+
+/// The urgency level of a notification.
+///
+/// ## Platform-specific
+///
+/// - **Windows / macOS:** Unsupported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Urgency {
+  /// Low urgency, e.g. background updates.
+  Low,
+  /// The default urgency.
+  Normal,
+  /// Critical notifications are not dismissed automatically.
+  Critical,
+}
+
+/// When a notification is delivered.
+///
+/// Scheduled notifications are kept in memory and delivered by a single timer thread,
+/// so they are discarded if the app exits before their delivery time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Schedule {
+  /// Delivers the notification at the given time.
+  At(SystemTime),
+  /// Delivers the notification after the given delay.
+  After(Duration),
+}
+
+/// A button shown on a notification.
+///
+/// ## Platform-specific
+///
+/// - **Linux:** Inline replies are not supported by the notification daemons,
+///   so the app should ask for the reply itself when the action is invoked.
+/// - **Windows / macOS:** Unsupported, showing a notification with actions returns an error.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NotificationAction {
+  /// The action identifier, sent back on [`NotificationEvent::Action`].
+  pub id: String,
+  /// The button label.
+  pub title: String,
+}
+
+impl NotificationAction {
+  /// Creates a new notification action.
+  pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+    Self {
+      id: id.into(),
+      title: title.into(),
+    }
+  }
+}
+
+/// An interaction with a notification shown with [`Notification::notify`].
+///
+/// The event is delivered as [`RunEvent::Notification`](crate::RunEvent#variant.Notification)
+/// and emitted to the frontend as a `tauri://notification` event.
+///
+/// ## Platform-specific
+///
+/// - **Windows / macOS:** Unsupported, no events are delivered.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum NotificationEvent {
+  /// The notification was clicked.
+  Clicked {
+    /// The notification identifier, see [`Notification::id`].
+    id: Option<String>,
+  },
+  /// One of the notification actions was clicked.
+  Action {
+    /// The notification identifier, see [`Notification::id`].
+    id: Option<String>,
+    /// The action identifier.
+    action: String,
+  },
+  /// The notification was dismissed or expired.
+  Closed {
+    /// The notification identifier, see [`Notification::id`].
+    id: Option<String>,
+  },
+}
 
 /// The desktop notification definition.
 ///
@@ -40,6 +138,18 @@ pub struct Notification {
   icon: Option<String>,
   /// The notification identifier
   identifier: String,
+  /// The identifier of this notification, sent back on its events.
+  id: Option<String>,
+  /// The notification buttons.
+  actions: Vec<NotificationAction>,
+  /// The notification sound.
+  sound: Option<String>,
+  /// The notification urgency.
+  urgency: Option<Urgency>,
+  /// How long the notification is shown.
+  timeout: Option<Duration>,
+  /// When the notification is delivered.
+  schedule: Option<Schedule>,
 }
 
 impl Notification {
@@ -72,42 +182,134 @@ impl Notification {
     self
   }
 
-  /// Shows the notification.
+  /// Sets the identifier of this notification, sent back on its [`NotificationEvent`]s.
+  #[must_use]
+  pub fn id(mut self, id: impl Into<String>) -> Self {
+    self.id = Some(id.into());
+    self
+  }
+
+  /// Adds a button to the notification.
   ///
-  /// # Examples
+  /// ## Platform-specific
   ///
-  /// ```no_run
-  /// use tauri::api::notification::Notification;
+  /// - **Windows / macOS:** Unsupported, [`Self::show`] and [`Self::notify`] return an error.
+  #[must_use]
+  pub fn action(mut self, action: NotificationAction) -> Self {
+    self.actions.push(action);
+    self
+  }
+
+  /// Sets the name of the sound played when the notification is shown.
   ///
-  /// // on an actual app, remove the string argument
-  /// let context = tauri::generate_context!("test/fixture/src-tauri/tauri.conf.json");
-  /// Notification::new(&context.config().tauri.bundle.identifier)
-  ///   .title("Tauri")
-  ///   .body("Tauri is awesome!")
-  ///   .show()
-  ///   .unwrap();
-  /// ```
+  /// ## Platform-specific
+  ///
+  /// - **Linux:** A name from the freedesktop sound naming specification, e.g. `message-new-instant`.
+  /// - **macOS:** A system sound name, e.g. `Ping`.
+  /// - **Windows:** Unsupported.
+  #[must_use]
+  pub fn sound(mut self, sound: impl Into<String>) -> Self {
+    self.sound = Some(sound.into());
+    self
+  }
+
+  /// Sets the notification urgency.
+  #[must_use]
+  pub fn urgency(mut self, urgency: Urgency) -> Self {
+    self.urgency = Some(urgency);
+    self
+  }
+
+  /// Sets how long the notification is shown.
   ///
   /// ## Platform-specific
   ///
-  /// - **Windows**: Not supported on Windows 7. If your app targets it, enable the `windows7-compat` feature and use [`Self::notify`].
-  #[cfg_attr(
-    all(not(doc_cfg), feature = "windows7-compat"),
-    deprecated = "This function does not work on Windows 7. Use `Self::notify` instead."
-  )]
-  pub fn show(self) -> crate::api::Result<()> {
+  /// - **Windows / macOS:** Unsupported.
+  #[must_use]
+  pub fn timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = Some(timeout);
+    self
+  }
+
+  /// Delays the notification delivery.
+  #[must_use]
+  pub fn schedule(mut self, schedule: Schedule) -> Self {
+    self.schedule = Some(schedule);
+    self
+  }
+
+  /// Returns an error if the notification has actions and they are not supported on this platform.
+  fn check_actions(&self) -> crate::api::Result<()> {
+    if cfg!(not(any(
+      target_os = "linux",
+      target_os = "dragonfly",
+      target_os = "freebsd",
+      target_os = "netbsd",
+      target_os = "openbsd"
+    )))
+      && !self.actions.is_empty()
+    {
+      return Err(crate::api::Error::NotificationActionsUnsupported);
+    }
+    Ok(())
+  }
+
+  /// The time to wait before showing the notification.
+  fn delay(&self) -> Option<Duration> {
+    match self.schedule? {
+      Schedule::At(time) => time.duration_since(SystemTime::now()).ok(),
+      Schedule::After(delay) => Some(delay),
+    }
+  }
+
+  fn to_notify_rust(&self) -> crate::api::Result<notify_rust::Notification> {
     let mut notification = notify_rust::Notification::new();
-    if let Some(body) = self.body {
-      notification.body(&body);
+    if let Some(body) = &self.body {
+      notification.body(body);
     }
-    if let Some(title) = self.title {
-      notification.summary(&title);
+    if let Some(title) = &self.title {
+      notification.summary(title);
     }
-    if let Some(icon) = self.icon {
-      notification.icon(&icon);
+    if let Some(icon) = &self.icon {
+      notification.icon(icon);
     } else {
       notification.auto_icon();
     }
+    #[cfg(any(
+      target_os = "linux",
+      target_os = "dragonfly",
+      target_os = "freebsd",
+      target_os = "netbsd",
+      target_os = "openbsd"
+    ))]
+    {
+      for action in &self.actions {
+        notification.action(&action.id, &action.title);
+      }
+      if let Some(urgency) = self.urgency {
+        notification.urgency(match urgency {
+          Urgency::Low => notify_rust::Urgency::Low,
+          Urgency::Normal => notify_rust::Urgency::Normal,
+          Urgency::Critical => notify_rust::Urgency::Critical,
+        });
+      }
+      if let Some(timeout) = self.timeout {
+        notification.timeout(notify_rust::Timeout::Milliseconds(
+          timeout.as_millis().min(u32::MAX as u128) as u32,
+        ));
+      }
+    }
+    #[cfg(any(
+      target_os = "linux",
+      target_os = "dragonfly",
+      target_os = "freebsd",
+      target_os = "netbsd",
+      target_os = "openbsd",
+      target_os = "macos"
+    ))]
+    if let Some(sound) = &self.sound {
+      notification.sound_name(sound);
+    }
     #[cfg(windows)]
     {
       let exe = tauri_utils::platform::current_exe()?;
@@ -128,15 +330,52 @@ impl Notification {
         "com.apple.Terminal"
       });
     }
+    Ok(notification)
+  }
 
-    crate::async_runtime::spawn(async move {
-      let _ = notification.show();
-    });
+  /// Shows the notification.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// use tauri::api::notification::Notification;
+  ///
+  /// // on an actual app, remove the string argument
+  /// let context = tauri::generate_context!("test/fixture/src-tauri/tauri.conf.json");
+  /// Notification::new(&context.config().tauri.bundle.identifier)
+  ///   .title("Tauri")
+  ///   .body("Tauri is awesome!")
+  ///   .show()
+  ///   .unwrap();
+  /// ```
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows**: Not supported on Windows 7. If your app targets it, enable the `windows7-compat` feature and use [`Self::notify`].
+  #[cfg_attr(
+    all(not(doc_cfg), feature = "windows7-compat"),
+    deprecated = "This function does not work on Windows 7. Use `Self::notify` instead."
+  )]
+  pub fn show(self) -> crate::api::Result<()> {
+    self.check_actions()?;
+    let notification = self.to_notify_rust()?;
+
+    if let Some(delay) = self.delay() {
+      scheduler::schedule(delay, move || {
+        let _ = notification.show();
+      });
+    } else {
+      crate::async_runtime::spawn(async move {
+        let _ = notification.show();
+      });
+    }
 
     Ok(())
   }
 
-  /// Shows the notification. This API is similar to [`Self::show`], but it also works on Windows 7.
+  /// Shows the notification and delivers its [`NotificationEvent`]s to the app.
+  ///
+  /// This API is similar to [`Self::show`], but it also works on Windows 7 when the `windows7-compat` feature is enabled.
   ///
   /// # Examples
   ///
@@ -159,26 +398,109 @@ impl Notification {
   ///   .run(context)
   ///   .expect("error while running tauri application");
   /// ```
-  #[cfg(feature = "windows7-compat")]
-  #[cfg_attr(doc_cfg, doc(cfg(feature = "windows7-compat")))]
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / macOS:** [`NotificationEvent`]s are not delivered.
+  /// - **Linux:** Each shown notification waits for its events on a dedicated thread until it is closed,
+  ///   so at most 32 notifications, including the scheduled ones, can wait for their events at the same time.
+  ///   Past that limit, an error is returned.
   #[allow(unused_variables)]
   pub fn notify<R: crate::Runtime>(self, app: &crate::AppHandle<R>) -> crate::api::Result<()> {
-    #[cfg(windows)]
+    self.check_actions()?;
+    #[cfg(all(windows, feature = "windows7-compat"))]
+    if crate::utils::platform::is_windows_7() {
+      return self.notify_win7(app);
+    }
+    #[cfg(any(
+      target_os = "linux",
+      target_os = "dragonfly",
+      target_os = "freebsd",
+      target_os = "netbsd",
+      target_os = "openbsd"
+    ))]
     {
-      if crate::utils::platform::is_windows_7() {
-        self.notify_win7(app)
-      } else {
-        #[allow(deprecated)]
-        self.show()
-      }
+      self.notify_with_events(app)
     }
-    #[cfg(not(windows))]
+    #[cfg(not(any(
+      target_os = "linux",
+      target_os = "dragonfly",
+      target_os = "freebsd",
+      target_os = "netbsd",
+      target_os = "openbsd"
+    )))]
     {
       #[allow(deprecated)]
       self.show()
     }
   }
 
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  fn notify_with_events<R: crate::Runtime>(
+    self,
+    app: &crate::AppHandle<R>,
+  ) -> crate::api::Result<()> {
+    use crate::{runtime::EventLoopProxy, EventLoopMessage, Manager};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// The maximum amount of notifications waiting for their events at the same time.
+    const MAX_LISTENERS: usize = 32;
+    static LISTENERS: AtomicUsize = AtomicUsize::new(0);
+
+    if LISTENERS.fetch_add(1, Ordering::SeqCst) >= MAX_LISTENERS {
+      LISTENERS.fetch_sub(1, Ordering::SeqCst);
+      return Err(crate::api::Error::TooManyNotificationListeners);
+    }
+
+    let mut notification = match self.to_notify_rust() {
+      Ok(notification) => notification,
+      Err(e) => {
+        LISTENERS.fetch_sub(1, Ordering::SeqCst);
+        return Err(e);
+      }
+    };
+    // the `default` action is invoked when the notification itself is clicked
+    notification.action("default", "");
+    let delay = self.delay();
+    let id = self.id;
+    let app = app.clone();
+
+    // waiting for the notification action blocks, so it can't run on the async runtime
+    let deliver = move || {
+      std::thread::spawn(move || {
+        if let Ok(handle) = notification.show() {
+          handle.wait_for_action(|action| {
+            let event = match action {
+              "default" => NotificationEvent::Clicked { id },
+              "__closed" => NotificationEvent::Closed { id },
+              action => NotificationEvent::Action {
+                id,
+                action: action.into(),
+              },
+            };
+            let _ = app.emit_all(NOTIFICATION_EVENT, event.clone());
+            let _ = app
+              .create_proxy()
+              .send_event(EventLoopMessage::Notification(event));
+          });
+        }
+        LISTENERS.fetch_sub(1, Ordering::SeqCst);
+      });
+    };
+    match delay {
+      Some(delay) => scheduler::schedule(delay, deliver),
+      None => deliver(),
+    }
+
+    Ok(())
+  }
+
   #[cfg(all(windows, feature = "windows7-compat"))]
   fn notify_win7<R: crate::Runtime>(self, app: &crate::AppHandle<R>) -> crate::api::Result<()> {
     let app = app.clone();
@@ -205,3 +527,56 @@ impl Notification {
     Ok(())
   }
 }
+
+/// Delivers the scheduled notifications from a single thread.
+mod scheduler {
+  use once_cell::sync::Lazy;
+  use std::{
+    sync::{
+      mpsc::{channel, RecvTimeoutError, Sender},
+      Mutex,
+    },
+    time::{Duration, Instant},
+  };
+
+  type Job = Box<dyn FnOnce() + Send>;
+
+  static SCHEDULER: Lazy<Mutex<Sender<(Instant, Job)>>> = Lazy::new(|| {
+    let (tx, rx) = channel::<(Instant, Job)>();
+    std::thread::spawn(move || {
+      let mut pending: Vec<(Instant, Job)> = Vec::new();
+      loop {
+        let received = match pending.iter().map(|(at, _)| *at).min() {
+          Some(next) => rx.recv_timeout(next.saturating_duration_since(Instant::now())),
+          None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+          Ok(job) => pending.push(job),
+          Err(RecvTimeoutError::Timeout) => {}
+          Err(RecvTimeoutError::Disconnected) => break,
+        }
+        let now = Instant::now();
+        let mut i = 0;
+        while i < pending.len() {
+          if pending[i].0 <= now {
+            let (_, job) = pending.swap_remove(i);
+            job();
+          } else {
+            i += 1;
+          }
+        }
+      }
+    });
+    Mutex::new(tx)
+  });
+
+  /// Runs `job` on the scheduler thread once `delay` has elapsed.
+  pub(super) fn schedule<F: FnOnce() + Send + 'static>(delay: Duration, job: F) {
+    let now = Instant::now();
+    // a delay that overflows the clock is clamped to a time that is never reached in practice
+    let at = now
+      .checked_add(delay)
+      .unwrap_or_else(|| now + Duration::from_secs(u32::MAX.into()));
+    let _ = SCHEDULER.lock().unwrap().send((at, Box::new(job)));
+  }
+}
@@ -14,6 +14,12 @@ mod command;
 #[cfg(feature = "process-command-api")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "process-command-api")))]
 pub use command::*;
+#[cfg(feature = "process-command-api")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "process-command-api")))]
+mod supervisor;
+#[cfg(feature = "process-command-api")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "process-command-api")))]
+pub use supervisor::*;
 
 /// Finds the current running binary's path.
 ///
@@ -7,8 +7,12 @@ use std::{
   io::{BufReader, Write},
   path::PathBuf,
   process::{Command as StdCommand, Stdio},
-  sync::{Arc, Mutex, RwLock},
-  thread::spawn,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex, RwLock,
+  },
+  thread::{sleep, spawn},
+  time::{Duration, Instant},
 };
 
 #[cfg(unix)]
@@ -21,12 +25,208 @@ const CREATE_NO_WINDOW: u32 = 0x0800_0000;
 
 use crate::async_runtime::{block_on as block_on_task, channel, Receiver, Sender};
 pub use encoding_rs::Encoding;
-use os_pipe::{pipe, PipeReader, PipeWriter};
-use serde::Serialize;
+use os_pipe::{pipe, PipeReader};
+use serde::{Deserialize, Serialize};
 use shared_child::SharedChild;
 use tauri_utils::platform;
 
-type ChildStore = Arc<Mutex<HashMap<u32, Arc<SharedChild>>>>;
+#[derive(Debug)]
+enum Killer {
+  Pipe(Arc<SharedChild>),
+  #[cfg(feature = "process-pty")]
+  Pty(Box<dyn portable_pty::ChildKiller + Send + Sync>),
+}
+
+impl Killer {
+  fn clone_killer(&self) -> Self {
+    match self {
+      Self::Pipe(child) => Self::Pipe(child.clone()),
+      #[cfg(feature = "process-pty")]
+      Self::Pty(killer) => Self::Pty(killer.clone_killer()),
+    }
+  }
+
+  fn kill(&mut self) -> std::io::Result<()> {
+    match self {
+      Self::Pipe(child) => child.kill(),
+      #[cfg(feature = "process-pty")]
+      Self::Pty(killer) => killer.kill(),
+    }
+  }
+}
+
+/// A spawned process and how it must be terminated.
+#[derive(Debug)]
+struct ChildProcess {
+  pid: u32,
+  killer: Killer,
+  process_group: bool,
+  kill_policy: KillPolicy,
+  /// Set once the process has been waited on.
+  exited: Arc<AtomicBool>,
+}
+
+impl ChildProcess {
+  fn new(pid: u32, killer: Killer, process_group: bool, kill_policy: KillPolicy) -> Self {
+    Self {
+      pid,
+      killer,
+      process_group,
+      kill_policy,
+      exited: Default::default(),
+    }
+  }
+
+  fn clone_process(&self) -> Self {
+    Self {
+      pid: self.pid,
+      killer: self.killer.clone_killer(),
+      process_group: self.process_group,
+      kill_policy: self.kill_policy,
+      exited: self.exited.clone(),
+    }
+  }
+
+  fn is_running(&self) -> bool {
+    #[cfg(unix)]
+    if self.process_group {
+      // the group outlives its leader while any of its processes is running,
+      // and its id is not reused until the group is empty
+      return signal(self.pid, true, 0).is_ok();
+    }
+    !self.exited.load(Ordering::SeqCst)
+  }
+
+  /// Asks the process to exit.
+  fn request_exit(&self) -> std::io::Result<()> {
+    // once the process or its group is gone its id may belong to another process
+    if !self.is_running() {
+      return Ok(());
+    }
+    #[cfg(unix)]
+    {
+      signal(self.pid, self.process_group, libc::SIGTERM)
+    }
+    #[cfg(windows)]
+    {
+      taskkill(self.pid, self.process_group, false)
+    }
+  }
+
+  fn kill(&mut self) -> std::io::Result<()> {
+    if self.process_group {
+      if !self.is_running() {
+        return Ok(());
+      }
+      #[cfg(unix)]
+      return kill_group(self.pid);
+      #[cfg(windows)]
+      return taskkill(self.pid, true, true);
+    }
+    self.killer.kill()
+  }
+
+  fn terminate(&mut self) -> std::io::Result<()> {
+    if let KillPolicy::Graceful { timeout } = self.kill_policy {
+      if self.request_exit().is_ok() {
+        return self.kill_after(timeout);
+      }
+    }
+    self.kill_if_running()
+  }
+
+  /// Kills the process if it is still running after the timeout.
+  fn kill_after(&mut self, timeout: Duration) -> std::io::Result<()> {
+    if wait_until(|| !self.is_running(), timeout) {
+      Ok(())
+    } else {
+      self.kill_if_running()
+    }
+  }
+
+  fn kill_if_running(&mut self) -> std::io::Result<()> {
+    if self.is_running() {
+      self.kill()
+    } else {
+      Ok(())
+    }
+  }
+}
+
+#[cfg(unix)]
+fn signal(pid: u32, group: bool, signal: libc::c_int) -> std::io::Result<()> {
+  let pid = pid as libc::pid_t;
+  // a negative pid targets every process in the group
+  let target = if group { -pid } else { pid };
+  if unsafe { libc::kill(target, signal) } == 0 {
+    Ok(())
+  } else {
+    Err(std::io::Error::last_os_error())
+  }
+}
+
+/// Kills every process in the group, signaling it until it is empty
+/// since its processes may fork while it is being killed.
+#[cfg(unix)]
+fn kill_group(pgid: u32) -> std::io::Result<()> {
+  let start = Instant::now();
+  loop {
+    if let Err(e) = signal(pgid, true, libc::SIGKILL) {
+      return if e.raw_os_error() == Some(libc::ESRCH) {
+        Ok(())
+      } else {
+        Err(e)
+      };
+    }
+    // killed processes stay in the group until they are reaped
+    if start.elapsed() >= Duration::from_secs(5) {
+      return Err(std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        format!("process group {pgid} is still running"),
+      ));
+    }
+    sleep(Duration::from_millis(10));
+  }
+}
+
+#[cfg(windows)]
+fn taskkill(pid: u32, tree: bool, force: bool) -> std::io::Result<()> {
+  let mut command = StdCommand::new("taskkill");
+  command.args(["/PID", &pid.to_string()]);
+  if tree {
+    command.arg("/T");
+  }
+  if force {
+    command.arg("/F");
+  }
+  command.stdout(Stdio::null());
+  command.stderr(Stdio::null());
+  command.creation_flags(CREATE_NO_WINDOW);
+  if command.status()?.success() {
+    Ok(())
+  } else {
+    Err(std::io::Error::new(
+      std::io::ErrorKind::Other,
+      format!("failed to terminate process {pid}"),
+    ))
+  }
+}
+
+/// Polls `condition` until it holds or the timeout elapses, returning whether it holds.
+fn wait_until<F: Fn() -> bool>(condition: F, timeout: Duration) -> bool {
+  let start = Instant::now();
+  loop {
+    if condition() {
+      return true;
+    }
+    if start.elapsed() >= timeout {
+      return false;
+    }
+    sleep(Duration::from_millis(10));
+  }
+}
+
+type ChildStore = Arc<Mutex<HashMap<u32, ChildProcess>>>;
 
 fn commands() -> &'static ChildStore {
   use once_cell::sync::Lazy;
@@ -34,14 +234,60 @@ fn commands() -> &'static ChildStore {
   &STORE
 }
 
-/// Kills all child processes created with [`Command`].
+/// Terminates all child processes created with [`Command`], honouring their [`KillPolicy`].
 /// By default it's called before the [`crate::App`] exits.
+///
+/// Processes started by a [`super::Supervisor`] are not restarted.
+///
+/// Processes with a [`KillPolicy::Graceful`] policy are asked to exit at the same time,
+/// so this blocks for at most the longest of their timeouts.
 pub fn kill_children() {
-  let commands = commands().lock().unwrap();
-  let children = commands.values();
-  for child in children {
-    let _ = child.kill();
+  super::supervisor::stop_supervisors();
+  let mut children: Vec<ChildProcess> = commands()
+    .lock()
+    .unwrap()
+    .values()
+    .map(ChildProcess::clone_process)
+    .collect();
+
+  let mut timeout = None;
+  for child in &mut children {
+    match child.kill_policy {
+      KillPolicy::Graceful { timeout: t } if child.request_exit().is_ok() => {
+        timeout = timeout.max(Some(t));
+      }
+      _ => {
+        let _ = child.kill();
+      }
+    }
   }
+
+  if let Some(timeout) = timeout {
+    wait_until(|| children.iter().all(|c| !c.is_running()), timeout);
+    for child in &mut children {
+      if child.is_running() {
+        let _ = child.kill();
+      }
+    }
+  }
+}
+
+/// How a child process is terminated by [`CommandChild::terminate`] and [`kill_children`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillPolicy {
+  /// Kills the process immediately.
+  Kill,
+  /// Asks the process to exit and kills it if it is still running after the timeout.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / macOS:** Sends `SIGTERM`, then `SIGKILL`.
+  /// - **Windows:** Runs `taskkill`, which asks the process windows to close, then `taskkill /F`.
+  ///   Processes without a window can't be asked to exit, so they are killed after the timeout.
+  Graceful {
+    /// How long the process is given to exit.
+    timeout: Duration,
+  },
 }
 
 /// Payload for the [`CommandEvent::Terminated`] command event.
@@ -62,14 +308,50 @@ pub enum CommandEvent {
   Stderr(String),
   /// Stdout bytes until a newline (\n) or carriage return (\r) is found.
   Stdout(String),
+  /// Raw stdout bytes, sent as soon as they are read.
+  ///
+  /// Sent instead of [`Self::Stdout`] when the command uses [`Command::raw_output`]
+  /// or is spawned in a pseudo terminal with [`Command::pty`], which merges the stdout and stderr streams.
+  StdoutBytes(Vec<u8>),
+  /// Raw stderr bytes, sent as soon as they are read.
+  ///
+  /// Sent instead of [`Self::Stderr`] when the command uses [`Command::raw_output`].
+  StderrBytes(Vec<u8>),
   /// An error happened waiting for the command to finish or converting the stdout/stderr bytes to an UTF-8 string.
   Error(String),
   /// Command process terminated.
   Terminated(TerminatedPayload),
 }
 
+/// The size of a pseudo terminal, in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PtySize {
+  /// The number of rows.
+  pub rows: u16,
+  /// The number of columns.
+  pub cols: u16,
+}
+
+impl Default for PtySize {
+  fn default() -> Self {
+    Self { rows: 24, cols: 80 }
+  }
+}
+
+#[cfg(feature = "process-pty")]
+impl From<PtySize> for portable_pty::PtySize {
+  fn from(size: PtySize) -> Self {
+    Self {
+      rows: size.rows,
+      cols: size.cols,
+      pixel_width: 0,
+      pixel_height: 0,
+    }
+  }
+}
+
 /// The type to spawn commands.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Command {
   program: String,
   args: Vec<String>,
@@ -77,31 +359,88 @@ pub struct Command {
   env: HashMap<String, String>,
   current_dir: Option<PathBuf>,
   encoding: Option<&'static Encoding>,
+  raw_output: bool,
+  process_group: bool,
+  kill_policy: KillPolicy,
+  #[cfg(feature = "process-pty")]
+  pty: Option<PtySize>,
 }
 
 /// Spawned child process.
-#[derive(Debug)]
 pub struct CommandChild {
-  inner: Arc<SharedChild>,
-  stdin_writer: PipeWriter,
+  process: ChildProcess,
+  #[cfg(feature = "process-pty")]
+  pty_master: Option<Box<dyn portable_pty::MasterPty + Send>>,
+  stdin_writer: Box<dyn Write + Send>,
+}
+
+impl std::fmt::Debug for CommandChild {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CommandChild")
+      .field("pid", &self.pid())
+      .finish()
+  }
 }
 
 impl CommandChild {
   /// Writes to process stdin.
   pub fn write(&mut self, buf: &[u8]) -> crate::api::Result<()> {
     self.stdin_writer.write_all(buf)?;
+    self.stdin_writer.flush()?;
+    Ok(())
+  }
+
+  /// Sends a kill signal to the child, or to its whole process group if it was spawned with [`Command::process_group`].
+  pub fn kill(mut self) -> crate::api::Result<()> {
+    self.process.kill()?;
+    Ok(())
+  }
+
+  /// Terminates the child according to its [`KillPolicy`].
+  ///
+  /// With a [`KillPolicy::Graceful`] policy, this blocks until the process exits or the timeout elapses.
+  pub fn terminate(mut self) -> crate::api::Result<()> {
+    self.process.terminate()?;
     Ok(())
   }
 
-  /// Sends a kill signal to the child.
-  pub fn kill(self) -> crate::api::Result<()> {
-    self.inner.kill()?;
+  /// Terminates the child according to its [`KillPolicy`] without blocking.
+  ///
+  /// With a [`KillPolicy::Graceful`] policy, the process is asked to exit right away
+  /// and killed from a blocking task if it is still running after the timeout.
+  #[cfg_attr(not(shell_script), allow(dead_code))]
+  pub(crate) fn terminate_in_background(mut self) -> crate::api::Result<()> {
+    if let KillPolicy::Graceful { timeout } = self.process.kill_policy {
+      if self.process.request_exit().is_ok() {
+        // the whole child is moved so the pseudo terminal stays open until the process exits
+        let mut child = self;
+        crate::async_runtime::spawn_blocking(move || {
+          let _ = child.process.kill_after(timeout);
+        });
+        return Ok(());
+      }
+    }
+    self.process.kill_if_running()?;
     Ok(())
   }
 
   /// Returns the process pid.
   pub fn pid(&self) -> u32 {
-    self.inner.id()
+    self.process.pid
+  }
+
+  /// Resizes the pseudo terminal of a command spawned with [`Command::pty`].
+  ///
+  /// Fails if the command was not spawned in a pseudo terminal.
+  #[cfg(feature = "process-pty")]
+  #[cfg_attr(doc_cfg, doc(cfg(feature = "process-pty")))]
+  pub fn resize(&self, size: PtySize) -> crate::api::Result<()> {
+    match &self.pty_master {
+      Some(master) => master.resize(size.into()).map_err(pty_error),
+      None => Err(crate::api::Error::Command(
+        "the process was not spawned in a pseudo terminal".into(),
+      )),
+    }
   }
 }
 
@@ -158,6 +497,20 @@ impl From<Command> for StdCommand {
     if let Some(current_dir) = cmd.current_dir {
       command.current_dir(current_dir);
     }
+    #[cfg(unix)]
+    if cmd.process_group {
+      use std::os::unix::process::CommandExt;
+      // SAFETY: `setpgid` is async-signal-safe
+      unsafe {
+        command.pre_exec(|| {
+          if libc::setpgid(0, 0) == 0 {
+            Ok(())
+          } else {
+            Err(std::io::Error::last_os_error())
+          }
+        });
+      }
+    }
     #[cfg(windows)]
     command.creation_flags(CREATE_NO_WINDOW);
     command
@@ -174,6 +527,11 @@ impl Command {
       env: Default::default(),
       current_dir: None,
       encoding: None,
+      raw_output: false,
+      process_group: false,
+      kill_policy: KillPolicy::Kill,
+      #[cfg(feature = "process-pty")]
+      pty: None,
     }
   }
 
@@ -226,6 +584,60 @@ impl Command {
     self
   }
 
+  /// Reads stdout and stderr as raw byte chunks, sent as soon as they are read,
+  /// instead of splitting them in lines decoded to strings.
+  ///
+  /// The output is sent as [`CommandEvent::StdoutBytes`] and [`CommandEvent::StderrBytes`]
+  /// and [`Self::encoding`] is ignored.
+  /// Use this mode for binary output or for output that isn't terminated by a newline, such as progress bars.
+  #[must_use]
+  pub fn raw_output(mut self) -> Self {
+    self.raw_output = true;
+    self
+  }
+
+  /// Spawns the command in a new process group, so [`CommandChild::kill`], [`CommandChild::terminate`]
+  /// and [`kill_children`] also terminate the processes it spawns, such as forked workers.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** The process tree is terminated with `taskkill /T`,
+  ///   which doesn't reach processes whose parent already exited.
+  /// - **Linux / macOS:** Processes that move to another process group or session are not terminated.
+  ///   Commands spawned with [`Self::pty`] always lead their own process group.
+  #[must_use]
+  pub fn process_group(mut self) -> Self {
+    self.process_group = true;
+    self
+  }
+
+  /// Sets how the child is terminated by [`CommandChild::terminate`] and by [`kill_children`] when the app exits.
+  ///
+  /// Defaults to [`KillPolicy::Kill`].
+  #[must_use]
+  pub fn kill_policy(mut self, policy: KillPolicy) -> Self {
+    self.kill_policy = policy;
+    self
+  }
+
+  /// Spawns the command in a pseudo terminal of the given size,
+  /// so interactive programs detect a terminal and enable colors and line editing.
+  ///
+  /// The terminal merges stdout and stderr, so the output is sent as [`CommandEvent::StdoutBytes`] chunks
+  /// and [`Self::encoding`] is ignored. The terminal can be resized with [`CommandChild::resize`].
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** Requires Windows 10 1809 or later. The terminal is closed when the [`CommandChild`] is dropped.
+  /// - **All platforms:** The [`TerminatedPayload::signal`] is always `None`.
+  #[cfg(feature = "process-pty")]
+  #[cfg_attr(doc_cfg, doc(cfg(feature = "process-pty")))]
+  #[must_use]
+  pub fn pty(mut self, size: PtySize) -> Self {
+    self.pty.replace(size);
+    self
+  }
+
   /// Spawns the command.
   ///
   /// # Examples
@@ -252,7 +664,15 @@ impl Command {
   /// });
   /// ```
   pub fn spawn(self) -> crate::api::Result<(Receiver<CommandEvent>, CommandChild)> {
+    #[cfg(feature = "process-pty")]
+    if let Some(size) = self.pty {
+      return self.spawn_pty(size);
+    }
+
     let encoding = self.encoding;
+    let raw_output = self.raw_output;
+    let process_group = self.process_group;
+    let kill_policy = self.kill_policy;
     let mut command: StdCommand = self.into();
     let (stdout_reader, stdout_writer) = pipe()?;
     let (stderr_reader, stderr_writer) = pipe()?;
@@ -266,27 +686,54 @@ impl Command {
     let child_ = child.clone();
     let guard = Arc::new(RwLock::new(()));
 
-    commands().lock().unwrap().insert(child.id(), child.clone());
+    let process = ChildProcess::new(
+      child.id(),
+      Killer::Pipe(child.clone()),
+      process_group,
+      kill_policy,
+    );
+    let exited = process.exited.clone();
+    commands()
+      .lock()
+      .unwrap()
+      .insert(child.id(), process.clone_process());
 
     let (tx, rx) = channel(1);
 
-    spawn_pipe_reader(
-      tx.clone(),
-      guard.clone(),
-      stdout_reader,
-      CommandEvent::Stdout,
-      encoding,
-    );
-    spawn_pipe_reader(
-      tx.clone(),
-      guard.clone(),
-      stderr_reader,
-      CommandEvent::Stderr,
-      encoding,
-    );
+    if raw_output {
+      spawn_chunk_reader(
+        tx.clone(),
+        guard.clone(),
+        stdout_reader,
+        CommandEvent::StdoutBytes,
+      );
+      spawn_chunk_reader(
+        tx.clone(),
+        guard.clone(),
+        stderr_reader,
+        CommandEvent::StderrBytes,
+      );
+    } else {
+      spawn_pipe_reader(
+        tx.clone(),
+        guard.clone(),
+        stdout_reader,
+        CommandEvent::Stdout,
+        encoding,
+      );
+      spawn_pipe_reader(
+        tx.clone(),
+        guard.clone(),
+        stderr_reader,
+        CommandEvent::Stderr,
+        encoding,
+      );
+    }
 
     spawn(move || {
-      let _ = match child_.wait() {
+      let status = child_.wait();
+      exited.store(true, Ordering::SeqCst);
+      let _ = match status {
         Ok(status) => {
           let _l = guard.write().unwrap();
           commands().lock().unwrap().remove(&child_.id());
@@ -311,7 +758,88 @@ impl Command {
     Ok((
       rx,
       CommandChild {
-        inner: child,
+        process,
+        #[cfg(feature = "process-pty")]
+        pty_master: None,
+        stdin_writer: Box::new(stdin_writer),
+      },
+    ))
+  }
+
+  #[cfg(feature = "process-pty")]
+  fn spawn_pty(self, size: PtySize) -> crate::api::Result<(Receiver<CommandEvent>, CommandChild)> {
+    let pair = portable_pty::native_pty_system()
+      .openpty(size.into())
+      .map_err(pty_error)?;
+
+    let mut builder = portable_pty::CommandBuilder::new(&self.program);
+    builder.args(&self.args);
+    if self.env_clear {
+      builder.env_clear();
+    }
+    for (key, value) in &self.env {
+      builder.env(key, value);
+    }
+    if let Some(current_dir) = &self.current_dir {
+      builder.cwd(current_dir);
+    }
+
+    let mut child = pair.slave.spawn_command(builder).map_err(pty_error)?;
+    // the reader only reaches the end of the stream when every handle to the slave is closed
+    drop(pair.slave);
+    let pid = child
+      .process_id()
+      .ok_or_else(|| crate::api::Error::Command("failed to get the process id".into()))?;
+    let reader = pair.master.try_clone_reader().map_err(pty_error)?;
+    let stdin_writer = pair.master.take_writer().map_err(pty_error)?;
+
+    let process = ChildProcess::new(
+      pid,
+      Killer::Pty(child.clone_killer()),
+      self.process_group,
+      self.kill_policy,
+    );
+    let exited = process.exited.clone();
+    commands()
+      .lock()
+      .unwrap()
+      .insert(pid, process.clone_process());
+
+    let (tx, rx) = channel(1);
+    let guard = Arc::new(RwLock::new(()));
+
+    spawn_chunk_reader(tx.clone(), guard.clone(), reader, CommandEvent::StdoutBytes);
+
+    spawn(move || {
+      let status = child.wait();
+      exited.store(true, Ordering::SeqCst);
+      // wait briefly for the remaining output, since the terminal may outlive the process
+      for _ in 0..50 {
+        if guard.try_write().is_ok() {
+          break;
+        }
+        sleep(Duration::from_millis(10));
+      }
+      commands().lock().unwrap().remove(&pid);
+      let _ = block_on_task(async move {
+        match status {
+          Ok(status) => {
+            tx.send(CommandEvent::Terminated(TerminatedPayload {
+              code: Some(status.exit_code() as i32),
+              signal: None,
+            }))
+            .await
+          }
+          Err(e) => tx.send(CommandEvent::Error(e.to_string())).await,
+        }
+      });
+    });
+
+    Ok((
+      rx,
+      CommandChild {
+        process,
+        pty_master: Some(pair.master),
         stdin_writer,
       },
     ))
@@ -359,6 +887,8 @@ impl Command {
       let mut code = None;
       let mut stdout = String::new();
       let mut stderr = String::new();
+      let mut stdout_bytes = Vec::new();
+      let mut stderr_bytes = Vec::new();
       while let Some(event) = rx.recv().await {
         match event {
           CommandEvent::Terminated(payload) => {
@@ -372,9 +902,14 @@ impl Command {
             stderr.push_str(line.as_str());
             stderr.push('\n');
           }
+          CommandEvent::StdoutBytes(bytes) => stdout_bytes.extend(bytes),
+          CommandEvent::StderrBytes(bytes) => stderr_bytes.extend(bytes),
           CommandEvent::Error(_) => {}
         }
       }
+      // decode the chunks at once since they may split a character
+      stdout.push_str(&String::from_utf8_lossy(&stdout_bytes));
+      stderr.push_str(&String::from_utf8_lossy(&stderr_bytes));
       Output {
         status: ExitStatus { code },
         stdout,
@@ -426,6 +961,53 @@ fn spawn_pipe_reader<F: Fn(String) -> CommandEvent + Send + Copy + 'static>(
   });
 }
 
+fn spawn_chunk_reader<
+  R: std::io::Read + Send + 'static,
+  F: Fn(Vec<u8>) -> CommandEvent + Send + Copy + 'static,
+>(
+  tx: Sender<CommandEvent>,
+  guard: Arc<RwLock<()>>,
+  mut reader: R,
+  wrapper: F,
+) {
+  spawn(move || {
+    let _lock = guard.read().unwrap();
+    let mut buf = [0; 4096];
+    loop {
+      match reader.read(&mut buf) {
+        Ok(0) => break,
+        Ok(n) => {
+          let tx_ = tx.clone();
+          let chunk = buf[..n].to_vec();
+          block_on_task(async move {
+            let _ = tx_.send(wrapper(chunk)).await;
+          });
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+        Err(e) => {
+          if !is_closed_pty(&e) {
+            let tx_ = tx.clone();
+            let _ =
+              block_on_task(async move { tx_.send(CommandEvent::Error(e.to_string())).await });
+          }
+          break;
+        }
+      }
+    }
+  });
+}
+
+/// Reading a pseudo terminal fails with `EIO` instead of reaching the end of the stream once the child closes it.
+fn is_closed_pty(e: &std::io::Error) -> bool {
+  // EIO is 5 on every unix platform we support
+  cfg!(unix) && e.raw_os_error() == Some(5)
+}
+
+#[cfg(feature = "process-pty")]
+fn pty_error<E: std::fmt::Display>(e: E) -> crate::api::Error {
+  crate::api::Error::Command(e.to_string())
+}
+
 // tests for the commands functions.
 #[cfg(test)]
 mod test {
@@ -454,6 +1036,126 @@ mod test {
     });
   }
 
+  #[cfg(not(windows))]
+  #[test]
+  fn test_cmd_raw_output() {
+    // `printf` output isn't terminated by a newline
+    let cmd = Command::new("printf")
+      .args(["\\000\\377progress"])
+      .raw_output();
+    let (mut rx, _) = cmd.spawn().unwrap();
+
+    crate::async_runtime::block_on(async move {
+      let mut output = Vec::new();
+      while let Some(event) = rx.recv().await {
+        match event {
+          CommandEvent::Terminated(payload) => {
+            assert_eq!(payload.code, Some(0));
+          }
+          CommandEvent::StdoutBytes(bytes) => output.extend(bytes),
+          CommandEvent::Stdout(_) => panic!("unexpected line event"),
+          _ => {}
+        }
+      }
+      assert_eq!(output, b"\x00\xffprogress");
+    });
+  }
+
+  #[cfg(all(not(windows), feature = "process-pty"))]
+  #[test]
+  fn test_cmd_pty() {
+    // `tty` fails when stdin is not a terminal
+    let cmd = Command::new("tty").pty(PtySize::default());
+    let (mut rx, _child) = cmd.spawn().unwrap();
+
+    crate::async_runtime::block_on(async move {
+      let mut output = Vec::new();
+      while let Some(event) = rx.recv().await {
+        match event {
+          CommandEvent::Terminated(payload) => {
+            assert_eq!(payload.code, Some(0));
+          }
+          CommandEvent::StdoutBytes(bytes) => output.extend(bytes),
+          _ => {}
+        }
+      }
+      assert!(String::from_utf8_lossy(&output).starts_with("/dev/"));
+    });
+  }
+
+  #[cfg(not(windows))]
+  #[test]
+  fn test_cmd_terminate_process_group() {
+    // the shell handles SIGTERM and forks a worker that must be terminated with it
+    let cmd = Command::new("sh")
+      .args([
+        "-c",
+        "trap 'echo bye; exit 0' TERM; sleep 30 & echo $!; while true; do sleep 0.1; done",
+      ])
+      .process_group()
+      .kill_policy(KillPolicy::Graceful {
+        timeout: Duration::from_secs(5),
+      });
+    let (mut rx, child) = cmd.spawn().unwrap();
+    let pgid = child.pid();
+
+    crate::async_runtime::block_on(async move {
+      let mut child = Some(child);
+      let mut lines = Vec::new();
+      while let Some(event) = rx.recv().await {
+        match event {
+          CommandEvent::Stdout(line) => {
+            lines.push(line);
+            if let Some(child) = child.take() {
+              child.terminate().unwrap();
+            }
+          }
+          CommandEvent::Terminated(payload) => {
+            assert_eq!(payload.code, Some(0));
+          }
+          _ => {}
+        }
+      }
+      assert_eq!(lines.last().map(String::as_str), Some("bye"));
+    });
+
+    // the worker is reaped by init after the leader exits
+    assert!(wait_until(
+      || signal(pgid, true, 0).is_err(),
+      Duration::from_secs(5)
+    ));
+  }
+
+  #[cfg(not(windows))]
+  #[test]
+  fn test_cmd_kill_process_group_after_leader_exits() {
+    // the leader forks a worker and exits right away
+    let cmd = Command::new("sh")
+      .args(["-c", "sleep 30 >/dev/null 2>&1 & echo $!"])
+      .process_group();
+    let (mut rx, child) = cmd.spawn().unwrap();
+    let pgid = child.pid();
+
+    let worker: u32 = crate::async_runtime::block_on(async move {
+      let mut worker = None;
+      while let Some(event) = rx.recv().await {
+        match event {
+          CommandEvent::Stdout(line) => worker = line.trim().parse().ok(),
+          CommandEvent::Terminated(payload) => assert_eq!(payload.code, Some(0)),
+          _ => {}
+        }
+      }
+      worker.unwrap()
+    });
+    assert!(signal(worker, false, 0).is_ok());
+
+    child.kill().unwrap();
+    assert!(wait_until(
+      || signal(pgid, true, 0).is_err(),
+      Duration::from_secs(5)
+    ));
+  }
+
   #[cfg(not(windows))]
   #[test]
   // test the failure case
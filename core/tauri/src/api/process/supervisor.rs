@@ -0,0 +1,522 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+  collections::{HashMap, VecDeque},
+  net::{Ipv4Addr, SocketAddr, TcpStream},
+  sync::{
+    atomic::{AtomicU32, Ordering},
+    mpsc::{self, RecvTimeoutError},
+    Arc, Mutex,
+  },
+  thread::{sleep, spawn},
+  time::{Duration, Instant},
+};
+
+use crate::async_runtime::{block_on as block_on_task, channel, Receiver, Sender};
+use serde::{Serialize, Serializer};
+
+use super::{Command, CommandChild, CommandEvent, TerminatedPayload};
+
+type SupervisorStore = Mutex<HashMap<u32, Arc<Shared>>>;
+
+fn supervisors() -> &'static SupervisorStore {
+  use once_cell::sync::Lazy;
+  static STORE: Lazy<SupervisorStore> = Lazy::new(Default::default);
+  &STORE
+}
+
+/// Stops every running supervisor so the processes terminated by [`super::kill_children`] are not restarted.
+pub(crate) fn stop_supervisors() {
+  for shared in supervisors().lock().unwrap().values() {
+    let _ = shared.messages.lock().unwrap().send(Message::Stop);
+  }
+}
+
+/// How a [`Supervisor`] detects that its process is ready.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ReadinessProbe {
+  /// The process is ready once its stdout contains the given text.
+  Stdout(String),
+  /// The process is ready once it accepts connections on the given local TCP port.
+  TcpPort(u16),
+}
+
+/// An event sent by a [`Supervisor`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", content = "payload")]
+#[non_exhaustive]
+pub enum SupervisorEvent {
+  /// The process was spawned.
+  Started {
+    /// The process pid.
+    pid: u32,
+  },
+  /// The process passed the readiness probe, or was spawned if there's no probe.
+  Ready {
+    /// The process pid.
+    pid: u32,
+  },
+  /// The process didn't pass the readiness probe in time, so it is terminated and restarted.
+  NotReady {
+    /// The process pid.
+    pid: u32,
+  },
+  /// An output or error event of the process.
+  Output(CommandEvent),
+  /// The process terminated.
+  Terminated(TerminatedPayload),
+  /// The process is restarted after the delay.
+  Restarting {
+    /// The number of restarts so far, including this one.
+    attempt: u32,
+    /// The delay before the process is spawned, in milliseconds when serialized.
+    #[serde(serialize_with = "serialize_millis")]
+    delay: Duration,
+  },
+  /// The process terminated too many times within the restart limit period and won't be restarted.
+  RestartLimitReached,
+  /// The supervisor was stopped with [`SupervisorHandle::stop`] and the process terminated.
+  Stopped,
+}
+
+fn serialize_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+  serializer.serialize_u64(duration.as_millis() as u64)
+}
+
+enum Message {
+  Event(u32, CommandEvent),
+  Ready(u32),
+  Stop,
+}
+
+#[derive(Debug)]
+struct Shared {
+  messages: Mutex<mpsc::Sender<Message>>,
+  child: Mutex<Option<CommandChild>>,
+}
+
+/// A handle to a process started with [`Supervisor::spawn`].
+#[derive(Debug, Clone)]
+pub struct SupervisorHandle {
+  id: u32,
+  shared: Arc<Shared>,
+}
+
+impl SupervisorHandle {
+  /// The supervisor identifier, unique for the lifetime of the app.
+  pub fn id(&self) -> u32 {
+    self.id
+  }
+
+  /// Returns the pid of the current process, or `None` while it is being restarted.
+  pub fn pid(&self) -> Option<u32> {
+    self
+      .shared
+      .child
+      .lock()
+      .unwrap()
+      .as_ref()
+      .map(CommandChild::pid)
+  }
+
+  /// Writes to the stdin of the current process.
+  pub fn write(&self, buf: &[u8]) -> crate::api::Result<()> {
+    match self.shared.child.lock().unwrap().as_mut() {
+      Some(child) => child.write(buf),
+      None => Err(crate::api::Error::Command(
+        "the supervised process is not running".into(),
+      )),
+    }
+  }
+
+  /// Stops the supervisor, terminating the process according to its [`super::KillPolicy`].
+  ///
+  /// The [`SupervisorEvent::Stopped`] event is sent once the process is terminated.
+  pub fn stop(&self) {
+    let _ = self.shared.messages.lock().unwrap().send(Message::Stop);
+  }
+}
+
+/// Keeps a process running, restarting it with an exponential backoff when it terminates.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use tauri::api::process::{Command, ReadinessProbe, Supervisor, SupervisorEvent};
+/// tauri::async_runtime::spawn(async move {
+///   let command = Command::new_sidecar("server").expect("failed to create the sidecar command");
+///   let (mut rx, _handle) = Supervisor::new(command)
+///     .readiness_probe(ReadinessProbe::TcpPort(8080))
+///     .restart_limit(5, Duration::from_secs(60))
+///     .spawn()
+///     .expect("failed to spawn the server");
+///
+///   while let Some(event) = rx.recv().await {
+///     if let SupervisorEvent::Ready { pid } = event {
+///       println!("server {} is ready", pid);
+///     }
+///   }
+/// });
+/// ```
+#[derive(Debug)]
+pub struct Supervisor {
+  command: Command,
+  readiness_probe: Option<ReadinessProbe>,
+  readiness_timeout: Duration,
+  initial_backoff: Duration,
+  max_backoff: Duration,
+  restart_limit: Option<(u32, Duration)>,
+}
+
+impl Supervisor {
+  /// Creates a supervisor for the given command, which is spawned again on every restart.
+  pub fn new(command: Command) -> Self {
+    Self {
+      command,
+      readiness_probe: None,
+      readiness_timeout: Duration::from_secs(30),
+      initial_backoff: Duration::from_millis(500),
+      max_backoff: Duration::from_secs(30),
+      restart_limit: None,
+    }
+  }
+
+  /// Sets how the supervisor detects that the process is ready.
+  ///
+  /// Without a probe, the process is considered ready as soon as it is spawned.
+  #[must_use]
+  pub fn readiness_probe(mut self, probe: ReadinessProbe) -> Self {
+    self.readiness_probe.replace(probe);
+    self
+  }
+
+  /// Sets how long the process is given to pass the readiness probe before it is restarted. Defaults to 30 seconds.
+  #[must_use]
+  pub fn readiness_timeout(mut self, timeout: Duration) -> Self {
+    self.readiness_timeout = timeout;
+    self
+  }
+
+  /// Sets the delay before the first restart and the maximum delay. Defaults to 500 milliseconds and 30 seconds.
+  ///
+  /// The delay doubles after each restart, and is reset once the process stays up for longer than the maximum delay.
+  #[must_use]
+  pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+    self.initial_backoff = initial;
+    self.max_backoff = max.max(initial);
+    self
+  }
+
+  /// Gives up restarting the process after `max_restarts` restarts within `period`,
+  /// sending [`SupervisorEvent::RestartLimitReached`]. By default the process is always restarted.
+  #[must_use]
+  pub fn restart_limit(mut self, max_restarts: u32, period: Duration) -> Self {
+    self.restart_limit.replace((max_restarts, period));
+    self
+  }
+
+  /// Spawns the process and starts supervising it.
+  ///
+  /// Fails if the process can't be spawned the first time.
+  /// The supervisor is stopped with [`SupervisorHandle::stop`], or by [`super::kill_children`] when the app exits.
+  pub fn spawn(self) -> crate::api::Result<(Receiver<SupervisorEvent>, SupervisorHandle)> {
+    static NEXT_ID: AtomicU32 = AtomicU32::new(0);
+
+    let first = self.command.clone().spawn()?;
+    let (messages_tx, messages) = mpsc::channel();
+    let shared = Arc::new(Shared {
+      messages: Mutex::new(messages_tx),
+      child: Default::default(),
+    });
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    supervisors().lock().unwrap().insert(id, shared.clone());
+
+    let (tx, rx) = channel(1);
+    let shared_ = shared.clone();
+    spawn(move || {
+      self.supervise(first, &shared_, &messages, &tx);
+      supervisors().lock().unwrap().remove(&id);
+    });
+
+    Ok((rx, SupervisorHandle { id, shared }))
+  }
+
+  fn supervise(
+    &self,
+    first: (Receiver<CommandEvent>, CommandChild),
+    shared: &Shared,
+    messages: &mpsc::Receiver<Message>,
+    tx: &Sender<SupervisorEvent>,
+  ) {
+    let mut first = Some(first);
+    let mut restarts: VecDeque<Instant> = VecDeque::new();
+    let mut delay = self.initial_backoff;
+    let mut attempt = 0;
+    let mut run: u32 = 0;
+    loop {
+      run = run.wrapping_add(1);
+      let started = Instant::now();
+      match first
+        .take()
+        .map(Ok)
+        .unwrap_or_else(|| self.command.clone().spawn())
+      {
+        Ok((rx, child)) => {
+          if self.watch(run, rx, child, shared, messages, tx) == Exit::Stopped {
+            return;
+          }
+        }
+        Err(e) => send(
+          tx,
+          SupervisorEvent::Output(CommandEvent::Error(e.to_string())),
+        ),
+      }
+
+      if started.elapsed() >= self.max_backoff {
+        delay = self.initial_backoff;
+      }
+      if let Some((max_restarts, period)) = self.restart_limit {
+        while matches!(restarts.front(), Some(t) if t.elapsed() > period) {
+          restarts.pop_front();
+        }
+        if restarts.len() >= max_restarts as usize {
+          send(tx, SupervisorEvent::RestartLimitReached);
+          return;
+        }
+        restarts.push_back(Instant::now());
+      }
+
+      attempt += 1;
+      send(tx, SupervisorEvent::Restarting { attempt, delay });
+      let deadline = Instant::now() + delay;
+      loop {
+        match messages.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+          Ok(Message::Stop) => {
+            send(tx, SupervisorEvent::Stopped);
+            return;
+          }
+          // events of the previous process
+          Ok(_) => {}
+          Err(_) => break,
+        }
+      }
+      delay = (delay * 2).min(self.max_backoff);
+    }
+  }
+
+  /// Forwards the events of a process until it terminates or the supervisor is stopped.
+  fn watch(
+    &self,
+    run: u32,
+    mut rx: Receiver<CommandEvent>,
+    child: CommandChild,
+    shared: &Shared,
+    messages: &mpsc::Receiver<Message>,
+    tx: &Sender<SupervisorEvent>,
+  ) -> Exit {
+    let pid = child.pid();
+    shared.child.lock().unwrap().replace(child);
+    send(tx, SupervisorEvent::Started { pid });
+
+    let messages_tx = shared.messages.lock().unwrap().clone();
+    let events_tx = messages_tx.clone();
+    spawn(move || {
+      while let Some(event) = block_on_task(rx.recv()) {
+        if events_tx.send(Message::Event(run, event)).is_err() {
+          break;
+        }
+      }
+    });
+
+    let mut readiness = Readiness::Pending;
+    let mut matcher = None;
+    match &self.readiness_probe {
+      None => {
+        readiness = Readiness::Ready;
+        send(tx, SupervisorEvent::Ready { pid });
+      }
+      Some(ReadinessProbe::Stdout(pattern)) => matcher = Some(Matcher::new(pattern)),
+      Some(ReadinessProbe::TcpPort(port)) => {
+        let address = SocketAddr::from((Ipv4Addr::LOCALHOST, *port));
+        let timeout = self.readiness_timeout;
+        spawn(move || {
+          let started = Instant::now();
+          while started.elapsed() < timeout {
+            if TcpStream::connect_timeout(&address, Duration::from_millis(100)).is_ok() {
+              let _ = messages_tx.send(Message::Ready(run));
+              return;
+            }
+            sleep(Duration::from_millis(100));
+          }
+        });
+      }
+    }
+
+    let deadline = Instant::now() + self.readiness_timeout;
+    loop {
+      let message = if readiness == Readiness::Pending {
+        match messages.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+          Ok(message) => message,
+          Err(RecvTimeoutError::Timeout) => {
+            readiness = Readiness::TimedOut;
+            send(tx, SupervisorEvent::NotReady { pid });
+            terminate(shared);
+            continue;
+          }
+          Err(RecvTimeoutError::Disconnected) => return Exit::Terminated,
+        }
+      } else {
+        match messages.recv() {
+          Ok(message) => message,
+          Err(_) => return Exit::Terminated,
+        }
+      };
+
+      match message {
+        Message::Stop => {
+          terminate(shared);
+          send(tx, SupervisorEvent::Stopped);
+          return Exit::Stopped;
+        }
+        Message::Ready(r) if r == run && readiness == Readiness::Pending => {
+          readiness = Readiness::Ready;
+          send(tx, SupervisorEvent::Ready { pid });
+        }
+        Message::Event(r, CommandEvent::Terminated(payload)) if r == run => {
+          shared.child.lock().unwrap().take();
+          send(tx, SupervisorEvent::Terminated(payload));
+          return Exit::Terminated;
+        }
+        Message::Event(r, event) if r == run => {
+          let ready = match &mut matcher {
+            Some(matcher) if readiness == Readiness::Pending => matcher.matches(&event),
+            _ => false,
+          };
+          send(tx, SupervisorEvent::Output(event));
+          if ready {
+            readiness = Readiness::Ready;
+            send(tx, SupervisorEvent::Ready { pid });
+          }
+        }
+        // messages of a previous process
+        _ => {}
+      }
+    }
+  }
+}
+
+#[derive(PartialEq, Eq)]
+enum Exit {
+  Terminated,
+  Stopped,
+}
+
+#[derive(PartialEq, Eq)]
+enum Readiness {
+  Pending,
+  Ready,
+  TimedOut,
+}
+
+/// Searches the stdout of a process for a pattern, which may be split across byte chunks.
+struct Matcher {
+  pattern: Vec<u8>,
+  tail: Vec<u8>,
+}
+
+impl Matcher {
+  fn new(pattern: &str) -> Self {
+    Self {
+      pattern: pattern.as_bytes().to_vec(),
+      tail: Vec::new(),
+    }
+  }
+
+  fn matches(&mut self, event: &CommandEvent) -> bool {
+    match event {
+      CommandEvent::Stdout(line) => contains(line.as_bytes(), &self.pattern),
+      CommandEvent::StdoutBytes(bytes) => {
+        self.tail.extend_from_slice(bytes);
+        if contains(&self.tail, &self.pattern) {
+          return true;
+        }
+        // keep enough bytes to match a pattern split across chunks
+        let keep = self.pattern.len().saturating_sub(1);
+        let len = self.tail.len();
+        self.tail.drain(..len.saturating_sub(keep));
+        false
+      }
+      _ => false,
+    }
+  }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+  needle.is_empty() || haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+fn terminate(shared: &Shared) {
+  let child = shared.child.lock().unwrap().take();
+  if let Some(child) = child {
+    let _ = child.terminate();
+  }
+}
+
+fn send(tx: &Sender<SupervisorEvent>, event: SupervisorEvent) {
+  let _ = block_on_task(tx.send(event));
+}
+
+#[cfg(test)]
+mod test {
+  #[cfg(not(windows))]
+  use super::*;
+
+  #[cfg(not(windows))]
+  #[test]
+  fn test_supervisor_restarts() {
+    let command = Command::new("sh").args(["-c", "echo listening; exit 1"]);
+    let (mut rx, _handle) = Supervisor::new(command)
+      .readiness_probe(ReadinessProbe::Stdout("listening".into()))
+      .backoff(Duration::from_millis(10), Duration::from_millis(20))
+      .restart_limit(2, Duration::from_secs(60))
+      .spawn()
+      .unwrap();
+
+    crate::async_runtime::block_on(async move {
+      let (mut started, mut ready, mut restarts) = (0, 0, 0);
+      while let Some(event) = rx.recv().await {
+        match event {
+          SupervisorEvent::Started { .. } => started += 1,
+          SupervisorEvent::Ready { .. } => ready += 1,
+          SupervisorEvent::Restarting { attempt, .. } => restarts = attempt,
+          SupervisorEvent::RestartLimitReached => break,
+          _ => {}
+        }
+      }
+      assert_eq!((started, ready, restarts), (3, 3, 2));
+    });
+  }
+
+  #[cfg(not(windows))]
+  #[test]
+  fn test_supervisor_stop() {
+    let command = Command::new("sleep").args(["30"]);
+    let (mut rx, handle) = Supervisor::new(command).spawn().unwrap();
+
+    crate::async_runtime::block_on(async move {
+      while let Some(event) = rx.recv().await {
+        match event {
+          SupervisorEvent::Ready { .. } => handle.stop(),
+          SupervisorEvent::Restarting { .. } => panic!("unexpected restart"),
+          SupervisorEvent::Stopped => break,
+          _ => {}
+        }
+      }
+      assert!(handle.pid().is_none());
+    });
+  }
+}
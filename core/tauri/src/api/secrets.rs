@@ -0,0 +1,639 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Storing secrets such as passwords and tokens in the credential store of the operating system.
+//!
+//! A secret is identified by a service and an account name, and it is stored with the
+//! [Secret Service](https://specifications.freedesktop.org/secret-service/) D-Bus API on Linux,
+//! in the Keychain on macOS and in the Credential Manager on Windows.
+//!
+//! # Headless Linux
+//!
+//! The Secret Service is only available when a provider such as GNOME Keyring or KWallet runs in the D-Bus session.
+//! When it can't be reached, or its default collection can't be opened or unlocked, the secrets are stored in an encrypted file in the directory set with [`Secrets::fallback_dir`],
+//! or an error is returned if it is not set.
+//! The secrets stored in the file are still returned and deleted once the Secret Service is available,
+//! and a secret stored in the Secret Service replaces the one of the file.
+//! The file is encrypted with a key generated on its first use and only readable by the current user,
+//! so the secrets are not written in plaintext, but this does not protect them from a process that can read the key file.
+
+use std::path::{Path, PathBuf};
+
+/// The name of the encrypted file storing the secrets, relative to the fallback directory.
+const FALLBACK_FILE_NAME: &str = ".secrets";
+/// The name of the file storing the key of the encrypted file, relative to the fallback directory.
+const FALLBACK_KEY_FILE_NAME: &str = ".secrets.key";
+
+/// The encrypted file, its temporary file and its key file in the fallback directory.
+#[cfg_attr(not(secrets_any), allow(dead_code))]
+pub(crate) fn fallback_files(dir: &Path) -> [PathBuf; 3] {
+  let file = dir.join(FALLBACK_FILE_NAME);
+  [
+    file.with_extension("tmp"),
+    dir.join(FALLBACK_KEY_FILE_NAME),
+    file,
+  ]
+}
+
+/// Access to the credential store. See the [module documentation](self) for more information.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tauri::api::secrets::Secrets;
+///
+/// tauri::Builder::default()
+///   .setup(|app| {
+///     let secrets = Secrets::new()
+///       .fallback_dir(tauri::api::path::app_local_data_dir(&app.config()).expect("no data directory"));
+///     secrets.set("com.tauri.dev", "user@tauri.app", "refresh token")?;
+///     assert_eq!(
+///       secrets.get("com.tauri.dev", "user@tauri.app")?.as_deref(),
+///       Some("refresh token")
+///     );
+///     secrets.delete("com.tauri.dev", "user@tauri.app")?;
+///     Ok(())
+///   });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Secrets {
+  #[cfg_attr(any(target_os = "macos", windows), allow(dead_code))]
+  fallback_dir: Option<PathBuf>,
+  #[cfg(test)]
+  mock: bool,
+}
+
+impl Secrets {
+  /// Creates an accessor to the credential store without the encrypted file fallback.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Keeps the secrets in memory, so the tests don't use the credential store.
+  #[cfg(test)]
+  #[cfg_attr(not(secrets_any), allow(dead_code))]
+  pub(crate) fn mock() -> Self {
+    Self {
+      mock: true,
+      ..Default::default()
+    }
+  }
+
+  /// Sets the directory of the encrypted file storing the secrets when the Secret Service is not available on Linux.
+  ///
+  /// Use a directory of the app such as the [app local data directory](crate::api::path::app_local_data_dir).
+  /// It is ignored on macOS and Windows.
+  #[must_use]
+  pub fn fallback_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+    self.fallback_dir.replace(dir.into());
+    self
+  }
+
+  /// Gets the secret of the account for the service, or `None` if it is not stored.
+  pub fn get(&self, service: &str, account: &str) -> crate::api::Result<Option<String>> {
+    #[cfg(test)]
+    if self.mock {
+      return Ok(mock::get(service, account));
+    }
+    imp::get(self, service, account)
+  }
+
+  /// Stores the secret of the account for the service, replacing the existing one.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** the secret must not exceed 2560 bytes.
+  pub fn set(&self, service: &str, account: &str, secret: &str) -> crate::api::Result<()> {
+    #[cfg(test)]
+    if self.mock {
+      mock::set(service, account, secret);
+      return Ok(());
+    }
+    imp::set(self, service, account, secret)
+  }
+
+  /// Deletes the secret of the account for the service. Returns whether it was stored.
+  pub fn delete(&self, service: &str, account: &str) -> crate::api::Result<bool> {
+    #[cfg(test)]
+    if self.mock {
+      return Ok(mock::delete(service, account));
+    }
+    imp::delete(self, service, account)
+  }
+}
+
+#[cfg(test)]
+mod mock {
+  use once_cell::sync::Lazy;
+  use std::{collections::HashMap, sync::Mutex};
+
+  static SECRETS: Lazy<Mutex<HashMap<(String, String), String>>> = Lazy::new(Default::default);
+
+  pub fn get(service: &str, account: &str) -> Option<String> {
+    SECRETS
+      .lock()
+      .unwrap()
+      .get(&(service.into(), account.into()))
+      .cloned()
+  }
+
+  pub fn set(service: &str, account: &str, secret: &str) {
+    SECRETS
+      .lock()
+      .unwrap()
+      .insert((service.into(), account.into()), secret.into());
+  }
+
+  pub fn delete(service: &str, account: &str) -> bool {
+    SECRETS
+      .lock()
+      .unwrap()
+      .remove(&(service.into(), account.into()))
+      .is_some()
+  }
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+mod imp {
+  use super::{fallback::EncryptedFile, Secrets};
+  use secret_service::{Collection, EncryptionType, SecretService};
+  use std::collections::HashMap;
+
+  fn error(error: secret_service::Error) -> crate::api::Error {
+    crate::api::Error::Secrets(error.to_string())
+  }
+
+  fn attributes<'a>(service: &'a str, account: &'a str) -> HashMap<&'a str, &'a str> {
+    HashMap::from([("service", service), ("account", account)])
+  }
+
+  fn unlocked<'a>(service: &'a SecretService<'a>) -> secret_service::Result<Collection<'a>> {
+    let collection = service.get_default_collection()?;
+    if collection.is_locked()? {
+      collection.unlock()?;
+    }
+    Ok(collection)
+  }
+
+  /// The encrypted file, if the fallback directory is set.
+  fn fallback_file(secrets: &Secrets) -> Option<EncryptedFile> {
+    secrets.fallback_dir.as_deref().map(EncryptedFile::new)
+  }
+
+  /// The encrypted file used when the Secret Service can't be reached.
+  fn fallback(secrets: &Secrets, e: secret_service::Error) -> crate::api::Result<EncryptedFile> {
+    fallback_file(secrets).ok_or_else(|| error(e))
+  }
+
+  /// Runs `f` with the unlocked default collection of the Secret Service,
+  /// or returns the error if the service or its collection can't be reached.
+  fn with_collection<T>(
+    f: impl FnOnce(&Collection<'_>) -> crate::api::Result<T>,
+  ) -> secret_service::Result<crate::api::Result<T>> {
+    let secret_service = SecretService::new(EncryptionType::Dh)?;
+    let collection = unlocked(&secret_service)?;
+    Ok(f(&collection))
+  }
+
+  pub fn get(
+    secrets: &Secrets,
+    service: &str,
+    account: &str,
+  ) -> crate::api::Result<Option<String>> {
+    let secret = match with_collection(|collection| {
+      let items = collection
+        .search_items(attributes(service, account))
+        .map_err(error)?;
+      match items.first() {
+        Some(item) => Ok(Some(String::from_utf8(item.get_secret().map_err(error)?)?)),
+        None => Ok(None),
+      }
+    }) {
+      Ok(secret) => secret?,
+      Err(e) => return fallback(secrets, e)?.get(service, account),
+    };
+    // the secret may have been stored in the file while the Secret Service was not available
+    match (secret, fallback_file(secrets)) {
+      (None, Some(file)) => file.get(service, account),
+      (secret, _) => Ok(secret),
+    }
+  }
+
+  pub fn set(
+    secrets: &Secrets,
+    service: &str,
+    account: &str,
+    secret: &str,
+  ) -> crate::api::Result<()> {
+    match with_collection(|collection| {
+      collection
+        .create_item(
+          &format!("{account}@{service}"),
+          attributes(service, account),
+          secret.as_bytes(),
+          true,
+          "text/plain",
+        )
+        .map_err(error)?;
+      Ok(())
+    }) {
+      Ok(result) => result?,
+      Err(e) => return fallback(secrets, e)?.set(service, account, secret),
+    }
+    // so the replaced secret is not returned if the Secret Service is not available later
+    if let Some(file) = fallback_file(secrets) {
+      file.delete(service, account)?;
+    }
+    Ok(())
+  }
+
+  pub fn delete(secrets: &Secrets, service: &str, account: &str) -> crate::api::Result<bool> {
+    let deleted = match with_collection(|collection| {
+      let items = collection
+        .search_items(attributes(service, account))
+        .map_err(error)?;
+      for item in &items {
+        item.delete().map_err(error)?;
+      }
+      Ok(!items.is_empty())
+    }) {
+      Ok(deleted) => deleted?,
+      Err(e) => return fallback(secrets, e)?.delete(service, account),
+    };
+    let deleted_from_file = match fallback_file(secrets) {
+      Some(file) => file.delete(service, account)?,
+      None => false,
+    };
+    Ok(deleted || deleted_from_file)
+  }
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+mod fallback {
+  use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    XChaCha20Poly1305, XNonce,
+  };
+  use once_cell::sync::Lazy;
+  use std::{
+    collections::BTreeMap,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+  };
+
+  use super::{FALLBACK_FILE_NAME as FILE_NAME, FALLBACK_KEY_FILE_NAME as KEY_FILE_NAME};
+
+  const NONCE_SIZE: usize = 24;
+
+  /// The secrets by service and account.
+  type Entries = BTreeMap<String, BTreeMap<String, String>>;
+
+  /// Serializes the updates of the file, which is entirely rewritten on each change.
+  static LOCK: Lazy<Mutex<()>> = Lazy::new(Default::default);
+
+  fn error(message: &str) -> crate::api::Error {
+    crate::api::Error::Secrets(message.into())
+  }
+
+  /// Opens a file that is only readable by the current user.
+  fn create_private(path: &Path, create_new: bool) -> std::io::Result<fs::File> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true);
+    if create_new {
+      options.create_new(true);
+    } else {
+      options.create(true).truncate(true);
+    }
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::OpenOptionsExt;
+      options.mode(0o600);
+    }
+    options.open(path)
+  }
+
+  /// A file storing the secrets encrypted with XChaCha20-Poly1305.
+  pub struct EncryptedFile {
+    file: PathBuf,
+    key_file: PathBuf,
+  }
+
+  impl EncryptedFile {
+    pub fn new(dir: &Path) -> Self {
+      Self {
+        file: dir.join(FILE_NAME),
+        key_file: dir.join(KEY_FILE_NAME),
+      }
+    }
+
+    fn cipher(&self) -> crate::api::Result<XChaCha20Poly1305> {
+      let key = match fs::read(&self.key_file) {
+        Ok(key) => key,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+          if self.file.exists() {
+            return Err(error(
+              "the key of the encrypted secrets file is missing, delete the file to reset it",
+            ));
+          }
+          if let Some(parent) = self.key_file.parent() {
+            fs::create_dir_all(parent)?;
+          }
+          let key = rand::random::<[u8; 32]>().to_vec();
+          create_private(&self.key_file, true)?.write_all(&key)?;
+          key
+        }
+        Err(e) => return Err(e.into()),
+      };
+      XChaCha20Poly1305::new_from_slice(&key)
+        .map_err(|_| error("invalid key for the encrypted secrets file"))
+    }
+
+    fn read(&self, cipher: &XChaCha20Poly1305) -> crate::api::Result<Entries> {
+      let bytes = match fs::read(&self.file) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Entries::new()),
+        Err(e) => return Err(e.into()),
+      };
+      if bytes.len() < NONCE_SIZE {
+        return Err(error("the encrypted secrets file is corrupted"));
+      }
+      let (nonce, ciphertext) = bytes.split_at(NONCE_SIZE);
+      let plaintext = cipher
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| error("failed to decrypt the encrypted secrets file"))?;
+      serde_json::from_slice(&plaintext).map_err(Into::into)
+    }
+
+    /// Writes the entries to a temporary file and moves it to the secrets file,
+    /// so the secrets are not lost if the app exits while writing it.
+    fn write(&self, cipher: &XChaCha20Poly1305, entries: &Entries) -> crate::api::Result<()> {
+      let nonce = rand::random::<[u8; NONCE_SIZE]>();
+      let ciphertext = cipher
+        .encrypt(
+          XNonce::from_slice(&nonce),
+          serde_json::to_vec(entries)?.as_slice(),
+        )
+        .map_err(|_| error("failed to encrypt the secrets"))?;
+      let temp_file = self.file.with_extension("tmp");
+      let mut temp = create_private(&temp_file, false)?;
+      temp.write_all(&nonce)?;
+      temp.write_all(&ciphertext)?;
+      temp.sync_all()?;
+      fs::rename(&temp_file, &self.file)?;
+      Ok(())
+    }
+
+    pub fn get(&self, service: &str, account: &str) -> crate::api::Result<Option<String>> {
+      let _lock = LOCK.lock().unwrap();
+      if !self.file.exists() {
+        return Ok(None);
+      }
+      let cipher = self.cipher()?;
+      let entries = self.read(&cipher)?;
+      Ok(
+        entries
+          .get(service)
+          .and_then(|accounts| accounts.get(account))
+          .cloned(),
+      )
+    }
+
+    pub fn set(&self, service: &str, account: &str, secret: &str) -> crate::api::Result<()> {
+      let _lock = LOCK.lock().unwrap();
+      let cipher = self.cipher()?;
+      let mut entries = self.read(&cipher)?;
+      entries
+        .entry(service.into())
+        .or_default()
+        .insert(account.into(), secret.into());
+      self.write(&cipher, &entries)
+    }
+
+    pub fn delete(&self, service: &str, account: &str) -> crate::api::Result<bool> {
+      let _lock = LOCK.lock().unwrap();
+      if !self.file.exists() {
+        return Ok(false);
+      }
+      let cipher = self.cipher()?;
+      let mut entries = self.read(&cipher)?;
+      let deleted = match entries.get_mut(service) {
+        Some(accounts) => {
+          let deleted = accounts.remove(account).is_some();
+          if accounts.is_empty() {
+            entries.remove(service);
+          }
+          deleted
+        }
+        None => false,
+      };
+      if deleted {
+        self.write(&cipher, &entries)?;
+      }
+      Ok(deleted)
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypts_secrets() {
+      let dir = tempfile::tempdir().unwrap();
+      let file = EncryptedFile::new(&dir.path().join("data"));
+      assert_eq!(file.get("service", "account").unwrap(), None);
+      file.set("service", "account", "top secret").unwrap();
+      file.set("service", "other", "other secret").unwrap();
+
+      let file = EncryptedFile::new(&dir.path().join("data"));
+      assert_eq!(
+        file.get("service", "account").unwrap().as_deref(),
+        Some("top secret")
+      );
+      let contents = fs::read(dir.path().join("data").join(FILE_NAME)).unwrap();
+      assert!(!String::from_utf8_lossy(&contents).contains("top secret"));
+
+      assert!(file.delete("service", "account").unwrap());
+      assert!(!file.delete("service", "account").unwrap());
+      assert_eq!(file.get("service", "account").unwrap(), None);
+      assert_eq!(
+        file.get("service", "other").unwrap().as_deref(),
+        Some("other secret")
+      );
+    }
+
+    #[test]
+    fn rejects_tampered_file() {
+      let dir = tempfile::tempdir().unwrap();
+      let file = EncryptedFile::new(dir.path());
+      file.set("service", "account", "top secret").unwrap();
+
+      let path = dir.path().join(FILE_NAME);
+      let mut contents = fs::read(&path).unwrap();
+      let last = contents.len() - 1;
+      contents[last] ^= 1;
+      fs::write(&path, contents).unwrap();
+      assert!(file.get("service", "account").is_err());
+
+      fs::remove_file(dir.path().join(KEY_FILE_NAME)).unwrap();
+      assert!(file.set("service", "account", "new secret").is_err());
+    }
+  }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+  use super::Secrets;
+  use security_framework::passwords::{
+    delete_generic_password, get_generic_password, set_generic_password,
+  };
+
+  /// `errSecItemNotFound`
+  const ITEM_NOT_FOUND: i32 = -25300;
+
+  fn error(error: security_framework::base::Error) -> crate::api::Error {
+    crate::api::Error::Secrets(error.to_string())
+  }
+
+  pub fn get(_: &Secrets, service: &str, account: &str) -> crate::api::Result<Option<String>> {
+    match get_generic_password(service, account) {
+      Ok(secret) => Ok(Some(String::from_utf8(secret)?)),
+      Err(e) if e.code() == ITEM_NOT_FOUND => Ok(None),
+      Err(e) => Err(error(e)),
+    }
+  }
+
+  pub fn set(_: &Secrets, service: &str, account: &str, secret: &str) -> crate::api::Result<()> {
+    set_generic_password(service, account, secret.as_bytes()).map_err(error)
+  }
+
+  pub fn delete(_: &Secrets, service: &str, account: &str) -> crate::api::Result<bool> {
+    match delete_generic_password(service, account) {
+      Ok(()) => Ok(true),
+      Err(e) if e.code() == ITEM_NOT_FOUND => Ok(false),
+      Err(e) => Err(error(e)),
+    }
+  }
+}
+
+#[cfg(windows)]
+mod imp {
+  use super::Secrets;
+  use std::{ffi::OsStr, io, os::windows::ffi::OsStrExt};
+
+  use windows::{
+    core::{PCWSTR, PWSTR},
+    Win32::{
+      Foundation::ERROR_NOT_FOUND,
+      Security::Credentials::{
+        CredDeleteW, CredFree, CredReadW, CredWriteW, CREDENTIALW, CRED_PERSIST_LOCAL_MACHINE,
+        CRED_TYPE_GENERIC,
+      },
+    },
+  };
+
+  /// `CRED_MAX_CREDENTIAL_BLOB_SIZE`
+  const MAX_SECRET_SIZE: usize = 5 * 512;
+
+  fn encode(value: impl AsRef<OsStr>) -> Vec<u16> {
+    value
+      .as_ref()
+      .encode_wide()
+      .chain(std::iter::once(0))
+      .collect()
+  }
+
+  /// The credential target is `{account}@{service}`, with the `\` and `@` of the account escaped
+  /// so the first unescaped `@` always separates the account from the service.
+  fn target_name(service: &str, account: &str) -> Vec<u16> {
+    let account = account.replace('\\', "\\\\").replace('@', "\\@");
+    encode(format!("{account}@{service}"))
+  }
+
+  /// Maps the last error to `Ok(false)` if the credential doesn't exist.
+  fn not_found() -> crate::api::Result<bool> {
+    let error = io::Error::last_os_error();
+    if error.raw_os_error() == Some(ERROR_NOT_FOUND.0 as i32) {
+      Ok(false)
+    } else {
+      Err(error.into())
+    }
+  }
+
+  pub fn get(_: &Secrets, service: &str, account: &str) -> crate::api::Result<Option<String>> {
+    let target = target_name(service, account);
+    let mut credential: *mut CREDENTIALW = std::ptr::null_mut();
+    let found = unsafe {
+      CredReadW(
+        PCWSTR(target.as_ptr()),
+        CRED_TYPE_GENERIC.0,
+        0,
+        &mut credential,
+      )
+    };
+    if !found.as_bool() {
+      return not_found().map(|_| None);
+    }
+    let secret = unsafe {
+      let size = (*credential).CredentialBlobSize as usize;
+      let secret = if size == 0 {
+        Vec::new()
+      } else {
+        std::slice::from_raw_parts((*credential).CredentialBlob, size).to_vec()
+      };
+      CredFree(credential as *const _);
+      secret
+    };
+    Ok(Some(String::from_utf8(secret)?))
+  }
+
+  pub fn set(_: &Secrets, service: &str, account: &str, secret: &str) -> crate::api::Result<()> {
+    if secret.len() > MAX_SECRET_SIZE {
+      return Err(crate::api::Error::Secrets(format!(
+        "the secret exceeds the {MAX_SECRET_SIZE} bytes limit of the Credential Manager"
+      )));
+    }
+    let mut target = target_name(service, account);
+    let mut user_name = encode(account);
+    let mut blob = secret.as_bytes().to_vec();
+    let credential = CREDENTIALW {
+      Type: CRED_TYPE_GENERIC,
+      TargetName: PWSTR(target.as_mut_ptr()),
+      CredentialBlobSize: blob.len() as u32,
+      CredentialBlob: blob.as_mut_ptr(),
+      Persist: CRED_PERSIST_LOCAL_MACHINE,
+      UserName: PWSTR(user_name.as_mut_ptr()),
+      ..Default::default()
+    };
+    if unsafe { CredWriteW(&credential, 0) }.as_bool() {
+      Ok(())
+    } else {
+      Err(io::Error::last_os_error().into())
+    }
+  }
+
+  pub fn delete(_: &Secrets, service: &str, account: &str) -> crate::api::Result<bool> {
+    let target = target_name(service, account);
+    if unsafe { CredDeleteW(PCWSTR(target.as_ptr()), CRED_TYPE_GENERIC.0, 0) }.as_bool() {
+      Ok(true)
+    } else {
+      not_found()
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use super::{encode, target_name};
+
+    #[test]
+    fn target_name_is_unambiguous() {
+      assert_eq!(target_name("app", "user"), encode("user@app"));
+      assert_ne!(target_name("b@c", "a"), target_name("c", "a@b"));
+      assert_eq!(target_name("c", "a@b"), encode("a\\@b@c"));
+      assert_ne!(target_name("c", "a\\"), target_name("\\@c", "a"));
+    }
+  }
+}
@@ -2,6 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
+#[cfg(desktop)]
+mod opened;
+#[cfg(all(desktop, feature = "single-instance"))]
+mod single_instance;
 #[cfg(all(desktop, feature = "system-tray"))]
 pub(crate) mod tray;
 
@@ -29,6 +33,8 @@ use crate::{
 
 #[cfg(shell_scope)]
 use crate::scope::ShellScope;
+#[cfg(desktop)]
+use crate::window::state::WindowStateStore;
 
 use raw_window_handle::HasRawDisplayHandle;
 use tauri_macros::default_runtime;
@@ -37,6 +43,7 @@ use tauri_runtime::window::{
   FileDropEvent,
 };
 use tauri_utils::PackageInfo;
+use url::Url;
 
 use std::{
   collections::HashMap,
@@ -54,6 +61,10 @@ use crate::updater;
 #[cfg(target_os = "macos")]
 use crate::ActivationPolicy;
 
+/// The event name used to emit clipboard changes to the frontend.
+#[cfg(clipboard_watch)]
+pub(crate) const CLIPBOARD_CHANGE_EVENT: &str = "tauri://clipboard-change";
+
 pub(crate) type GlobalMenuEventListener<R> = Box<dyn Fn(WindowMenuEvent<R>) + Send + Sync>;
 pub(crate) type GlobalWindowEventListener<R> = Box<dyn Fn(GlobalWindowEvent<R>) + Send + Sync>;
 #[cfg(all(desktop, feature = "system-tray"))]
@@ -125,6 +136,10 @@ pub enum WindowEvent {
   ///
   /// - **Linux**: Not supported.
   ThemeChanged(Theme),
+  /// The URL of the main frame of the webview changed.
+  ///
+  /// Emitted when a navigation is committed, so navigations blocked by the [`on_navigation`](`crate::window::WindowBuilder#method.on_navigation`) handler and iframe navigations do not trigger this event.
+  UrlChanged(Url),
 }
 
 impl From<RuntimeWindowEvent> for WindowEvent {
@@ -146,6 +161,7 @@ impl From<RuntimeWindowEvent> for WindowEvent {
       },
       RuntimeWindowEvent::FileDrop(event) => Self::FileDrop(event),
       RuntimeWindowEvent::ThemeChanged(theme) => Self::ThemeChanged(theme),
+      RuntimeWindowEvent::UrlChanged(url) => Self::UrlChanged(url),
     }
   }
 }
@@ -184,6 +200,50 @@ pub enum RunEvent {
   #[cfg(updater)]
   #[cfg_attr(doc_cfg, doc(cfg(feature = "updater")))]
   Updater(crate::UpdaterEvent),
+  /// An interaction with a notification shown with [`Notification::notify`](crate::api::notification::Notification::notify).
+  #[cfg(all(desktop, feature = "notification"))]
+  #[cfg_attr(doc_cfg, doc(cfg(feature = "notification")))]
+  Notification(crate::api::notification::NotificationEvent),
+  /// The clipboard content changed.
+  #[cfg(clipboard_watch)]
+  #[cfg_attr(doc_cfg, doc(cfg(feature = "clipboard-watch")))]
+  ClipboardChange {
+    /// The MIME types available in the clipboard.
+    formats: Vec<String>,
+  },
+  /// The app was launched again while [`Builder::single_instance`] is enabled.
+  ///
+  /// The new process forwards its arguments and exits. Use this event to focus the main window
+  /// or to handle the arguments, such as files to open.
+  #[cfg(all(desktop, feature = "single-instance"))]
+  #[cfg_attr(doc_cfg, doc(cfg(feature = "single-instance")))]
+  #[non_exhaustive]
+  SecondInstance {
+    /// The arguments of the new process, starting with the executable path.
+    args: Vec<String>,
+    /// The working directory of the new process.
+    cwd: std::path::PathBuf,
+    /// The CLI matches of the arguments, see [`crate::api::cli::get_matches_from`].
+    ///
+    /// `None` if the `cli` config is not defined or if the arguments don't match it.
+    #[cfg(cli)]
+    matches: Option<crate::api::cli::Matches>,
+  },
+  /// The app was opened with URLs using one of the schemes configured in `tauri > bundle > deepLinkProtocols`
+  /// or with files using one of the extensions configured in `tauri > bundle > fileAssociations`, as `file://` URLs.
+  ///
+  /// Emitted after [`RunEvent::Ready`] for the URLs and files the app was launched with,
+  /// and for the ones forwarded by other instances when [`Builder::single_instance`] is enabled.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **macOS**: Not supported yet, the URLs and files are delivered through Apple Events instead of the process arguments.
+  #[cfg(desktop)]
+  #[non_exhaustive]
+  Opened {
+    /// The opened URLs.
+    urls: Vec<url::Url>,
+  },
 }
 
 impl From<EventLoopMessage> for RunEvent {
@@ -191,6 +251,24 @@ impl From<EventLoopMessage> for RunEvent {
     match event {
       #[cfg(updater)]
       EventLoopMessage::Updater(event) => RunEvent::Updater(event),
+      #[cfg(all(desktop, feature = "notification"))]
+      EventLoopMessage::Notification(event) => RunEvent::Notification(event),
+      #[cfg(clipboard_watch)]
+      EventLoopMessage::ClipboardChange(formats) => RunEvent::ClipboardChange { formats },
+      #[cfg(all(desktop, feature = "single-instance"))]
+      EventLoopMessage::SecondInstance {
+        args,
+        cwd,
+        #[cfg(cli)]
+        matches,
+      } => RunEvent::SecondInstance {
+        args,
+        cwd,
+        #[cfg(cli)]
+        matches,
+      },
+      #[cfg(desktop)]
+      EventLoopMessage::Opened(urls) => RunEvent::Opened { urls },
     }
   }
 }
@@ -545,6 +623,12 @@ impl<R: Runtime> AppHandle<R> {
     {
       crate::api::process::kill_children();
     }
+    #[cfg(desktop)]
+    if let Some(store) = self.try_state::<WindowStateStore>() {
+      if let Err(e) = store.on_exit() {
+        log::error!("failed to save the window state: {}", e);
+      }
+    }
     #[cfg(all(windows, feature = "system-tray"))]
     {
       for tray in self.manager().trays().values() {
@@ -1040,6 +1124,10 @@ pub struct Builder<R: Runtime> {
 
   /// The device event filter.
   device_event_filter: DeviceEventFilter,
+
+  /// Whether only one instance of the app can run at a time.
+  #[cfg(all(desktop, feature = "single-instance"))]
+  single_instance: bool,
 }
 
 impl<R: Runtime> Builder<R> {
@@ -1069,6 +1157,8 @@ impl<R: Runtime> Builder<R> {
       #[cfg(updater)]
       updater_settings: Default::default(),
       device_event_filter: Default::default(),
+      #[cfg(all(desktop, feature = "single-instance"))]
+      single_instance: false,
     }
   }
 
@@ -1540,9 +1630,57 @@ impl<R: Runtime> Builder<R> {
     self
   }
 
+  /// Ensures only one instance of the app runs at a time.
+  ///
+  /// When the app is launched again, the new process forwards its arguments and working directory
+  /// to the running instance as a [`RunEvent::SecondInstance`] event.
+  /// [`Self::build`] then returns [`crate::Error::ForwardedToRunningInstance`] so the new process can exit,
+  /// and [`Self::run`] returns `Ok(())` without starting the app.
+  /// Instances are identified by the [`identifier`](https://tauri.app/v1/api/config#bundleconfig.identifier) config.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / macOS:** Uses a Unix domain socket in `$XDG_RUNTIME_DIR`, or in the user cache directory if it is not set.
+  /// - **Windows:** Uses a named pipe, so each session can run an instance.
+  ///
+  /// # Examples
+  /// ```,no_run
+  /// let app = match tauri::Builder::default()
+  ///   .single_instance()
+  ///   .build(tauri::generate_context!("test/fixture/src-tauri/tauri.conf.json"))
+  /// {
+  ///   Ok(app) => app,
+  ///   // the running instance handles the launch
+  ///   Err(tauri::Error::ForwardedToRunningInstance) => return,
+  ///   Err(e) => panic!("error while building tauri application: {}", e),
+  /// };
+  /// app.run(|app, event| {
+  ///   if let tauri::RunEvent::SecondInstance { args, .. } = event {
+  ///     println!("launched again with {:?}", args);
+  ///   }
+  /// });
+  /// ```
+  #[cfg(all(desktop, feature = "single-instance"))]
+  #[cfg_attr(doc_cfg, doc(cfg(feature = "single-instance")))]
+  #[must_use]
+  pub fn single_instance(mut self) -> Self {
+    self.single_instance = true;
+    self
+  }
+
   /// Builds the application.
   #[allow(clippy::type_complexity)]
   pub fn build<A: Assets>(mut self, context: Context<A>) -> crate::Result<App<R>> {
+    #[cfg(all(desktop, feature = "single-instance"))]
+    let single_instance_listener = if self.single_instance {
+      match single_instance::acquire(&context.config().tauri.bundle.identifier)? {
+        Some(listener) => Some(listener),
+        None => return Err(crate::Error::ForwardedToRunningInstance),
+      }
+    } else {
+      None
+    };
+
     #[cfg(target_os = "macos")]
     if self.menu.is_none() && self.enable_macos_default_menu {
       self.menu = Some(Menu::os_default(&context.package_info().name));
@@ -1563,8 +1701,22 @@ impl<R: Runtime> Builder<R> {
       (self.invoke_responder, self.invoke_initialization_script),
     );
 
+    #[cfg(desktop)]
+    let window_state = WindowStateStore::load(
+      crate::api::path::app_config_dir(&manager.config())
+        .map(|dir| dir.join(crate::window::state::WINDOW_STATE_FILE_NAME)),
+    );
+    #[cfg(desktop)]
+    let mut restored_windows = Vec::new();
+
     // set up all the windows defined in the config
     for config in manager.config().tauri.windows.clone() {
+      #[allow(unused_mut)]
+      let mut config = config;
+      #[cfg(desktop)]
+      if let Some(show) = window_state.prepare(&mut config) {
+        restored_windows.push((config.label.clone(), show));
+      }
       let url = config.url.clone();
       let label = config.label.clone();
 
@@ -1626,26 +1778,53 @@ impl<R: Runtime> Builder<R> {
     };
 
     let env = Env::default();
+    let fs_scope = FsScope::for_fs_api(
+      &app.manager.config(),
+      app.package_info(),
+      &env,
+      &app.config().tauri.allowlist.fs.scope,
+    )?;
+    #[cfg(protocol_asset)]
+    let asset_protocol_scope = FsScope::for_fs_api(
+      &app.manager.config(),
+      app.package_info(),
+      &env,
+      &app.config().tauri.allowlist.protocol.asset_scope,
+    )?;
+    // the webview can't read the secrets file through the fs APIs
+    #[cfg(all(desktop, secrets_any))]
+    if let Some(dir) = crate::api::path::app_local_data_dir(&app.manager.config()) {
+      for file in crate::api::secrets::fallback_files(&dir) {
+        fs_scope.forbid_file(&file)?;
+        #[cfg(protocol_asset)]
+        asset_protocol_scope.forbid_file(&file)?;
+      }
+    }
+    let grants = crate::scope::ScopeGrants::load(
+      crate::api::path::app_data_dir(&app.manager.config())
+        .map(|dir| dir.join(crate::scope::GRANTS_FILE_NAME)),
+      crate::api::path::app_local_data_dir(&app.manager.config())
+        .map(|dir| dir.join(crate::scope::GRANTS_KEY_FILE_NAME)),
+      fs_scope.clone(),
+      #[cfg(protocol_asset)]
+      Some(asset_protocol_scope.clone()),
+      #[cfg(not(protocol_asset))]
+      None,
+      app.config().tauri.allowlist.fs.persist_scope,
+    )?;
     app.manage(Scopes {
-      fs: FsScope::for_fs_api(
-        &app.manager.config(),
-        app.package_info(),
-        &env,
-        &app.config().tauri.allowlist.fs.scope,
-      )?,
+      fs: fs_scope,
       #[cfg(protocol_asset)]
-      asset_protocol: FsScope::for_fs_api(
-        &app.manager.config(),
-        app.package_info(),
-        &env,
-        &app.config().tauri.allowlist.protocol.asset_scope,
-      )?,
+      asset_protocol: asset_protocol_scope,
       #[cfg(http_request)]
       http: crate::scope::HttpScope::for_http_api(&app.config().tauri.allowlist.http.scope),
       #[cfg(shell_scope)]
       shell: ShellScope::new(&app.manager.config(), app.package_info(), &env, shell_scope),
+      grants,
     });
     app.manage(env);
+    #[cfg(feature = "store")]
+    app.manage(crate::store::Store::for_app(&app.handle()));
 
     #[cfg(windows)]
     {
@@ -1674,7 +1853,32 @@ impl<R: Runtime> Builder<R> {
     #[cfg(all(desktop, feature = "system-tray"))]
     {
       if let Some(tray) = self.system_tray {
+        let id = tray.id.clone();
         tray.build(&app)?;
+        app.manager.set_default_tray(id);
+      }
+
+      // forward the tray menu clicks to the frontend when the menu API is allowlisted
+      #[cfg(menu_any)]
+      {
+        let app_handle = app.handle();
+        app
+          .runtime
+          .as_mut()
+          .unwrap()
+          .on_system_tray_event(move |tray_id, event| {
+            if let tray::RuntimeSystemTrayEvent::MenuItemClick(menu_id) = event {
+              if let Some((tray_id, tray)) = app_handle.manager().get_tray_by_runtime_id(tray_id) {
+                let id = tray.ids.lock().unwrap().get(menu_id).cloned();
+                if let Some(id) = id {
+                  let _ = app_handle.emit_all(
+                    tray::TRAY_MENU_EVENT,
+                    tray::TrayMenuEventPayload { tray_id, id },
+                  );
+                }
+              }
+            }
+          });
       }
 
       for listener in self.system_tray_event_listeners {
@@ -1695,6 +1899,64 @@ impl<R: Runtime> Builder<R> {
       }
     }
 
+    // forward the clipboard changes to the frontend and the event loop
+    #[cfg(clipboard_watch)]
+    {
+      use crate::runtime::{ClipboardManager, EventLoopProxy};
+      let app_handle = app.handle();
+      app.clipboard_manager().watch(move |formats| {
+        let _ = app_handle.emit_all(CLIPBOARD_CHANGE_EVENT, formats.clone());
+        let _ = app_handle
+          .create_proxy()
+          .send_event(EventLoopMessage::ClipboardChange(formats));
+      })?;
+    }
+
+    // deliver the deep links and files the app was launched with
+    #[cfg(desktop)]
+    {
+      use crate::runtime::EventLoopProxy;
+      let urls = opened::opened_urls(
+        &app.config().tauri.bundle,
+        &std::env::current_dir().unwrap_or_default(),
+        std::env::args_os().map(|arg| arg.to_string_lossy().into_owned()),
+      );
+      if !urls.is_empty() {
+        app
+          .handle()
+          .create_proxy()
+          .send_event(EventLoopMessage::Opened(urls))?;
+      }
+    }
+
+    // forward the launches of other instances to the event loop
+    #[cfg(all(desktop, feature = "single-instance"))]
+    if let Some(listener) = single_instance_listener {
+      use crate::runtime::EventLoopProxy;
+      let app_handle = app.handle();
+      listener.listen(move |launch| {
+        let urls =
+          opened::opened_urls(&app_handle.config().tauri.bundle, &launch.cwd, &launch.args);
+        #[cfg(cli)]
+        let matches = app_handle.config().tauri.cli.as_ref().and_then(|cli| {
+          crate::api::cli::get_matches_from(cli, app_handle.package_info(), &launch.args).ok()
+        });
+        let _ = app_handle
+          .create_proxy()
+          .send_event(EventLoopMessage::SecondInstance {
+            args: launch.args,
+            cwd: launch.cwd,
+            #[cfg(cli)]
+            matches,
+          });
+        if !urls.is_empty() {
+          let _ = app_handle
+            .create_proxy()
+            .send_event(EventLoopMessage::Opened(urls));
+        }
+      });
+    }
+
     app.manager.initialize_plugins(&app.handle())?;
 
     let window_labels = self
@@ -1712,6 +1974,18 @@ impl<R: Runtime> Builder<R> {
       let _window = app.manager.attach_window(app.handle(), detached);
     }
 
+    #[cfg(desktop)]
+    {
+      for (label, show) in restored_windows {
+        if let Some(window) = app.get_window(&label) {
+          if let Err(e) = window_state.restore(&window, show) {
+            log::error!("failed to restore the state of window `{}`: {}", label, e);
+          }
+        }
+      }
+      app.manage(window_state);
+    }
+
     (self.setup)(&mut app).map_err(|e| crate::Error::Setup(e.into()))?;
 
     #[cfg(updater)]
@@ -1722,7 +1996,14 @@ impl<R: Runtime> Builder<R> {
 
   /// Runs the configured Tauri application.
   pub fn run<A: Assets>(self, context: Context<A>) -> crate::Result<()> {
-    self.build(context)?.run(|_, _| {});
+    let app = match self.build(context) {
+      Ok(app) => app,
+      // the running instance handles the launch
+      #[cfg(all(desktop, feature = "single-instance"))]
+      Err(crate::Error::ForwardedToRunningInstance) => return Ok(()),
+      Err(e) => return Err(e),
+    };
+    app.run(|_, _| {});
     Ok(())
   }
 }
@@ -1753,6 +2034,26 @@ fn on_event_loop_event<R: Runtime, F: FnMut(&AppHandle<R>, RunEvent) + 'static>(
     manager.on_window_close(label);
   }
 
+  // track the state of the windows restored from the app config directory
+  #[cfg(desktop)]
+  if let RuntimeRunEvent::WindowEvent { label, event } = &event {
+    if let Some(store) = app_handle.try_state::<WindowStateStore>() {
+      let result = match event {
+        RuntimeWindowEvent::Resized(_)
+        | RuntimeWindowEvent::Moved(_)
+        | RuntimeWindowEvent::CloseRequested { .. } => app_handle
+          .get_window(label)
+          .map(|window| store.update(&window))
+          .unwrap_or(Ok(())),
+        RuntimeWindowEvent::Destroyed => store.on_window_close(label),
+        _ => Ok(()),
+      };
+      if let Err(e) = result {
+        log::error!("failed to save the state of window `{}`: {}", label, e);
+      }
+    }
+  }
+
   let event = match event {
     RuntimeRunEvent::Exit => RunEvent::Exit,
     RuntimeRunEvent::ExitRequested { tx } => RunEvent::ExitRequested {
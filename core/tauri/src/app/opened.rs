@@ -0,0 +1,157 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use url::Url;
+
+use crate::utils::config::BundleConfig;
+
+/// Extracts the URLs opened by the app from the launch arguments.
+///
+/// Those are the URLs with one of the configured deep link schemes,
+/// and the files with one of the associated extensions, as `file://` URLs.
+/// Relative file paths are resolved against `cwd`.
+///
+/// The first argument is skipped since it is the executable path.
+pub(crate) fn opened_urls<I, T>(config: &BundleConfig, cwd: &Path, args: I) -> Vec<Url>
+where
+  I: IntoIterator<Item = T>,
+  T: AsRef<str>,
+{
+  let schemes = config
+    .deep_link_protocols
+    .iter()
+    .flatten()
+    .flat_map(|protocol| &protocol.schemes)
+    .collect::<Vec<_>>();
+  let extensions = config
+    .file_associations
+    .iter()
+    .flatten()
+    .flat_map(|association| &association.ext)
+    .collect::<Vec<_>>();
+  let is_associated = |path: &Path| {
+    path
+      .extension()
+      .and_then(|ext| ext.to_str())
+      .map(|ext| extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)))
+      .unwrap_or(false)
+  };
+
+  args
+    .into_iter()
+    .skip(1)
+    .filter_map(|arg| {
+      let arg = arg.as_ref();
+      if let Ok(url) = Url::parse(arg) {
+        if schemes.iter().any(|s| s.eq_ignore_ascii_case(url.scheme())) {
+          return Some(url);
+        }
+        // the Linux desktop entries may pass the files as URLs
+        if url.scheme() == "file" {
+          return url
+            .to_file_path()
+            .ok()
+            .filter(|path| is_associated(path))
+            .map(|_| url);
+        }
+      }
+      let path = cwd.join(arg);
+      if is_associated(&path) {
+        Url::from_file_path(path).ok()
+      } else {
+        None
+      }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utils::config::{DeepLinkProtocol, FileAssociation};
+
+  fn config() -> BundleConfig {
+    BundleConfig {
+      deep_link_protocols: Some(vec![DeepLinkProtocol {
+        schemes: vec!["myapp".into(), "MyApp-Dev".into()],
+        name: None,
+      }]),
+      file_associations: Some(vec![FileAssociation {
+        ext: vec!["ourproj".into()],
+        mime_type: None,
+        description: None,
+        icon: None,
+        role: Default::default(),
+      }]),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn filters_configured_schemes() {
+    let urls = opened_urls(
+      &config(),
+      Path::new("/"),
+      [
+        "myapp://auth?code=1",
+        "myapp://not-the-executable",
+        "--flag",
+        "https://tauri.app",
+        "myapp-dev://open/file",
+        "C:\\Users\\file.txt",
+      ],
+    );
+    assert_eq!(
+      urls,
+      vec![
+        Url::parse("myapp://not-the-executable").unwrap(),
+        Url::parse("myapp-dev://open/file").unwrap(),
+      ]
+    );
+  }
+
+  #[test]
+  #[cfg(unix)]
+  fn resolves_associated_files() {
+    let urls = opened_urls(
+      &config(),
+      Path::new("/home/user"),
+      [
+        "app",
+        "docs/a.ourproj",
+        "/tmp/B.OURPROJ",
+        "file:///tmp/c.ourproj",
+        "file:///tmp/d.txt",
+        "e.txt",
+      ],
+    );
+    assert_eq!(
+      urls,
+      vec![
+        Url::parse("file:///home/user/docs/a.ourproj").unwrap(),
+        Url::parse("file:///tmp/B.OURPROJ").unwrap(),
+        Url::parse("file:///tmp/c.ourproj").unwrap(),
+      ]
+    );
+  }
+
+  #[test]
+  #[cfg(windows)]
+  fn resolves_associated_drive_paths() {
+    let urls = opened_urls(
+      &config(),
+      Path::new("C:\\Users\\user"),
+      ["app", "docs\\a.ourproj", "D:\\B.OURPROJ", "C:\\c.txt"],
+    );
+    assert_eq!(
+      urls,
+      vec![
+        Url::parse("file:///C:/Users/user/docs/a.ourproj").unwrap(),
+        Url::parse("file:///D:/B.OURPROJ").unwrap(),
+      ]
+    );
+  }
+}
@@ -0,0 +1,315 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+  io::Read,
+  path::PathBuf,
+  sync::{Arc, Mutex},
+  thread::spawn,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// The arguments and working directory of a launch of the app.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Launch {
+  pub(crate) args: Vec<String>,
+  pub(crate) cwd: PathBuf,
+}
+
+impl Launch {
+  fn current() -> Self {
+    Self {
+      args: std::env::args_os()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect(),
+      cwd: std::env::current_dir().unwrap_or_default(),
+    }
+  }
+}
+
+/// The maximum size of a forwarded launch, in bytes.
+const MAX_LAUNCH_SIZE: u64 = 1024 * 1024;
+
+/// Receives the launches forwarded by the other instances of the app.
+pub(crate) struct Listener(imp::Listener);
+
+impl Listener {
+  /// Calls `handler` on a background thread for each forwarded launch.
+  ///
+  /// Each connection is read on its own thread, so a client that doesn't close it can't block the others.
+  pub(crate) fn listen<F: Fn(Launch) + Send + 'static>(mut self, handler: F) {
+    let handler = Arc::new(Mutex::new(handler));
+    spawn(move || loop {
+      let stream = match self.0.accept() {
+        Ok(stream) => stream,
+        Err(_) => break,
+      };
+      let handler = handler.clone();
+      spawn(move || {
+        let mut buf = Vec::new();
+        // a launch larger than the limit is truncated, so it fails to deserialize
+        if stream
+          .take(MAX_LAUNCH_SIZE + 1)
+          .read_to_end(&mut buf)
+          .is_ok()
+        {
+          if let Ok(launch) = serde_json::from_slice(&buf) {
+            handler.lock().unwrap()(launch);
+          }
+        }
+      });
+    });
+  }
+}
+
+/// Makes this process the primary instance of the app with the given identifier.
+///
+/// Returns `None` if another instance is already running, after forwarding the current launch to it.
+pub(crate) fn acquire(identifier: &str) -> crate::Result<Option<Listener>> {
+  let launch = serde_json::to_vec(&Launch::current()).map_err(crate::Error::Json)?;
+  Ok(imp::acquire(identifier, &launch)?.map(Listener))
+}
+
+#[cfg(unix)]
+mod imp {
+  use std::{
+    io::{self, Write},
+    os::unix::net::{UnixListener, UnixStream},
+    path::PathBuf,
+  };
+
+  pub struct Listener(UnixListener);
+
+  impl Listener {
+    pub fn accept(&mut self) -> io::Result<UnixStream> {
+      self.0.accept().map(|(stream, _)| stream)
+    }
+  }
+
+  pub(super) fn socket_path(identifier: &str) -> PathBuf {
+    // prefer a directory private to the user, so each user can run an instance
+    std::env::var_os("XDG_RUNTIME_DIR")
+      .map(PathBuf::from)
+      .filter(|dir| dir.is_dir())
+      .or_else(dirs_next::cache_dir)
+      .unwrap_or_else(std::env::temp_dir)
+      .join(format!("{identifier}.sock"))
+  }
+
+  pub fn acquire(identifier: &str, launch: &[u8]) -> io::Result<Option<Listener>> {
+    let path = socket_path(identifier);
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+
+    match UnixListener::bind(&path) {
+      Ok(listener) => return Ok(Some(Listener(listener))),
+      Err(e) if e.kind() == io::ErrorKind::AddrInUse => {}
+      Err(e) => return Err(e),
+    }
+
+    match UnixStream::connect(&path) {
+      Ok(mut stream) => {
+        stream.write_all(launch)?;
+        Ok(None)
+      }
+      // the socket was left behind by an instance that didn't exit cleanly
+      Err(e) if e.kind() == io::ErrorKind::ConnectionRefused => {
+        std::fs::remove_file(&path)?;
+        UnixListener::bind(&path).map(|listener| Some(Listener(listener)))
+      }
+      // the running instance exited and removed its socket in the meantime
+      Err(e) if e.kind() == io::ErrorKind::NotFound => {
+        UnixListener::bind(&path).map(|listener| Some(Listener(listener)))
+      }
+      Err(e) => Err(e),
+    }
+  }
+}
+
+#[cfg(windows)]
+mod imp {
+  use std::{
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    os::windows::io::FromRawHandle,
+    thread::sleep,
+    time::Duration,
+  };
+
+  use windows::{
+    core::PCWSTR,
+    Win32::{
+      Foundation::{
+        ERROR_ACCESS_DENIED, ERROR_FILE_NOT_FOUND, ERROR_PIPE_BUSY, ERROR_PIPE_CONNECTED, HANDLE,
+        INVALID_HANDLE_VALUE,
+      },
+      Storage::FileSystem::{FILE_FLAG_FIRST_PIPE_INSTANCE, PIPE_ACCESS_INBOUND},
+      System::{
+        Pipes::{
+          ConnectNamedPipe, CreateNamedPipeW, PIPE_READMODE_BYTE, PIPE_TYPE_BYTE,
+          PIPE_UNLIMITED_INSTANCES, PIPE_WAIT,
+        },
+        RemoteDesktop::ProcessIdToSessionId,
+      },
+    },
+  };
+
+  pub struct Listener {
+    name: Vec<u16>,
+    next: Option<HANDLE>,
+  }
+
+  impl Listener {
+    pub fn accept(&mut self) -> io::Result<File> {
+      let handle = match self.next.take() {
+        Some(handle) => handle,
+        None => create_pipe(&self.name, false)?,
+      };
+      let connected = unsafe { ConnectNamedPipe(handle, std::ptr::null_mut()) }.as_bool();
+      let error = io::Error::last_os_error();
+      let client = unsafe { File::from_raw_handle(handle.0 as _) };
+      // the client may connect between the pipe creation and `ConnectNamedPipe`
+      if !connected && !is_error(&error, ERROR_PIPE_CONNECTED.0) {
+        return Err(error);
+      }
+      // other instances can only connect while a pipe instance is waiting,
+      // so the next one is created before this client is read
+      self.next.replace(create_pipe(&self.name, false)?);
+      Ok(client)
+    }
+  }
+
+  fn is_error(e: &io::Error, code: u32) -> bool {
+    e.raw_os_error() == Some(code as i32)
+  }
+
+  fn create_pipe(name: &[u16], first: bool) -> io::Result<HANDLE> {
+    let mut mode = PIPE_ACCESS_INBOUND;
+    if first {
+      mode |= FILE_FLAG_FIRST_PIPE_INSTANCE;
+    }
+    let handle = unsafe {
+      CreateNamedPipeW(
+        PCWSTR(name.as_ptr()),
+        mode,
+        PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT,
+        PIPE_UNLIMITED_INSTANCES,
+        0,
+        4096,
+        0,
+        std::ptr::null(),
+      )
+    };
+    if handle == INVALID_HANDLE_VALUE {
+      Err(io::Error::last_os_error())
+    } else {
+      Ok(handle)
+    }
+  }
+
+  pub fn acquire(identifier: &str, launch: &[u8]) -> io::Result<Option<Listener>> {
+    // pipe names are shared by all the sessions, so each session can run an instance
+    let mut session = 0;
+    if !unsafe { ProcessIdToSessionId(std::process::id(), &mut session) }.as_bool() {
+      return Err(io::Error::last_os_error());
+    }
+    let path = format!(r"\\.\pipe\{identifier}-single-instance-{session}");
+    let name = path
+      .encode_utf16()
+      .chain(std::iter::once(0))
+      .collect::<Vec<_>>();
+
+    for _ in 0..20 {
+      match create_pipe(&name, true) {
+        Ok(handle) => {
+          return Ok(Some(Listener {
+            name,
+            next: Some(handle),
+          }))
+        }
+        // the pipe is owned by another instance
+        Err(e) if is_error(&e, ERROR_ACCESS_DENIED.0) => {}
+        Err(e) => return Err(e),
+      }
+
+      match OpenOptions::new().write(true).open(&path) {
+        Ok(mut pipe) => {
+          pipe.write_all(launch)?;
+          return Ok(None);
+        }
+        // the running instance is connected to other clients, or it is exiting,
+        // in which case this process becomes the running instance on the next attempt
+        Err(e) if is_error(&e, ERROR_PIPE_BUSY.0) || is_error(&e, ERROR_FILE_NOT_FOUND.0) => {
+          sleep(Duration::from_millis(50));
+        }
+        Err(e) => return Err(e),
+      }
+    }
+
+    Err(io::Error::new(
+      io::ErrorKind::TimedOut,
+      "the running instance of the app did not accept the launch",
+    ))
+  }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+  use super::{acquire, imp::socket_path};
+  use std::{
+    os::unix::net::{UnixListener, UnixStream},
+    sync::mpsc::channel,
+    time::Duration,
+  };
+
+  #[test]
+  fn forwards_launch_to_running_instance() {
+    let identifier = format!("app.tauri.single-instance-forward-{}", std::process::id());
+    let listener = acquire(&identifier).unwrap().unwrap();
+    let (tx, rx) = channel();
+    listener.listen(move |launch| tx.send(launch).unwrap());
+
+    assert!(acquire(&identifier).unwrap().is_none());
+    let launch = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(
+      launch.args,
+      std::env::args_os()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+    );
+    assert_eq!(launch.cwd, std::env::current_dir().unwrap());
+
+    std::fs::remove_file(socket_path(&identifier)).unwrap();
+  }
+
+  #[test]
+  fn open_connection_does_not_block_launches() {
+    let identifier = format!("app.tauri.single-instance-open-{}", std::process::id());
+    let listener = acquire(&identifier).unwrap().unwrap();
+    let (tx, rx) = channel();
+    listener.listen(move |launch| tx.send(launch).unwrap());
+
+    // a client that never closes its connection
+    let _stream = UnixStream::connect(socket_path(&identifier)).unwrap();
+    assert!(acquire(&identifier).unwrap().is_none());
+    assert!(rx.recv_timeout(Duration::from_secs(5)).is_ok());
+
+    std::fs::remove_file(socket_path(&identifier)).unwrap();
+  }
+
+  #[test]
+  fn replaces_stale_socket() {
+    let identifier = format!("app.tauri.single-instance-stale-{}", std::process::id());
+    let path = socket_path(&identifier);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    // the socket file outlives its listener, like after a crash
+    drop(UnixListener::bind(&path).unwrap());
+
+    assert!(acquire(&identifier).unwrap().is_some());
+
+    std::fs::remove_file(path).unwrap();
+  }
+}
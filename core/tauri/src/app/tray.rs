@@ -5,7 +5,8 @@
 pub use crate::{
   runtime::{
     menu::{
-      MenuHash, MenuId, MenuIdRef, MenuUpdate, SystemTrayMenu, SystemTrayMenuEntry, TrayHandle,
+      MenuEntryRef, MenuHash, MenuId, MenuIdRef, MenuPosition, MenuStructureUpdate, MenuUpdate,
+      SystemTrayMenu, SystemTrayMenuEntry, TrayHandle,
     },
     window::dpi::{PhysicalPosition, PhysicalSize},
     RuntimeHandle, SystemTrayEvent as RuntimeSystemTrayEvent,
@@ -17,7 +18,6 @@ use crate::{sealed::RuntimeOrDispatch, Manager};
 use rand::distributions::{Alphanumeric, DistString};
 use tauri_macros::default_runtime;
 use tauri_runtime::TrayId;
-use tauri_utils::debug_eprintln;
 
 use std::{
   collections::{hash_map::DefaultHasher, HashMap},
@@ -28,6 +28,18 @@ use std::{
 
 type TrayEventHandler = dyn Fn(SystemTrayEvent) + Send + Sync + 'static;
 
+/// The event emitted to the frontend when a tray menu item is clicked.
+#[cfg(menu_any)]
+pub(crate) const TRAY_MENU_EVENT: &str = "tauri://tray-menu";
+
+#[cfg(menu_any)]
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TrayMenuEventPayload {
+  pub(crate) tray_id: String,
+  pub(crate) id: MenuId,
+}
+
 pub(crate) fn get_menu_ids(map: &mut HashMap<MenuHash, MenuId>, menu: &SystemTrayMenu) {
   for item in &menu.items {
     match item {
@@ -173,7 +185,7 @@ impl SystemTray {
         self.icon.replace(icon);
       }
       Err(e) => {
-        debug_eprintln!("Failed to load tray icon: {}", e);
+        log::warn!("Failed to load tray icon: {}", e);
       }
     }
     self
@@ -596,18 +608,27 @@ impl<R: Runtime> Clone for SystemTrayMenuItemHandle<R> {
 
 impl<R: Runtime> SystemTrayHandle<R> {
   /// Gets a handle to the menu item that has the specified `id`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the tray menu has no item with the given `id`. See [`Self::try_get_item`].
   pub fn get_item(&self, id: MenuIdRef<'_>) -> SystemTrayMenuItemHandle<R> {
+    self.try_get_item(id).expect("item id not found")
+  }
+
+  /// Gets a handle to the menu item that has the specified `id`, if it exists.
+  pub fn try_get_item(&self, id: MenuIdRef<'_>) -> Option<SystemTrayMenuItemHandle<R>> {
     let ids = self.ids.lock().unwrap();
     let iter = ids.iter();
     for (raw, item_id) in iter {
       if item_id == id {
-        return SystemTrayMenuItemHandle {
+        return Some(SystemTrayMenuItemHandle {
           id: *raw,
           tray_handler: self.inner.clone(),
-        };
+        });
       }
     }
-    panic!("item id not found")
+    None
   }
 
   /// Updates the tray icon.
@@ -624,6 +645,62 @@ impl<R: Runtime> SystemTrayHandle<R> {
     Ok(())
   }
 
+  /// Inserts a menu entry at the given position.
+  ///
+  /// The ids of the inserted items can be used with [`Self::get_item`] right away.
+  /// Returns an error if the entry referenced by `position` does not exist.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / Windows / macOS**: The whole menu is rebuilt and set again, which can make it flicker.
+  pub fn insert(&self, entry: SystemTrayMenuEntry, position: MenuPosition) -> crate::Result<()> {
+    let mut ids = HashMap::new();
+    match &entry {
+      SystemTrayMenuEntry::CustomItem(c) => {
+        ids.insert(c.id, c.id_str.clone());
+      }
+      SystemTrayMenuEntry::Submenu(s) => get_menu_ids(&mut ids, &s.inner),
+      _ => {}
+    }
+    self
+      .inner
+      .update_menu_structure(MenuStructureUpdate::Insert(entry, position))?;
+    self.ids.lock().unwrap().extend(ids);
+    Ok(())
+  }
+
+  /// Moves an existing menu entry to the given position, preserving its id and state.
+  ///
+  /// Returns an error if `entry` or the entry referenced by `position` does not exist.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / Windows / macOS**: The whole menu is rebuilt and set again, which can make it flicker.
+  pub fn move_entry(&self, entry: MenuEntryRef, position: MenuPosition) -> crate::Result<()> {
+    self
+      .inner
+      .update_menu_structure(MenuStructureUpdate::Move(entry, position))?;
+    Ok(())
+  }
+
+  /// Removes an existing menu entry.
+  ///
+  /// Removing a submenu also removes the items nested in it.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / Windows / macOS**: The whole menu is rebuilt and set again, which can make it flicker.
+  pub fn remove(&self, entry: MenuEntryRef) -> crate::Result<()> {
+    let removed = self
+      .inner
+      .update_menu_structure(MenuStructureUpdate::Remove(entry))?;
+    let mut ids = self.ids.lock().unwrap();
+    for id in removed {
+      ids.remove(&id);
+    }
+    Ok(())
+  }
+
   /// Support [macOS tray icon template](https://developer.apple.com/documentation/appkit/nsimage/1520017-template?language=objc) to adjust automatically based on taskbar color.
   #[cfg(target_os = "macos")]
   pub fn set_icon_as_template(&self, is_template: bool) -> crate::Result<()> {
@@ -13,6 +13,8 @@ use serde_json::Value as JsonValue;
 use std::sync::Arc;
 
 mod app;
+#[cfg(archive_any)]
+mod archive;
 #[cfg(cli)]
 mod cli;
 #[cfg(clipboard_any)]
@@ -26,6 +28,10 @@ mod file_system;
 mod global_shortcut;
 #[cfg(http_any)]
 mod http;
+#[cfg(log_any)]
+mod logging;
+#[cfg(menu_any)]
+mod menu;
 mod notification;
 #[cfg(os_any)]
 mod operating_system;
@@ -33,8 +39,12 @@ mod operating_system;
 mod path;
 #[cfg(process_any)]
 mod process;
+#[cfg(all(desktop, secrets_any))]
+mod secrets;
 #[cfg(shell_any)]
 mod shell;
+#[cfg(store_any)]
+mod store;
 mod window;
 
 /// The context passed to the invoke handler.
@@ -89,12 +99,22 @@ enum Module {
   #[cfg(cli)]
   Cli(cli::Cmd),
   Notification(notification::Cmd),
+  #[cfg(log_any)]
+  Log(logging::Cmd),
   #[cfg(http_any)]
   Http(http::Cmd),
   #[cfg(global_shortcut_any)]
   GlobalShortcut(global_shortcut::Cmd),
   #[cfg(clipboard_any)]
   Clipboard(clipboard::Cmd),
+  #[cfg(archive_any)]
+  Archive(archive::Cmd),
+  #[cfg(menu_any)]
+  Menu(menu::Cmd),
+  #[cfg(all(desktop, secrets_any))]
+  Secrets(secrets::Cmd),
+  #[cfg(store_any)]
+  Store(store::Cmd),
 }
 
 impl Module {
@@ -185,6 +205,13 @@ impl Module {
           .and_then(|r| r.json)
           .map_err(InvokeError::from_anyhow)
       }),
+      #[cfg(log_any)]
+      Self::Log(cmd) => resolver.respond_async(async move {
+        cmd
+          .run(context)
+          .and_then(|r| r.json)
+          .map_err(InvokeError::from_anyhow)
+      }),
       #[cfg(http_any)]
       Self::Http(cmd) => resolver.respond_async(async move {
         cmd
@@ -207,6 +234,34 @@ impl Module {
           .and_then(|r| r.json)
           .map_err(InvokeError::from_anyhow)
       }),
+      #[cfg(archive_any)]
+      Self::Archive(cmd) => resolver.respond_async(async move {
+        cmd
+          .run(context)
+          .and_then(|r| r.json)
+          .map_err(InvokeError::from_anyhow)
+      }),
+      #[cfg(menu_any)]
+      Self::Menu(cmd) => resolver.respond_async(async move {
+        cmd
+          .run(context)
+          .and_then(|r| r.json)
+          .map_err(InvokeError::from_anyhow)
+      }),
+      #[cfg(all(desktop, secrets_any))]
+      Self::Secrets(cmd) => resolver.respond_async(async move {
+        cmd
+          .run(context)
+          .and_then(|r| r.json)
+          .map_err(InvokeError::from_anyhow)
+      }),
+      #[cfg(store_any)]
+      Self::Store(cmd) => resolver.respond_async(async move {
+        cmd
+          .run(context)
+          .and_then(|r| r.json)
+          .map_err(InvokeError::from_anyhow)
+      }),
     }
   }
 }
@@ -232,6 +287,7 @@ pub(crate) fn handle<R: Runtime>(
     Ok(module) => module.run(window, resolver, config, package_info.clone()),
     Err(e) => {
       let message = e.to_string();
+      log::warn!("failed to parse the `{}` IPC message: {}", module, message);
       if message.starts_with("unknown variant") {
         let mut s = message.split('`');
         s.next();
@@ -0,0 +1,354 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#![allow(unused_imports)]
+
+use super::InvokeContext;
+use crate::{
+  api::{
+    file::SafePathBuf,
+    ipc::{format_callback, CallbackFn},
+    path::BaseDirectory,
+  },
+  scope::Scopes,
+  Config, Env, Manager, PackageInfo, Runtime, Window,
+};
+#[allow(unused_imports)]
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use tauri_macros::{command_enum, module_command_handler, CommandModule};
+
+use std::{
+  fs::{self, File},
+  path::{Path, PathBuf},
+};
+
+/// The default maximum amount of bytes an archive is allowed to be extracted into (1 GiB).
+#[cfg(archive_extract)]
+const DEFAULT_MAX_EXTRACTED_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// The archive formats supported by the API.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Format {
+  /// Zip archive.
+  Zip,
+  /// Uncompressed tar archive.
+  Tar,
+  /// Gz-compressed tar archive.
+  TarGz,
+}
+
+impl Format {
+  /// Infers the archive format from the file extension.
+  #[cfg(any(archive_extract, archive_create))]
+  fn from_path(path: &Path) -> Option<Self> {
+    let file_name = path.file_name()?.to_string_lossy().to_lowercase();
+    if file_name.ends_with(".zip") {
+      Some(Self::Zip)
+    } else if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+      Some(Self::TarGz)
+    } else if file_name.ends_with(".tar") {
+      Some(Self::Tar)
+    } else {
+      None
+    }
+  }
+}
+
+#[cfg(any(archive_extract, archive_create))]
+impl From<Format> for crate::api::file::ArchiveFormat {
+  fn from(format: Format) -> Self {
+    use crate::api::file::{ArchiveFormat, Compression};
+    match format {
+      Format::Zip => ArchiveFormat::Zip,
+      Format::Tar => ArchiveFormat::Tar(None),
+      Format::TarGz => ArchiveFormat::Tar(Some(Compression::Gz)),
+    }
+  }
+}
+
+/// The options for the extract API.
+#[cfg_attr(not(archive_extract), allow(dead_code))]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtractOptions {
+  /// The archive format. Inferred from the archive extension if not provided.
+  format: Option<Format>,
+  /// The base directory of the archive and destination paths.
+  dir: Option<BaseDirectory>,
+  /// The maximum amount of bytes the archive is allowed to be extracted into.
+  max_size: Option<u64>,
+}
+
+/// The options for the create API.
+#[cfg_attr(not(archive_create), allow(dead_code))]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOptions {
+  /// The archive format. Inferred from the destination extension if not provided.
+  format: Option<Format>,
+  /// The base directory of the destination and source paths.
+  dir: Option<BaseDirectory>,
+}
+
+/// The payload sent to the progress callback.
+#[cfg(any(archive_extract, archive_create))]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Progress<'a> {
+  /// The path that is being processed.
+  path: &'a Path,
+  /// The amount of bytes processed so far.
+  bytes: u64,
+}
+
+/// The API descriptor.
+#[command_enum]
+#[derive(Deserialize, CommandModule)]
+#[serde(tag = "cmd", rename_all = "camelCase")]
+pub(crate) enum Cmd {
+  /// The extract API.
+  #[cmd(archive_extract, "archive > extract")]
+  #[serde(rename_all = "camelCase")]
+  Extract {
+    archive: SafePathBuf,
+    destination: SafePathBuf,
+    on_progress_fn: Option<CallbackFn>,
+    #[serde(default)]
+    options: ExtractOptions,
+  },
+  /// The create API.
+  #[cmd(archive_create, "archive > create")]
+  #[serde(rename_all = "camelCase")]
+  Create {
+    destination: SafePathBuf,
+    paths: Vec<SafePathBuf>,
+    on_progress_fn: Option<CallbackFn>,
+    #[serde(default)]
+    options: CreateOptions,
+  },
+}
+
+impl Cmd {
+  #[module_command_handler(archive_extract)]
+  fn extract<R: Runtime>(
+    context: InvokeContext<R>,
+    archive: SafePathBuf,
+    destination: SafePathBuf,
+    on_progress_fn: Option<CallbackFn>,
+    options: ExtractOptions,
+  ) -> super::Result<()> {
+    let archive = resolve_path(
+      &context.config,
+      &context.package_info,
+      &context.window,
+      archive,
+      options.dir,
+    )?;
+    let destination = resolve_path(
+      &context.config,
+      &context.package_info,
+      &context.window,
+      destination,
+      options.dir,
+    )?;
+    let format = options
+      .format
+      .or_else(|| Format::from_path(archive.as_ref()))
+      .ok_or_else(|| anyhow::anyhow!("unknown archive format: {}", archive.display()))?;
+
+    let file = File::open(&archive).with_context(|| format!("path: {}", archive.display()))?;
+    let scopes = context.window.state::<Scopes>();
+    crate::api::file::Extract::from_cursor(file, format.into())
+      .max_size(options.max_size.unwrap_or(DEFAULT_MAX_EXTRACTED_SIZE))
+      .extract_into_with(destination.as_ref(), |path, bytes| {
+        // the destination is allowed, but its subdirectories might not be
+        if !scopes.fs.is_allowed(path) {
+          log::warn!("path not allowed by the fs scope: {}", path.display());
+          return Err(crate::api::Error::PathNotAllowed(path.to_path_buf()));
+        }
+        if let Some(on_progress_fn) = on_progress_fn {
+          let js = format_callback(on_progress_fn, &Progress { path, bytes })?;
+          let _ = context.window.eval(&js);
+        }
+        Ok(())
+      })
+      .with_context(|| {
+        format!(
+          "archive: {}, destination: {}",
+          archive.display(),
+          destination.display()
+        )
+      })
+      .map_err(Into::into)
+  }
+
+  #[module_command_handler(archive_create)]
+  fn create<R: Runtime>(
+    context: InvokeContext<R>,
+    destination: SafePathBuf,
+    paths: Vec<SafePathBuf>,
+    on_progress_fn: Option<CallbackFn>,
+    options: CreateOptions,
+  ) -> super::Result<()> {
+    let destination = resolve_path(
+      &context.config,
+      &context.package_info,
+      &context.window,
+      destination,
+      options.dir,
+    )?;
+    let format = options
+      .format
+      .or_else(|| Format::from_path(destination.as_ref()))
+      .ok_or_else(|| anyhow::anyhow!("unknown archive format: {}", destination.display()))?;
+
+    let mut compress = crate::api::file::Compress::new(format.into());
+    for path in paths {
+      let path = resolve_path(
+        &context.config,
+        &context.package_info,
+        &context.window,
+        path,
+        options.dir,
+      )?;
+      let name = path
+        .as_ref()
+        .file_name()
+        .map(PathBuf::from)
+        .unwrap_or_default();
+      compress = compress.add(path.as_ref(), name);
+    }
+
+    // files inside the added directories must be allowed too,
+    // and they are all checked before the destination is touched
+    let scopes = context.window.state::<Scopes>();
+    for path in compress.files()? {
+      if !scopes.fs.is_allowed(&path) {
+        log::warn!("path not allowed by the fs scope: {}", path.display());
+        return Err(anyhow::anyhow!(
+          crate::Error::PathNotAllowed(path).to_string()
+        ));
+      }
+    }
+
+    // the archive is written to a temporary file next to the destination, and moved to it on success,
+    // so an existing file at the destination is left untouched if the operation fails
+    let parent = destination
+      .as_ref()
+      .parent()
+      .ok_or_else(|| anyhow::anyhow!("invalid destination: {}", destination.display()))?;
+    let mut temp = tempfile::NamedTempFile::new_in(parent)
+      .with_context(|| format!("path: {}", destination.display()))?;
+    compress
+      .write_to_with(temp.as_file_mut(), |path, bytes| {
+        // the directories might have changed since they were checked
+        if !scopes.fs.is_allowed(path) {
+          log::warn!("path not allowed by the fs scope: {}", path.display());
+          return Err(crate::api::Error::PathNotAllowed(path.to_path_buf()));
+        }
+        if let Some(on_progress_fn) = on_progress_fn {
+          let js = format_callback(on_progress_fn, &Progress { path, bytes })?;
+          let _ = context.window.eval(&js);
+        }
+        Ok(())
+      })
+      .with_context(|| format!("destination: {}", destination.display()))?;
+    temp
+      .persist(&destination)
+      .with_context(|| format!("destination: {}", destination.display()))?;
+    Ok(())
+  }
+}
+
+#[cfg(any(archive_extract, archive_create))]
+fn resolve_path<R: Runtime>(
+  config: &Config,
+  package_info: &PackageInfo,
+  window: &Window<R>,
+  path: SafePathBuf,
+  dir: Option<BaseDirectory>,
+) -> super::Result<SafePathBuf> {
+  let env = window.state::<Env>().inner();
+  match crate::api::path::resolve_path(config, package_info, env, &path, dir) {
+    Ok(path) => {
+      if window.state::<Scopes>().fs.is_allowed(&path) {
+        Ok(
+          // safety: the path is resolved by Tauri so it is safe
+          unsafe { SafePathBuf::new_unchecked(path) },
+        )
+      } else {
+        log::warn!("path not allowed by the fs scope: {}", path.display());
+        Err(anyhow::anyhow!(
+          crate::Error::PathNotAllowed(path).to_string()
+        ))
+      }
+    }
+    Err(e) => super::Result::<SafePathBuf>::Err(e.into())
+      .with_context(|| format!("path: {}, base dir: {dir:?}", path.display())),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{CreateOptions, ExtractOptions, SafePathBuf};
+  use crate::api::ipc::CallbackFn;
+  use quickcheck::{Arbitrary, Gen};
+
+  impl Arbitrary for ExtractOptions {
+    fn arbitrary(g: &mut Gen) -> Self {
+      Self {
+        format: None,
+        dir: None,
+        max_size: Option::arbitrary(g),
+      }
+    }
+  }
+
+  impl Arbitrary for CreateOptions {
+    fn arbitrary(_: &mut Gen) -> Self {
+      Self {
+        format: None,
+        dir: None,
+      }
+    }
+  }
+
+  #[tauri_macros::module_command_test(archive_extract, "archive > extract")]
+  #[quickcheck_macros::quickcheck]
+  fn extract(
+    archive: SafePathBuf,
+    destination: SafePathBuf,
+    on_progress_fn: Option<CallbackFn>,
+    options: ExtractOptions,
+  ) {
+    let res = super::Cmd::extract(
+      crate::test::mock_invoke_context(),
+      archive,
+      destination,
+      on_progress_fn,
+      options,
+    );
+    crate::test_utils::assert_not_allowlist_error(res);
+  }
+
+  #[tauri_macros::module_command_test(archive_create, "archive > create")]
+  #[quickcheck_macros::quickcheck]
+  fn create(
+    destination: SafePathBuf,
+    paths: Vec<SafePathBuf>,
+    on_progress_fn: Option<CallbackFn>,
+    options: CreateOptions,
+  ) {
+    let res = super::Cmd::create(
+      crate::test::mock_invoke_context(),
+      destination,
+      paths,
+      on_progress_fn,
+      options,
+    );
+    crate::test_utils::assert_not_allowlist_error(res);
+  }
+}
@@ -5,12 +5,24 @@
 #![allow(unused_imports)]
 
 use super::InvokeContext;
-#[cfg(any(clipboard_write_text, clipboard_read_text))]
-use crate::runtime::ClipboardManager;
+#[cfg(clipboard_any)]
+use crate::runtime::{ClipboardFormat, ClipboardImage, ClipboardManager};
 use crate::Runtime;
+#[cfg(any(clipboard_write_files, clipboard_read_files))]
+use crate::{scope::Scopes, Manager};
 use serde::Deserialize;
+use std::path::PathBuf;
 use tauri_macros::{command_enum, module_command_handler, CommandModule};
 
+/// A clipboard payload identified by its MIME type.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(clipboard_write_data), allow(dead_code))]
+pub struct ClipboardFormatDto {
+  mime_type: String,
+  data: Vec<u8>,
+}
+
 /// The API descriptor.
 #[command_enum]
 #[derive(Deserialize, CommandModule)]
@@ -21,6 +33,43 @@ pub enum Cmd {
   WriteText(String),
   /// Read clipboard content as text.
   ReadText,
+  /// Write a PNG image to the clipboard.
+  #[cmd(clipboard_write_image, "clipboard > writeImage")]
+  WriteImage(Vec<u8>),
+  /// Read clipboard content as a PNG image.
+  #[cmd(clipboard_read_image, "clipboard > readImage")]
+  ReadImage,
+  /// Write HTML to the clipboard.
+  #[cmd(clipboard_write_html, "clipboard > writeHtml")]
+  #[serde(rename_all = "camelCase")]
+  WriteHtml {
+    html: String,
+    alt_text: Option<String>,
+  },
+  /// Read clipboard content as HTML.
+  #[cmd(clipboard_read_html, "clipboard > readHtml")]
+  ReadHtml,
+  /// Write a list of file paths to the clipboard.
+  #[cmd(clipboard_write_files, "clipboard > writeFiles")]
+  WriteFiles(Vec<PathBuf>),
+  /// Read clipboard content as a list of file paths.
+  #[cmd(clipboard_read_files, "clipboard > readFiles")]
+  ReadFiles,
+  /// Write payloads with arbitrary MIME types to the clipboard.
+  #[cmd(clipboard_write_data, "clipboard > writeData")]
+  WriteData(Vec<ClipboardFormatDto>),
+  /// Read the clipboard payload with the given MIME type.
+  #[cmd(clipboard_read_data, "clipboard > readData")]
+  ReadData(String),
+  /// Get the MIME types available in the clipboard.
+  ///
+  /// Listing the formats does not read their content, so it is allowed by the `readData` flag
+  /// instead of having its own.
+  #[cmd(clipboard_read_data, "clipboard > readData")]
+  AvailableFormats,
+  /// Clear the clipboard.
+  #[cmd(clipboard_clear, "clipboard > clear")]
+  Clear,
 }
 
 impl Cmd {
@@ -48,6 +97,171 @@ impl Cmd {
   fn read_text<R: Runtime>(_: InvokeContext<R>) -> super::Result<()> {
     Err(crate::Error::ApiNotAllowlisted("clipboard > readText".into()).into_anyhow())
   }
+
+  #[module_command_handler(clipboard_write_image)]
+  fn write_image<R: Runtime>(context: InvokeContext<R>, bytes: Vec<u8>) -> super::Result<()> {
+    let mut decoder = png::Decoder::new(std::io::Cursor::new(bytes));
+    // normalizes the image to 8-bit RGB(A) or grayscale(alpha)
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer)?;
+    buffer.truncate(info.buffer_size());
+    let rgba = match info.color_type {
+      png::ColorType::Rgba => buffer,
+      png::ColorType::Rgb => buffer
+        .chunks_exact(3)
+        .flat_map(|p| [p[0], p[1], p[2], u8::MAX])
+        .collect(),
+      png::ColorType::GrayscaleAlpha => buffer
+        .chunks_exact(2)
+        .flat_map(|p| [p[0], p[0], p[0], p[1]])
+        .collect(),
+      png::ColorType::Grayscale => buffer.iter().flat_map(|g| [*g, *g, *g, u8::MAX]).collect(),
+      png::ColorType::Indexed => unreachable!("indexed images are expanded by the decoder"),
+    };
+    context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .write_image(ClipboardImage {
+        rgba,
+        width: info.width,
+        height: info.height,
+      })
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(clipboard_read_image)]
+  fn read_image<R: Runtime>(context: InvokeContext<R>) -> super::Result<Option<Vec<u8>>> {
+    let image = context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .read_image()
+      .map_err(crate::error::into_anyhow)?;
+    match image {
+      Some(image) => {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, image.width, image.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&image.rgba)?;
+        Ok(Some(bytes))
+      }
+      None => Ok(None),
+    }
+  }
+
+  #[module_command_handler(clipboard_write_html)]
+  fn write_html<R: Runtime>(
+    context: InvokeContext<R>,
+    html: String,
+    alt_text: Option<String>,
+  ) -> super::Result<()> {
+    context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .write_html(html, alt_text)
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(clipboard_read_html)]
+  fn read_html<R: Runtime>(context: InvokeContext<R>) -> super::Result<Option<String>> {
+    context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .read_html()
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(clipboard_write_files)]
+  fn write_files<R: Runtime>(context: InvokeContext<R>, paths: Vec<PathBuf>) -> super::Result<()> {
+    let scopes = context.window.state::<Scopes>();
+    if let Some(path) = paths.iter().find(|path| !scopes.fs.is_allowed(path)) {
+      return Err(crate::Error::PathNotAllowed(path.clone()).into_anyhow());
+    }
+    context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .write_files(paths)
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(clipboard_read_files)]
+  fn read_files<R: Runtime>(context: InvokeContext<R>) -> super::Result<Option<Vec<PathBuf>>> {
+    let files = context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .read_files()
+      .map_err(crate::error::into_anyhow)?;
+    // the paths outside of the fs scope are not exposed to the webview
+    let scopes = context.window.state::<Scopes>();
+    Ok(files.map(|files| {
+      files
+        .into_iter()
+        .filter(|path| scopes.fs.is_allowed(path))
+        .collect()
+    }))
+  }
+
+  #[module_command_handler(clipboard_write_data)]
+  fn write_data<R: Runtime>(
+    context: InvokeContext<R>,
+    formats: Vec<ClipboardFormatDto>,
+  ) -> super::Result<()> {
+    context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .write_formats(
+        formats
+          .into_iter()
+          .map(|f| ClipboardFormat {
+            mime_type: f.mime_type,
+            data: f.data,
+          })
+          .collect(),
+      )
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(clipboard_read_data)]
+  fn read_data<R: Runtime>(
+    context: InvokeContext<R>,
+    mime_type: String,
+  ) -> super::Result<Option<Vec<u8>>> {
+    context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .read_format(mime_type)
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(clipboard_read_data)]
+  fn available_formats<R: Runtime>(context: InvokeContext<R>) -> super::Result<Vec<String>> {
+    context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .available_formats()
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(clipboard_clear)]
+  fn clear<R: Runtime>(context: InvokeContext<R>) -> super::Result<()> {
+    context
+      .window
+      .app_handle
+      .clipboard_manager()
+      .clear()
+      .map_err(crate::error::into_anyhow)
+  }
 }
 
 #[cfg(test)]
@@ -73,4 +287,69 @@ mod tests {
       assert_eq!(super::Cmd::read_text(ctx).unwrap(), Some(text));
     }
   }
+
+  #[tauri_macros::module_command_test(clipboard_write_html, "clipboard > writeHtml")]
+  #[quickcheck_macros::quickcheck]
+  fn write_html(html: String, alt_text: Option<String>) {
+    let ctx = crate::test::mock_invoke_context();
+    super::Cmd::write_html(ctx.clone(), html.clone(), alt_text).unwrap();
+    #[cfg(clipboard_read_html)]
+    assert_eq!(super::Cmd::read_html(ctx).unwrap(), Some(html));
+  }
+
+  #[cfg(clipboard_write_text)]
+  #[test]
+  fn watch() {
+    use crate::runtime::ClipboardManager;
+
+    let ctx = crate::test::mock_invoke_context();
+    let mut clipboard = ctx.window.app_handle.clipboard_manager();
+    let (tx, rx) = std::sync::mpsc::channel();
+    clipboard
+      .watch(move |formats| {
+        let _ = tx.send(formats);
+      })
+      .unwrap();
+
+    super::Cmd::write_text(ctx, "Tauri!".into()).unwrap();
+    assert_eq!(
+      rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap(),
+      vec!["text/plain".to_string()]
+    );
+    clipboard.unwatch().unwrap();
+  }
+
+  #[cfg(clipboard_write_files)]
+  #[test]
+  fn write_files_outside_of_fs_scope() {
+    let ctx = crate::test::mock_invoke_context();
+    let path = std::env::temp_dir().join("tauri-clipboard.txt");
+    assert!(super::Cmd::write_files(ctx, vec![path]).is_err());
+  }
+
+  #[cfg(all(clipboard_write_image, clipboard_read_image))]
+  #[test]
+  fn image_round_trip() {
+    let ctx = crate::test::mock_invoke_context();
+    let mut bytes = Vec::new();
+    {
+      let mut encoder = png::Encoder::new(&mut bytes, 2, 1);
+      encoder.set_color(png::ColorType::Rgb);
+      encoder.set_depth(png::BitDepth::Eight);
+      encoder
+        .write_header()
+        .unwrap()
+        .write_image_data(&[255, 0, 0, 0, 0, 255])
+        .unwrap();
+    }
+    super::Cmd::write_image(ctx.clone(), bytes).unwrap();
+
+    let bytes = super::Cmd::read_image(ctx).unwrap().unwrap();
+    let mut reader = png::Decoder::new(std::io::Cursor::new(bytes))
+      .read_info()
+      .unwrap();
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    reader.next_frame(&mut buffer).unwrap();
+    assert_eq!(buffer, [255, 0, 0, 255, 0, 0, 255, 255]);
+  }
 }
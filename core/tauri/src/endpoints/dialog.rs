@@ -7,8 +7,8 @@
 use super::{InvokeContext, InvokeResponse};
 use crate::Runtime;
 #[cfg(any(dialog_open, dialog_save))]
-use crate::{api::dialog::blocking::FileDialogBuilder, Manager, Scopes};
-use serde::{Deserialize, Deserializer};
+use crate::{api::dialog::blocking::FileDialogBuilder, Manager, ScopeGrant, Scopes};
+use serde::{Deserialize, Deserializer, Serialize};
 use tauri_macros::{command_enum, module_command_handler, CommandModule};
 
 use std::path::PathBuf;
@@ -42,13 +42,20 @@ macro_rules! message_dialog {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DialogFilter {
   name: String,
   extensions: Vec<String>,
 }
 
+/// The selected paths and the filter matching them.
+#[derive(Debug, Serialize)]
+struct DialogSelection<T> {
+  path: T,
+  filter: Option<DialogFilter>,
+}
+
 /// The options for the open dialog API.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -70,6 +77,14 @@ pub struct OpenDialogOptions {
   /// Defines whether subdirectories will be allowed on the scope or not.
   #[serde(default)]
   pub recursive: bool,
+  /// The name of the filter selected when the dialog opens.
+  pub default_filter: Option<String>,
+  /// Whether the access to the selected paths is persisted across app restarts.
+  #[serde(default)]
+  pub persist: bool,
+  /// Whether the response includes the filter matching the selected paths.
+  #[serde(default)]
+  pub with_filter: bool,
 }
 
 /// The options for the save dialog API.
@@ -83,6 +98,21 @@ pub struct SaveDialogOptions {
   pub filters: Vec<DialogFilter>,
   /// The initial path of the dialog.
   pub default_path: Option<PathBuf>,
+  /// The name of the filter selected when the dialog opens.
+  pub default_filter: Option<String>,
+  /// Whether the response includes the filter matching the selected path.
+  #[serde(default)]
+  pub with_filter: bool,
+}
+
+/// The checkbox of a custom dialog.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogCheckbox {
+  /// The checkbox label.
+  pub label: String,
+  /// Whether the checkbox is initially checked.
+  #[serde(default)]
+  pub checked: bool,
 }
 
 /// Types of message, ask and confirm dialogs.
@@ -112,7 +142,7 @@ impl<'de> Deserialize<'de> for MessageDialogType {
   }
 }
 
-#[cfg(any(dialog_message, dialog_ask, dialog_confirm))]
+#[cfg(any(dialog_message, dialog_ask, dialog_confirm, dialog_custom))]
 impl From<MessageDialogType> for crate::api::dialog::MessageDialogKind {
   fn from(kind: MessageDialogType) -> Self {
     match kind {
@@ -162,6 +192,19 @@ pub enum Cmd {
     #[serde(rename = "buttonLabels")]
     button_labels: Option<(String, String)>,
   },
+  #[cmd(dialog_custom, "dialog > custom")]
+  CustomDialog {
+    title: Option<String>,
+    message: String,
+    #[serde(rename = "type")]
+    level: Option<MessageDialogType>,
+    #[serde(default)]
+    buttons: Vec<String>,
+    input: Option<String>,
+    checkbox: Option<DialogCheckbox>,
+  },
+  #[cmd(dialog_open, "dialog > open")]
+  RevokeAccess { path: PathBuf },
 }
 
 impl Cmd {
@@ -182,47 +225,71 @@ impl Cmd {
     if let Some(default_path) = options.default_path {
       dialog_builder = set_default_path(dialog_builder, default_path);
     }
-    for filter in options.filters {
+    let filters = sort_filters(options.filters, options.default_filter.as_deref());
+    for filter in &filters {
       let extensions: Vec<&str> = filter.extensions.iter().map(|s| &**s).collect();
-      dialog_builder = dialog_builder.add_filter(filter.name, &extensions);
+      dialog_builder = dialog_builder.add_filter(&filter.name, &extensions);
     }
 
     let scopes = context.window.state::<Scopes>();
-
-    let res = if options.directory {
-      if options.multiple {
-        let folders = dialog_builder.pick_folders();
-        if let Some(folders) = &folders {
-          for folder in folders {
-            scopes
-              .allow_directory(folder, options.recursive)
-              .map_err(crate::error::into_anyhow)?;
-          }
+    let allow = |grant: ScopeGrant| {
+      if options.persist {
+        scopes.grants.allow(grant)
+      } else {
+        match grant {
+          ScopeGrant::File { path } => scopes.allow_file(&path),
+          ScopeGrant::Directory { path, recursive } => scopes.allow_directory(&path, recursive),
         }
-        folders.into()
+      }
+      .map_err(crate::error::into_anyhow)
+    };
+
+    let paths = if options.directory {
+      let folders = if options.multiple {
+        dialog_builder.pick_folders()
       } else {
-        let folder = dialog_builder.pick_folder();
-        if let Some(path) = &folder {
-          scopes
-            .allow_directory(path, options.recursive)
-            .map_err(crate::error::into_anyhow)?;
+        dialog_builder.pick_folder().map(|folder| vec![folder])
+      };
+      if let Some(folders) = &folders {
+        for folder in folders {
+          allow(ScopeGrant::Directory {
+            path: folder.clone(),
+            recursive: options.recursive,
+          })?;
         }
-        folder.into()
       }
-    } else if options.multiple {
-      let files = dialog_builder.pick_files();
+      folders
+    } else {
+      let files = if options.multiple {
+        dialog_builder.pick_files()
+      } else {
+        dialog_builder.pick_file().map(|file| vec![file])
+      };
       if let Some(files) = &files {
         for file in files {
-          scopes.allow_file(file).map_err(crate::error::into_anyhow)?;
+          allow(ScopeGrant::File { path: file.clone() })?;
         }
       }
-      files.into()
-    } else {
-      let file = dialog_builder.pick_file();
-      if let Some(file) = &file {
-        scopes.allow_file(file).map_err(crate::error::into_anyhow)?;
+      files
+    };
+
+    let filter = paths
+      .as_ref()
+      .and_then(|paths| paths.first())
+      .and_then(|path| selected_filter(&filters, path));
+    let res = match (options.multiple, options.with_filter) {
+      (true, true) => DialogSelection {
+        path: paths,
+        filter,
+      }
+      .into(),
+      (true, false) => paths.into(),
+      (false, true) => DialogSelection {
+        path: paths.and_then(|mut paths| paths.pop()),
+        filter,
       }
-      file.into()
+      .into(),
+      (false, false) => paths.and_then(|mut paths| paths.pop()).into(),
     };
 
     Ok(res)
@@ -233,7 +300,7 @@ impl Cmd {
   fn save_dialog<R: Runtime>(
     context: InvokeContext<R>,
     options: SaveDialogOptions,
-  ) -> super::Result<Option<PathBuf>> {
+  ) -> super::Result<InvokeResponse> {
     let mut dialog_builder = FileDialogBuilder::new();
     #[cfg(any(windows, target_os = "macos"))]
     {
@@ -245,9 +312,10 @@ impl Cmd {
     if let Some(default_path) = options.default_path {
       dialog_builder = set_default_path(dialog_builder, default_path);
     }
-    for filter in options.filters {
+    let filters = sort_filters(options.filters, options.default_filter.as_deref());
+    for filter in &filters {
       let extensions: Vec<&str> = filter.extensions.iter().map(|s| &**s).collect();
-      dialog_builder = dialog_builder.add_filter(filter.name, &extensions);
+      dialog_builder = dialog_builder.add_filter(&filter.name, &extensions);
     }
 
     let scopes = context.window.state::<Scopes>();
@@ -257,7 +325,22 @@ impl Cmd {
       scopes.allow_file(p).map_err(crate::error::into_anyhow)?;
     }
 
-    Ok(path)
+    if options.with_filter {
+      let filter = path.as_ref().and_then(|p| selected_filter(&filters, p));
+      Ok(DialogSelection { path, filter }.into())
+    } else {
+      Ok(path.into())
+    }
+  }
+
+  #[module_command_handler(dialog_open)]
+  fn revoke_access<R: Runtime>(context: InvokeContext<R>, path: PathBuf) -> super::Result<()> {
+    context
+      .window
+      .scope_grants()
+      .revoke(path)
+      .map_err(crate::error::into_anyhow)?;
+    Ok(())
   }
 
   message_dialog!(
@@ -294,6 +377,62 @@ impl Cmd {
         .unwrap_or(crate::api::dialog::MessageDialogButtons::OkCancel)
     }
   );
+
+  #[module_command_handler(dialog_custom)]
+  fn custom_dialog<R: Runtime>(
+    context: InvokeContext<R>,
+    title: Option<String>,
+    message: String,
+    level: Option<MessageDialogType>,
+    buttons: Vec<String>,
+    input: Option<String>,
+    checkbox: Option<DialogCheckbox>,
+  ) -> super::Result<crate::api::dialog::CustomDialogResponse> {
+    let mut builder = crate::api::dialog::blocking::CustomDialogBuilder::new(
+      title.unwrap_or_else(|| context.window.app_handle.package_info().name.clone()),
+      message,
+    )
+    .buttons(buttons)
+    .parent_window(&context.window);
+    if let Some(level) = level {
+      builder = builder.kind(level.into());
+    }
+    if let Some(default_value) = input {
+      builder = builder.input(default_value);
+    }
+    if let Some(checkbox) = checkbox {
+      builder = builder.checkbox(checkbox.label, checkbox.checked);
+    }
+    builder.show().map_err(crate::error::into_anyhow)
+  }
+}
+
+/// Moves the default filter to the top of the list, since dialogs select the first filter.
+#[cfg(any(dialog_open, dialog_save))]
+fn sort_filters(mut filters: Vec<DialogFilter>, default_filter: Option<&str>) -> Vec<DialogFilter> {
+  if let Some(index) = default_filter.and_then(|name| filters.iter().position(|f| f.name == name)) {
+    let filter = filters.remove(index);
+    filters.insert(0, filter);
+  }
+  filters
+}
+
+/// Finds the filter matching the extension of the selected path.
+///
+/// The native dialogs don't report the filter chosen by the user,
+/// so the first filter matching the path is assumed to be the selected one.
+#[cfg(any(dialog_open, dialog_save))]
+fn selected_filter(filters: &[DialogFilter], path: &std::path::Path) -> Option<DialogFilter> {
+  let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase());
+  filters
+    .iter()
+    .find(|filter| {
+      filter
+        .extensions
+        .iter()
+        .any(|e| e == "*" || Some(e.trim_start_matches('.').to_lowercase()) == extension)
+    })
+    .cloned()
 }
 
 #[cfg(any(dialog_open, dialog_save))]
@@ -330,6 +469,9 @@ mod tests {
         default_path: Option::arbitrary(g),
         title: Option::arbitrary(g),
         recursive: bool::arbitrary(g),
+        default_filter: Option::arbitrary(g),
+        persist: bool::arbitrary(g),
+        with_filter: bool::arbitrary(g),
       }
     }
   }
@@ -340,6 +482,8 @@ mod tests {
         filters: Vec::new(),
         default_path: Option::arbitrary(g),
         title: Option::arbitrary(g),
+        default_filter: Option::arbitrary(g),
+        with_filter: bool::arbitrary(g),
       }
     }
   }
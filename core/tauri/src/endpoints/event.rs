@@ -137,8 +137,7 @@ impl Cmd {
       payload.as_ref().and_then(|p| {
         serde_json::to_string(&p)
           .map_err(|e| {
-            #[cfg(debug_assertions)]
-            eprintln!("{e}");
+            log::error!("failed to serialize the event payload: {}", e);
             e
           })
           .ok()
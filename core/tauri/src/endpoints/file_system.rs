@@ -380,6 +380,7 @@ fn resolve_path<R: Runtime>(
           unsafe { SafePathBuf::new_unchecked(path) },
         )
       } else {
+        log::warn!("path not allowed by the fs scope: {}", path.display());
         Err(anyhow::anyhow!(
           crate::Error::PathNotAllowed(path).to_string()
         ))
@@ -423,6 +424,47 @@ mod tests {
     }
   }
 
+  #[cfg(fs_read_file)]
+  #[test]
+  fn read_file_denies_scope_grants() {
+    use crate::{
+      scope::{FsScope, ScopeGrant, ScopeGrants, Scopes},
+      Manager,
+    };
+
+    let ctx = crate::test::mock_invoke_context();
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join(crate::scope::GRANTS_FILE_NAME);
+    let key_file = dir.path().join(crate::scope::GRANTS_KEY_FILE_NAME);
+    let asset_scope = FsScope::default();
+    let grants = ScopeGrants::load(
+      Some(file.clone()),
+      Some(key_file.clone()),
+      ctx.window.state::<Scopes>().fs.clone(),
+      Some(asset_scope.clone()),
+      false,
+    )
+    .unwrap();
+
+    let notes = dir.path().join("notes.txt");
+    std::fs::write(&notes, "notes").unwrap();
+    // granting the files explicitly writes them and would allow them if they weren't forbidden
+    for path in [&notes, &file, &key_file] {
+      grants
+        .allow(ScopeGrant::File {
+          path: path.to_path_buf(),
+        })
+        .unwrap();
+    }
+    assert!(super::Cmd::read_file(ctx.clone(), SafePathBuf::new(notes).unwrap(), None).is_ok());
+
+    for path in [file, key_file] {
+      assert!(path.exists());
+      assert!(!asset_scope.is_allowed(&path));
+      assert!(super::Cmd::read_file(ctx.clone(), SafePathBuf::new(path).unwrap(), None).is_err());
+    }
+  }
+
   #[tauri_macros::module_command_test(fs_read_file, "fs > readFile")]
   #[quickcheck_macros::quickcheck]
   fn read_file(path: SafePathBuf, options: Option<FileOperationOptions>) {
@@ -105,6 +105,7 @@ impl Cmd {
             if crate::api::file::SafePathBuf::new(path.clone()).is_err()
               || !scopes.fs.is_allowed(path)
             {
+              log::warn!("path not allowed by the fs scope: {}", path.display());
               return Err(crate::Error::PathNotAllowed(path.clone()).into_anyhow());
             }
           }
@@ -113,6 +114,7 @@ impl Cmd {
       let response = client.send(options).await?;
       Ok(response.read().await?)
     } else {
+      log::warn!("url not allowed by the http scope: {}", options.url);
       Err(crate::Error::UrlNotAllowed(options.url).into_anyhow())
     }
   }
@@ -0,0 +1,92 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use super::InvokeContext;
+use crate::Runtime;
+use serde::Deserialize;
+use tauri_macros::{command_enum, module_command_handler, CommandModule};
+
+/// The level of a log record.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+  Trace,
+  Debug,
+  Info,
+  Warn,
+  Error,
+}
+
+impl From<LogLevel> for log::Level {
+  fn from(level: LogLevel) -> Self {
+    match level {
+      LogLevel::Trace => Self::Trace,
+      LogLevel::Debug => Self::Debug,
+      LogLevel::Info => Self::Info,
+      LogLevel::Warn => Self::Warn,
+      LogLevel::Error => Self::Error,
+    }
+  }
+}
+
+/// The API descriptor.
+#[command_enum]
+#[derive(Deserialize, CommandModule)]
+#[serde(tag = "cmd", rename_all = "camelCase")]
+pub enum Cmd {
+  /// Logs a message with the `webview:{window label}` target.
+  #[cmd(log_write, "log > write")]
+  Log { level: LogLevel, message: String },
+}
+
+impl Cmd {
+  #[module_command_handler(log_write)]
+  fn log<R: Runtime>(
+    context: InvokeContext<R>,
+    level: LogLevel,
+    message: String,
+  ) -> super::Result<()> {
+    log::log!(
+      target: &format!("webview:{}", context.window.label()),
+      log::Level::from(level),
+      "{}",
+      escape_line_breaks(&message)
+    );
+    Ok(())
+  }
+}
+
+/// Escapes the line breaks and backslashes of a webview message, so it can't forge other log records.
+#[cfg(log_write)]
+fn escape_line_breaks(message: &str) -> String {
+  let mut escaped = String::with_capacity(message.len());
+  for c in message.chars() {
+    match c {
+      '\\' => escaped.push_str("\\\\"),
+      '\r' => escaped.push_str("\\r"),
+      '\n' => escaped.push_str("\\n"),
+      c => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+#[cfg(test)]
+mod tests {
+  #[tauri_macros::module_command_test(log_write, "log > write")]
+  #[quickcheck_macros::quickcheck]
+  fn log(message: String) {
+    let ctx = crate::test::mock_invoke_context();
+    super::Cmd::log(ctx, super::LogLevel::Info, message).unwrap();
+  }
+
+  #[cfg(log_write)]
+  #[test]
+  fn escapes_line_breaks() {
+    assert_eq!(
+      super::escape_line_breaks("done\r\n[ERROR] forged \\n"),
+      "done\\r\\n[ERROR] forged \\\\n"
+    );
+  }
+}
@@ -0,0 +1,217 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#![allow(unused_imports)]
+
+use super::{
+  window::{MenuEntryDto, NativeMenuItemDto},
+  InvokeContext,
+};
+use crate::{runtime::menu::Menu, sealed::ManagerBase, Manager, Runtime, Window};
+#[cfg(all(desktop, feature = "system-tray"))]
+use crate::{
+  runtime::menu::{SystemTrayMenu, SystemTrayMenuEntry, SystemTrayMenuItem, SystemTraySubmenu},
+  SystemTrayHandle,
+};
+use serde::Deserialize;
+use tauri_macros::{command_enum, module_command_handler, CommandModule};
+
+/// The changes to apply to a menu item. Fields that are not set are left untouched.
+#[derive(Debug, Default, Deserialize)]
+pub struct MenuItemUpdate {
+  enabled: Option<bool>,
+  title: Option<String>,
+  selected: Option<bool>,
+}
+
+#[cfg(all(desktop, feature = "system-tray"))]
+impl From<MenuEntryDto> for SystemTrayMenuEntry {
+  fn from(entry: MenuEntryDto) -> Self {
+    match entry {
+      MenuEntryDto::Submenu {
+        title,
+        enabled,
+        items,
+      } => {
+        let mut menu = SystemTrayMenu::new();
+        menu.items = items.into_iter().map(Into::into).collect();
+        let mut submenu = SystemTraySubmenu::new(title, menu);
+        submenu.enabled = enabled;
+        Self::Submenu(submenu)
+      }
+      MenuEntryDto::CustomItem(item) => Self::CustomItem(item.into()),
+      MenuEntryDto::NativeItem(NativeMenuItemDto::Separator) => {
+        Self::NativeItem(SystemTrayMenuItem::Separator)
+      }
+    }
+  }
+}
+
+/// The API descriptor.
+#[command_enum]
+#[derive(Deserialize, CommandModule)]
+#[serde(tag = "cmd", rename_all = "camelCase")]
+pub enum Cmd {
+  /// Replaces the menu of a window.
+  #[cmd(menu_set_window_menu, "menu > setWindowMenu")]
+  SetWindowMenu {
+    label: Option<String>,
+    items: Vec<MenuEntryDto>,
+  },
+  /// Replaces the menu of a system tray.
+  #[cmd(menu_set_tray_menu, "menu > setTrayMenu")]
+  #[serde(rename_all = "camelCase")]
+  SetTrayMenu {
+    tray_id: Option<String>,
+    items: Vec<MenuEntryDto>,
+  },
+  /// Updates an item of a window menu.
+  #[cmd(menu_update_item, "menu > updateItem")]
+  UpdateWindowMenuItem {
+    label: Option<String>,
+    id: String,
+    update: MenuItemUpdate,
+  },
+  /// Updates an item of a system tray menu.
+  #[cmd(menu_update_item, "menu > updateItem")]
+  #[serde(rename_all = "camelCase")]
+  UpdateTrayMenuItem {
+    tray_id: Option<String>,
+    id: String,
+    update: MenuItemUpdate,
+  },
+}
+
+impl Cmd {
+  #[module_command_handler(menu_set_window_menu)]
+  fn set_window_menu<R: Runtime>(
+    context: InvokeContext<R>,
+    label: Option<String>,
+    items: Vec<MenuEntryDto>,
+  ) -> super::Result<()> {
+    let mut menu = Menu::new();
+    menu.items = items.into_iter().map(Into::into).collect();
+    get_window(context, label)?
+      .menu_handle()
+      .set_menu(menu)
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(menu_set_tray_menu)]
+  #[allow(unused_variables)]
+  fn set_tray_menu<R: Runtime>(
+    context: InvokeContext<R>,
+    tray_id: Option<String>,
+    items: Vec<MenuEntryDto>,
+  ) -> super::Result<()> {
+    #[cfg(all(desktop, feature = "system-tray"))]
+    {
+      let mut menu = SystemTrayMenu::new();
+      menu.items = items.into_iter().map(Into::into).collect();
+      get_tray(&context, tray_id)?
+        .set_menu(menu)
+        .map_err(crate::error::into_anyhow)
+    }
+    #[cfg(not(all(desktop, feature = "system-tray")))]
+    Err(crate::Error::TrayNotFound(tray_id.unwrap_or_default()).into_anyhow())
+  }
+
+  #[module_command_handler(menu_update_item)]
+  fn update_window_menu_item<R: Runtime>(
+    context: InvokeContext<R>,
+    label: Option<String>,
+    id: String,
+    update: MenuItemUpdate,
+  ) -> super::Result<()> {
+    let item = get_window(context, label)?
+      .menu_handle()
+      .try_get_item(&id)
+      .ok_or(crate::Error::MenuItemNotFound(id))?;
+    if let Some(enabled) = update.enabled {
+      item.set_enabled(enabled)?;
+    }
+    if let Some(title) = update.title {
+      item.set_title(title)?;
+    }
+    if let Some(selected) = update.selected {
+      item.set_selected(selected)?;
+    }
+    Ok(())
+  }
+
+  #[module_command_handler(menu_update_item)]
+  #[allow(unused_variables)]
+  fn update_tray_menu_item<R: Runtime>(
+    context: InvokeContext<R>,
+    tray_id: Option<String>,
+    id: String,
+    update: MenuItemUpdate,
+  ) -> super::Result<()> {
+    #[cfg(all(desktop, feature = "system-tray"))]
+    {
+      let item = get_tray(&context, tray_id)?
+        .try_get_item(&id)
+        .ok_or(crate::Error::MenuItemNotFound(id))?;
+      if let Some(enabled) = update.enabled {
+        item.set_enabled(enabled)?;
+      }
+      if let Some(title) = update.title {
+        item.set_title(title)?;
+      }
+      if let Some(selected) = update.selected {
+        item.set_selected(selected)?;
+      }
+      Ok(())
+    }
+    #[cfg(not(all(desktop, feature = "system-tray")))]
+    Err(crate::Error::TrayNotFound(tray_id.unwrap_or_default()).into_anyhow())
+  }
+}
+
+#[cfg(any(menu_set_window_menu, menu_update_item))]
+fn get_window<R: Runtime>(
+  context: InvokeContext<R>,
+  label: Option<String>,
+) -> crate::Result<Window<R>> {
+  match label {
+    Some(l) if !l.is_empty() => context
+      .window
+      .get_window(&l)
+      .ok_or(crate::Error::WebviewNotFound),
+    _ => Ok(context.window),
+  }
+}
+
+/// Gets the tray with the given id, or the tray configured with [`crate::Builder::system_tray`] if no id is given.
+#[cfg(all(desktop, feature = "system-tray"))]
+fn get_tray<R: Runtime>(
+  context: &InvokeContext<R>,
+  tray_id: Option<String>,
+) -> crate::Result<SystemTrayHandle<R>> {
+  match tray_id {
+    Some(id) => context
+      .window
+      .app_handle
+      .tray_handle_by_id(&id)
+      .ok_or(crate::Error::TrayNotFound(id)),
+    None => context
+      .window
+      .manager()
+      .default_tray()
+      .ok_or_else(|| crate::Error::TrayNotFound("default".into())),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::MenuItemUpdate;
+
+  #[test]
+  fn deserialize_partial_update() {
+    let update: MenuItemUpdate = serde_json::from_str(r#"{ "title": "Quit" }"#).unwrap();
+    assert_eq!(update.title.as_deref(), Some("Quit"));
+    assert!(update.enabled.is_none());
+    assert!(update.selected.is_none());
+  }
+}
@@ -10,7 +10,12 @@ use serde::Deserialize;
 use tauri_macros::{command_enum, module_command_handler, CommandModule};
 
 #[cfg(notification_all)]
-use crate::{api::notification::Notification, Env, Manager};
+use crate::{
+  api::notification::{Notification, NotificationAction, Schedule, Urgency},
+  Env, Manager,
+};
+#[cfg(notification_all)]
+use std::time::{Duration, UNIX_EPOCH};
 
 // `Granted` response from `request_permission`. Matches the Web API return value.
 const PERMISSION_GRANTED: &str = "granted";
@@ -19,6 +24,7 @@ const PERMISSION_DENIED: &str = "denied";
 
 /// The options for the notification API.
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(not(notification_all), allow(dead_code))]
 pub struct NotificationOptions {
   /// The notification title.
   pub title: String,
@@ -26,6 +32,42 @@ pub struct NotificationOptions {
   pub body: Option<String>,
   /// The notification icon.
   pub icon: Option<String>,
+  /// The notification identifier, sent back on its events.
+  pub id: Option<String>,
+  /// The notification buttons.
+  #[cfg(notification_all)]
+  #[serde(default)]
+  pub actions: Vec<NotificationAction>,
+  /// The notification sound.
+  pub sound: Option<String>,
+  /// The notification urgency.
+  #[cfg(notification_all)]
+  pub urgency: Option<Urgency>,
+  /// How long the notification is shown, in milliseconds.
+  pub timeout: Option<u64>,
+  /// When the notification is delivered.
+  pub schedule: Option<ScheduleDto>,
+}
+
+/// When a notification is delivered.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[cfg_attr(not(notification_all), allow(dead_code))]
+#[serde(rename_all = "camelCase")]
+pub enum ScheduleDto {
+  /// Delivers the notification at the given time, in milliseconds since the Unix epoch.
+  At(u64),
+  /// Delivers the notification after the given delay, in milliseconds.
+  After(u64),
+}
+
+#[cfg(notification_all)]
+impl From<ScheduleDto> for Schedule {
+  fn from(schedule: ScheduleDto) -> Self {
+    match schedule {
+      ScheduleDto::At(ms) => Self::At(UNIX_EPOCH + Duration::from_millis(ms)),
+      ScheduleDto::After(ms) => Self::After(Duration::from_millis(ms)),
+    }
+  }
 }
 
 /// The API descriptor.
@@ -56,12 +98,25 @@ impl Cmd {
     if let Some(icon) = options.icon {
       notification = notification.icon(icon);
     }
-    #[cfg(feature = "windows7-compat")]
-    {
-      notification.notify(&context.window.app_handle)?;
+    if let Some(id) = options.id {
+      notification = notification.id(id);
+    }
+    for action in options.actions {
+      notification = notification.action(action);
+    }
+    if let Some(sound) = options.sound {
+      notification = notification.sound(sound);
+    }
+    if let Some(urgency) = options.urgency {
+      notification = notification.urgency(urgency);
+    }
+    if let Some(timeout) = options.timeout {
+      notification = notification.timeout(Duration::from_millis(timeout));
+    }
+    if let Some(schedule) = options.schedule {
+      notification = notification.schedule(schedule.into());
     }
-    #[cfg(not(feature = "windows7-compat"))]
-    notification.show()?;
+    notification.notify(&context.window.app_handle)?;
     Ok(())
   }
 
@@ -94,6 +149,14 @@ mod tests {
         title: String::arbitrary(g),
         body: Option::arbitrary(g),
         icon: Option::arbitrary(g),
+        id: Option::arbitrary(g),
+        #[cfg(notification_all)]
+        actions: Vec::new(),
+        sound: Option::arbitrary(g),
+        #[cfg(notification_all)]
+        urgency: None,
+        timeout: Option::arbitrary(g),
+        schedule: None,
       }
     }
   }
@@ -0,0 +1,149 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#![allow(unused_imports)]
+
+use super::InvokeContext;
+use crate::{api::secrets::Secrets, Runtime};
+use serde::Deserialize;
+use tauri_macros::{command_enum, module_command_handler, CommandModule};
+
+/// The API descriptor.
+#[command_enum]
+#[derive(Deserialize, CommandModule)]
+#[serde(tag = "cmd", rename_all = "camelCase")]
+pub enum Cmd {
+  /// Gets the secret of an account.
+  #[cmd(secrets_get, "secrets > get")]
+  Get { service: String, account: String },
+  /// Stores the secret of an account.
+  #[cmd(secrets_set, "secrets > set")]
+  Set {
+    service: String,
+    account: String,
+    secret: String,
+  },
+  /// Deletes the secret of an account.
+  #[cmd(secrets_delete, "secrets > delete")]
+  Delete { service: String, account: String },
+}
+
+impl Cmd {
+  #[module_command_handler(secrets_get)]
+  fn get<R: Runtime>(
+    context: InvokeContext<R>,
+    service: String,
+    account: String,
+  ) -> super::Result<Option<String>> {
+    secrets(&context)
+      .get(&scoped_service(&context, &service), &account)
+      .map_err(Into::into)
+  }
+
+  #[module_command_handler(secrets_set)]
+  fn set<R: Runtime>(
+    context: InvokeContext<R>,
+    service: String,
+    account: String,
+    secret: String,
+  ) -> super::Result<()> {
+    secrets(&context)
+      .set(&scoped_service(&context, &service), &account, &secret)
+      .map_err(Into::into)
+  }
+
+  #[module_command_handler(secrets_delete)]
+  fn delete<R: Runtime>(
+    context: InvokeContext<R>,
+    service: String,
+    account: String,
+  ) -> super::Result<bool> {
+    secrets(&context)
+      .delete(&scoped_service(&context, &service), &account)
+      .map_err(Into::into)
+  }
+}
+
+/// The secrets of the webview fall back to the app local data directory when the Secret Service is not available.
+///
+/// The files of the fallback are forbidden on the fs and asset protocol scopes when the app is built.
+#[cfg(not(test))]
+fn secrets<R: Runtime>(context: &InvokeContext<R>) -> Secrets {
+  let secrets = Secrets::new();
+  match crate::api::path::app_local_data_dir(&context.config) {
+    Some(dir) => secrets.fallback_dir(dir),
+    None => secrets,
+  }
+}
+
+#[cfg(test)]
+fn secrets<R: Runtime>(_context: &InvokeContext<R>) -> Secrets {
+  Secrets::mock()
+}
+
+/// Prefixes the service with the bundle identifier, so the webview can't access the secrets of other apps.
+fn scoped_service<R: Runtime>(context: &InvokeContext<R>, service: &str) -> String {
+  format!("{}.{}", context.config.tauri.bundle.identifier, service)
+}
+
+#[cfg(test)]
+mod tests {
+  const SERVICE: &str = "endpoints-secrets-test";
+
+  #[tauri_macros::module_command_test(secrets_get, "secrets > get")]
+  #[test]
+  fn get() {
+    let ctx = crate::test::mock_invoke_context();
+    let service = super::scoped_service(&ctx, SERVICE);
+    assert_eq!(
+      super::Cmd::get(ctx.clone(), SERVICE.into(), "get".into()).unwrap(),
+      None
+    );
+    super::secrets(&ctx).set(&service, "get", "secret").unwrap();
+    assert_eq!(
+      super::Cmd::get(ctx.clone(), SERVICE.into(), "get".into()).unwrap(),
+      Some("secret".into())
+    );
+    // the service is scoped to the app
+    assert_eq!(super::secrets(&ctx).get(SERVICE, "get").unwrap(), None);
+  }
+
+  #[tauri_macros::module_command_test(secrets_set, "secrets > set")]
+  #[test]
+  fn set() {
+    let ctx = crate::test::mock_invoke_context();
+    super::Cmd::set(ctx.clone(), SERVICE.into(), "set".into(), "secret".into()).unwrap();
+    let service = super::scoped_service(&ctx, SERVICE);
+    assert_eq!(
+      super::secrets(&ctx).get(&service, "set").unwrap(),
+      Some("secret".into())
+    );
+  }
+
+  #[tauri_macros::module_command_test(secrets_delete, "secrets > delete")]
+  #[test]
+  fn delete() {
+    let ctx = crate::test::mock_invoke_context();
+    super::secrets(&ctx)
+      .set(&super::scoped_service(&ctx, SERVICE), "delete", "secret")
+      .unwrap();
+    assert!(super::Cmd::delete(ctx.clone(), SERVICE.into(), "delete".into()).unwrap());
+    assert!(!super::Cmd::delete(ctx, SERVICE.into(), "delete".into()).unwrap());
+  }
+
+  #[cfg(secrets_get)]
+  #[test]
+  fn forbids_fallback_files() {
+    let ctx = crate::test::mock_invoke_context();
+    let dir = crate::api::path::app_local_data_dir(&ctx.config).unwrap();
+    let scopes = crate::Manager::state::<crate::scope::Scopes>(&ctx.window);
+    scopes.fs.allow_directory(&dir, true).unwrap();
+    assert!(scopes.fs.is_allowed(dir.join("notes.txt")));
+    for file in crate::api::secrets::fallback_files(&dir) {
+      // dotfiles are not matched by the directory pattern on unix
+      scopes.fs.allow_file(&file).unwrap();
+      assert!(!scopes.fs.is_allowed(file));
+    }
+  }
+}
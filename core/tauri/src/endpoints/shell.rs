@@ -31,6 +31,17 @@ fn command_child_store() -> &'static ChildStore {
   &STORE
 }
 
+type SupervisorId = u32;
+#[cfg(any(shell_execute, shell_sidecar))]
+type SupervisorStore = Arc<Mutex<HashMap<SupervisorId, crate::api::process::SupervisorHandle>>>;
+
+#[cfg(any(shell_execute, shell_sidecar))]
+fn supervisor_store() -> &'static SupervisorStore {
+  use once_cell::sync::Lazy;
+  static STORE: Lazy<SupervisorStore> = Lazy::new(Default::default);
+  &STORE
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 pub enum Buffer {
@@ -38,6 +49,13 @@ pub enum Buffer {
   Raw(Vec<u8>),
 }
 
+/// The size of the pseudo terminal a command is spawned in.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct PtySize {
+  rows: u16,
+  cols: u16,
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn default_env() -> Option<HashMap<String, String>> {
   Some(HashMap::default())
@@ -56,6 +74,126 @@ pub struct CommandOptions {
   env: Option<HashMap<String, String>>,
   // Character encoding for stdout/stderr
   encoding: Option<String>,
+  // Sends stdout/stderr as raw byte chunks instead of lines
+  #[serde(default)]
+  raw: bool,
+  // Spawns the command in a pseudo terminal of the given size
+  pty: Option<PtySize>,
+  // Spawns the command in a new process group so its whole process tree is terminated
+  #[serde(default)]
+  process_group: bool,
+  // Time in milliseconds the process is given to exit before it is killed
+  kill_timeout: Option<u64>,
+}
+
+/// How a supervised command is restarted. Durations are in milliseconds.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupervisorOptions {
+  ready_pattern: Option<String>,
+  ready_port: Option<u16>,
+  ready_timeout: Option<u64>,
+  initial_backoff: Option<u64>,
+  max_backoff: Option<u64>,
+  max_restarts: Option<u32>,
+  restart_period: Option<u64>,
+}
+
+#[cfg(any(shell_execute, shell_sidecar))]
+#[allow(unused_variables)]
+fn prepare_command<R: Runtime>(
+  context: &InvokeContext<R>,
+  program: String,
+  args: ExecuteArgs,
+  options: CommandOptions,
+) -> super::Result<crate::api::process::Command> {
+  let mut command = if options.sidecar {
+    #[cfg(not(shell_sidecar))]
+    return Err(crate::Error::ApiNotAllowlisted("shell > sidecar".to_string()).into_anyhow());
+    #[cfg(shell_sidecar)]
+    {
+      let program = PathBuf::from(program);
+      let program_as_string = program.display().to_string();
+      let program_no_ext_as_string = program.with_extension("").display().to_string();
+      let configured_sidecar = context
+        .config
+        .tauri
+        .bundle
+        .external_bin
+        .as_ref()
+        .map(|bins| {
+          bins
+            .iter()
+            .find(|b| b == &&program_as_string || b == &&program_no_ext_as_string)
+        })
+        .unwrap_or_default();
+      if let Some(sidecar) = configured_sidecar {
+        context
+          .window
+          .state::<Scopes>()
+          .shell
+          .prepare_sidecar(&program.to_string_lossy(), sidecar, args)
+          .map_err(crate::error::into_anyhow)?
+      } else {
+        return Err(crate::Error::SidecarNotAllowed(program).into_anyhow());
+      }
+    }
+  } else {
+    #[cfg(not(shell_execute))]
+    return Err(crate::Error::ApiNotAllowlisted("shell > execute".to_string()).into_anyhow());
+    #[cfg(shell_execute)]
+    match context
+      .window
+      .state::<Scopes>()
+      .shell
+      .prepare(&program, args)
+    {
+      Ok(cmd) => cmd,
+      Err(e) => {
+        log::warn!("program not allowed by the shell scope: {}", e);
+        return Err(crate::Error::ProgramNotAllowed(PathBuf::from(program)).into_anyhow());
+      }
+    }
+  };
+  if let Some(cwd) = options.cwd {
+    command = command.current_dir(cwd);
+  }
+  if let Some(env) = options.env {
+    command = command.envs(env);
+  } else {
+    command = command.env_clear();
+  }
+  if let Some(encoding) = options.encoding {
+    if let Some(encoding) = crate::api::process::Encoding::for_label(encoding.as_bytes()) {
+      command = command.encoding(encoding);
+    } else {
+      return Err(anyhow::anyhow!(format!("unknown encoding {encoding}")));
+    }
+  }
+  if options.raw {
+    command = command.raw_output();
+  }
+  if options.process_group {
+    command = command.process_group();
+  }
+  if let Some(timeout) = options.kill_timeout {
+    command = command.kill_policy(crate::api::process::KillPolicy::Graceful {
+      timeout: std::time::Duration::from_millis(timeout),
+    });
+  }
+  if let Some(size) = options.pty {
+    #[cfg(not(shell_pty))]
+    return Err(crate::Error::ApiNotAllowlisted("shell > pty".to_string()).into_anyhow());
+    #[cfg(shell_pty)]
+    {
+      command = command.pty(crate::api::process::PtySize {
+        rows: size.rows,
+        cols: size.cols,
+      });
+    }
+  }
+  Ok(command)
 }
 
 /// The API descriptor.
@@ -77,13 +215,28 @@ pub enum Cmd {
   StdinWrite { pid: ChildId, buffer: Buffer },
   #[cmd(shell_script, "shell > execute or shell > sidecar")]
   KillChild { pid: ChildId },
+  /// Spawns a command and restarts it when it terminates.
+  #[cmd(shell_script, "shell > execute or shell > sidecar")]
+  #[serde(rename_all = "camelCase")]
+  Supervise {
+    program: String,
+    args: ExecuteArgs,
+    on_event_fn: CallbackFn,
+    #[serde(default)]
+    options: CommandOptions,
+    #[serde(default)]
+    supervisor: SupervisorOptions,
+  },
+  #[cmd(shell_script, "shell > execute or shell > sidecar")]
+  StopSupervisor { id: SupervisorId },
+  #[cmd(shell_script, "shell > execute or shell > sidecar")]
+  ResizePty { pid: ChildId, rows: u16, cols: u16 },
   #[cmd(shell_open, "shell > open")]
   Open { path: String, with: Option<String> },
 }
 
 impl Cmd {
   #[module_command_handler(shell_script)]
-  #[allow(unused_variables)]
   fn execute<R: Runtime>(
     context: InvokeContext<R>,
     program: String,
@@ -91,91 +244,90 @@ impl Cmd {
     on_event_fn: CallbackFn,
     options: CommandOptions,
   ) -> super::Result<ChildId> {
-    let mut command = if options.sidecar {
-      #[cfg(not(shell_sidecar))]
-      return Err(crate::Error::ApiNotAllowlisted("shell > sidecar".to_string()).into_anyhow());
-      #[cfg(shell_sidecar)]
-      {
-        let program = PathBuf::from(program);
-        let program_as_string = program.display().to_string();
-        let program_no_ext_as_string = program.with_extension("").display().to_string();
-        let configured_sidecar = context
-          .config
-          .tauri
-          .bundle
-          .external_bin
-          .as_ref()
-          .map(|bins| {
-            bins
-              .iter()
-              .find(|b| b == &&program_as_string || b == &&program_no_ext_as_string)
-          })
-          .unwrap_or_default();
-        if let Some(sidecar) = configured_sidecar {
-          context
-            .window
-            .state::<Scopes>()
-            .shell
-            .prepare_sidecar(&program.to_string_lossy(), sidecar, args)
-            .map_err(crate::error::into_anyhow)?
-        } else {
-          return Err(crate::Error::SidecarNotAllowed(program).into_anyhow());
-        }
-      }
-    } else {
-      #[cfg(not(shell_execute))]
-      return Err(crate::Error::ApiNotAllowlisted("shell > execute".to_string()).into_anyhow());
-      #[cfg(shell_execute)]
-      match context
-        .window
-        .state::<Scopes>()
-        .shell
-        .prepare(&program, args)
-      {
-        Ok(cmd) => cmd,
-        Err(e) => {
-          #[cfg(debug_assertions)]
-          eprintln!("{e}");
-          return Err(crate::Error::ProgramNotAllowed(PathBuf::from(program)).into_anyhow());
-        }
-      }
-    };
-    #[cfg(any(shell_execute, shell_sidecar))]
-    {
-      if let Some(cwd) = options.cwd {
-        command = command.current_dir(cwd);
-      }
-      if let Some(env) = options.env {
-        command = command.envs(env);
-      } else {
-        command = command.env_clear();
-      }
-      if let Some(encoding) = options.encoding {
-        if let Some(encoding) = crate::api::process::Encoding::for_label(encoding.as_bytes()) {
-          command = command.encoding(encoding);
-        } else {
-          return Err(anyhow::anyhow!(format!("unknown encoding {encoding}")));
+    let command = prepare_command(&context, program, args, options)?;
+    let (mut rx, child) = command.spawn()?;
+
+    let pid = child.pid();
+    command_child_store().lock().unwrap().insert(pid, child);
+
+    crate::async_runtime::spawn(async move {
+      while let Some(event) = rx.recv().await {
+        if matches!(event, crate::api::process::CommandEvent::Terminated(_)) {
+          command_child_store().lock().unwrap().remove(&pid);
         }
+        let js = crate::api::ipc::format_callback(on_event_fn, &event)
+          .expect("unable to serialize CommandEvent");
+
+        let _ = context.window.eval(js.as_str());
       }
-      let (mut rx, child) = command.spawn()?;
+    });
 
-      let pid = child.pid();
-      command_child_store().lock().unwrap().insert(pid, child);
+    Ok(pid)
+  }
 
-      crate::async_runtime::spawn(async move {
-        while let Some(event) = rx.recv().await {
-          if matches!(event, crate::api::process::CommandEvent::Terminated(_)) {
-            command_child_store().lock().unwrap().remove(&pid);
-          }
-          let js = crate::api::ipc::format_callback(on_event_fn, &event)
-            .expect("unable to serialize CommandEvent");
+  #[module_command_handler(shell_script)]
+  fn supervise<R: Runtime>(
+    context: InvokeContext<R>,
+    program: String,
+    args: ExecuteArgs,
+    on_event_fn: CallbackFn,
+    options: CommandOptions,
+    supervisor: SupervisorOptions,
+  ) -> super::Result<SupervisorId> {
+    use crate::api::process::{ReadinessProbe, Supervisor, SupervisorEvent};
+    use std::time::Duration;
 
-          let _ = context.window.eval(js.as_str());
+    let command = prepare_command(&context, program, args, options)?;
+    let mut builder = Supervisor::new(command);
+    if let Some(pattern) = supervisor.ready_pattern {
+      builder = builder.readiness_probe(ReadinessProbe::Stdout(pattern));
+    } else if let Some(port) = supervisor.ready_port {
+      builder = builder.readiness_probe(ReadinessProbe::TcpPort(port));
+    }
+    if let Some(timeout) = supervisor.ready_timeout {
+      builder = builder.readiness_timeout(Duration::from_millis(timeout));
+    }
+    if supervisor.initial_backoff.is_some() || supervisor.max_backoff.is_some() {
+      let initial = supervisor.initial_backoff.unwrap_or(500);
+      let max = supervisor.max_backoff.unwrap_or(30_000);
+      builder = builder.backoff(Duration::from_millis(initial), Duration::from_millis(max));
+    }
+    if let Some(max_restarts) = supervisor.max_restarts {
+      let period = supervisor.restart_period.unwrap_or(60_000);
+      builder = builder.restart_limit(max_restarts, Duration::from_millis(period));
+    }
+    let (mut rx, handle) = builder.spawn()?;
+
+    let id = handle.id();
+    supervisor_store().lock().unwrap().insert(id, handle);
+
+    crate::async_runtime::spawn(async move {
+      while let Some(event) = rx.recv().await {
+        if matches!(
+          event,
+          SupervisorEvent::Stopped | SupervisorEvent::RestartLimitReached
+        ) {
+          supervisor_store().lock().unwrap().remove(&id);
         }
-      });
+        let js = crate::api::ipc::format_callback(on_event_fn, &event)
+          .expect("unable to serialize SupervisorEvent");
+
+        let _ = context.window.eval(js.as_str());
+      }
+    });
+
+    Ok(id)
+  }
 
-      Ok(pid)
+  #[module_command_handler(shell_script)]
+  fn stop_supervisor<R: Runtime>(
+    _context: InvokeContext<R>,
+    id: SupervisorId,
+  ) -> super::Result<()> {
+    if let Some(handle) = supervisor_store().lock().unwrap().get(&id) {
+      handle.stop();
     }
+    Ok(())
   }
 
   #[module_command_handler(shell_script)]
@@ -195,12 +347,33 @@ impl Cmd {
 
   #[module_command_handler(shell_script)]
   fn kill_child<R: Runtime>(_context: InvokeContext<R>, pid: ChildId) -> super::Result<()> {
-    if let Some(child) = command_child_store().lock().unwrap().remove(&pid) {
-      child.kill()?;
+    let child = command_child_store().lock().unwrap().remove(&pid);
+    if let Some(child) = child {
+      // the kill timeout is awaited on a blocking task instead of the async runtime
+      child.terminate_in_background()?;
     }
     Ok(())
   }
 
+  #[module_command_handler(shell_script)]
+  #[allow(unused_variables)]
+  fn resize_pty<R: Runtime>(
+    _context: InvokeContext<R>,
+    pid: ChildId,
+    rows: u16,
+    cols: u16,
+  ) -> super::Result<()> {
+    #[cfg(not(shell_pty))]
+    return Err(crate::Error::ApiNotAllowlisted("shell > pty".to_string()).into_anyhow());
+    #[cfg(shell_pty)]
+    {
+      if let Some(child) = command_child_store().lock().unwrap().get(&pid) {
+        child.resize(crate::api::process::PtySize { rows, cols })?;
+      }
+      Ok(())
+    }
+  }
+
   /// Open a (url) path with a default or specific browser opening program.
   ///
   /// See [`crate::api::shell::open`] for how it handles security-related measures.
@@ -228,7 +401,7 @@ impl Cmd {
 
 #[cfg(test)]
 mod tests {
-  use super::{Buffer, ChildId, CommandOptions, ExecuteArgs};
+  use super::{Buffer, ChildId, CommandOptions, ExecuteArgs, SupervisorId, SupervisorOptions};
   use crate::api::ipc::CallbackFn;
   use quickcheck::{Arbitrary, Gen};
 
@@ -239,6 +412,24 @@ mod tests {
         cwd: Option::arbitrary(g),
         env: Option::arbitrary(g),
         encoding: Option::arbitrary(g),
+        raw: bool::arbitrary(g),
+        pty: None,
+        process_group: bool::arbitrary(g),
+        kill_timeout: Option::arbitrary(g),
+      }
+    }
+  }
+
+  impl Arbitrary for SupervisorOptions {
+    fn arbitrary(g: &mut Gen) -> Self {
+      Self {
+        ready_pattern: Option::arbitrary(g),
+        ready_port: Option::arbitrary(g),
+        ready_timeout: Option::arbitrary(g),
+        initial_backoff: Option::arbitrary(g),
+        max_backoff: Option::arbitrary(g),
+        max_restarts: Option::arbitrary(g),
+        restart_period: Option::arbitrary(g),
       }
     }
   }
@@ -274,6 +465,25 @@ mod tests {
   #[quickcheck_macros::quickcheck]
   fn kill_child(_pid: ChildId) {}
 
+  #[tauri_macros::module_command_test(shell_execute, "shell > execute or shell > sidecar")]
+  #[quickcheck_macros::quickcheck]
+  fn supervise(
+    _program: String,
+    _args: ExecuteArgs,
+    _on_event_fn: CallbackFn,
+    _options: CommandOptions,
+    _supervisor: SupervisorOptions,
+  ) {
+  }
+
+  #[tauri_macros::module_command_test(shell_execute, "shell > execute or shell > sidecar")]
+  #[quickcheck_macros::quickcheck]
+  fn stop_supervisor(_id: SupervisorId) {}
+
+  #[tauri_macros::module_command_test(shell_execute, "shell > execute or shell > sidecar")]
+  #[quickcheck_macros::quickcheck]
+  fn resize_pty(_pid: ChildId, _rows: u16, _cols: u16) {}
+
   #[tauri_macros::module_command_test(shell_open, "shell > open")]
   #[quickcheck_macros::quickcheck]
   fn open(_path: String, _with: Option<String>) {}
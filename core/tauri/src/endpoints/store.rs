@@ -0,0 +1,156 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#![allow(unused_imports)]
+
+use super::InvokeContext;
+use crate::{store::Store, Manager, Runtime};
+use serde::Deserialize;
+use serde_json::{Map, Value as JsonValue};
+use tauri_macros::{command_enum, module_command_handler, CommandModule};
+
+/// The API descriptor.
+#[command_enum]
+#[derive(Deserialize, CommandModule)]
+#[serde(tag = "cmd", rename_all = "camelCase")]
+pub enum Cmd {
+  /// Gets the value of a key, or its default.
+  #[cmd(store_get, "store > get")]
+  Get { key: String },
+  /// Sets the value of a key.
+  #[cmd(store_set, "store > set")]
+  Set { key: String, value: JsonValue },
+  /// Removes the value of a key.
+  #[cmd(store_remove, "store > remove")]
+  Remove { key: String },
+  /// Whether the key is set or has a default.
+  #[cmd(store_get, "store > get")]
+  Has { key: String },
+  /// Gets all the values.
+  #[cmd(store_get, "store > get")]
+  Entries,
+}
+
+impl Cmd {
+  #[module_command_handler(store_get)]
+  fn get<R: Runtime>(context: InvokeContext<R>, key: String) -> super::Result<Option<JsonValue>> {
+    Ok(context.window.state::<Store>().get_value(&key))
+  }
+
+  #[module_command_handler(store_set)]
+  fn set<R: Runtime>(
+    context: InvokeContext<R>,
+    key: String,
+    value: JsonValue,
+  ) -> super::Result<()> {
+    context
+      .window
+      .state::<Store>()
+      .set(key, value)
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(store_remove)]
+  fn remove<R: Runtime>(context: InvokeContext<R>, key: String) -> super::Result<bool> {
+    context
+      .window
+      .state::<Store>()
+      .remove(&key)
+      .map_err(crate::error::into_anyhow)
+  }
+
+  #[module_command_handler(store_get)]
+  fn has<R: Runtime>(context: InvokeContext<R>, key: String) -> super::Result<bool> {
+    Ok(context.window.state::<Store>().has(&key))
+  }
+
+  #[module_command_handler(store_get)]
+  fn entries<R: Runtime>(context: InvokeContext<R>) -> super::Result<Map<String, JsonValue>> {
+    Ok(context.window.state::<Store>().entries())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{endpoints::InvokeContext, store::Store, test::MockRuntime, Manager};
+  use serde_json::{json, Map, Value as JsonValue};
+  use std::path::Path;
+
+  // keeps the values in the given directory instead of the app config directory
+  #[cfg(store_any)]
+  fn invoke_context(dir: &Path) -> InvokeContext<MockRuntime> {
+    let mut defaults = Map::new();
+    defaults.insert("theme".into(), json!("light"));
+    let store = Store::load(Some(dir.join("store.json")), defaults, Box::new(|_| {}));
+    let app = crate::Builder::<MockRuntime>::new()
+      .manage(store)
+      .build(crate::test::mock_context(crate::test::noop_assets()))
+      .unwrap();
+    InvokeContext {
+      window: app.get_window("main").unwrap(),
+      config: app.config(),
+      package_info: app.package_info().clone(),
+    }
+  }
+
+  #[tauri_macros::module_command_test(store_get, "store > get")]
+  #[test]
+  fn get() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = invoke_context(dir.path());
+    assert_eq!(
+      super::Cmd::get(ctx.clone(), "theme".into()).unwrap(),
+      Some(json!("light"))
+    );
+    assert_eq!(super::Cmd::get(ctx, "missing".into()).unwrap(), None);
+  }
+
+  #[tauri_macros::module_command_test(store_set, "store > set")]
+  #[test]
+  fn set() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = invoke_context(dir.path());
+    super::Cmd::set(ctx.clone(), "theme".into(), json!("dark")).unwrap();
+    assert!(super::Cmd::set(ctx.clone(), "theme".into(), json!(1)).is_err());
+    assert_eq!(
+      ctx.window.state::<Store>().get::<String>("theme").unwrap(),
+      Some("dark".into())
+    );
+    assert!(std::fs::read_to_string(dir.path().join("store.json"))
+      .unwrap()
+      .contains("dark"));
+  }
+
+  #[tauri_macros::module_command_test(store_remove, "store > remove")]
+  #[test]
+  fn remove() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = invoke_context(dir.path());
+    ctx.window.state::<Store>().set("token", "abc").unwrap();
+    assert!(super::Cmd::remove(ctx.clone(), "token".into()).unwrap());
+    assert!(!super::Cmd::remove(ctx.clone(), "token".into()).unwrap());
+    assert!(!ctx.window.state::<Store>().has("token"));
+  }
+
+  #[tauri_macros::module_command_test(store_get, "store > get")]
+  #[test]
+  fn has() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = invoke_context(dir.path());
+    assert!(super::Cmd::has(ctx.clone(), "theme".into()).unwrap());
+    assert!(!super::Cmd::has(ctx, "token".into()).unwrap());
+  }
+
+  #[tauri_macros::module_command_test(store_get, "store > get")]
+  #[test]
+  fn entries() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = invoke_context(dir.path());
+    ctx.window.state::<Store>().set("token", "abc").unwrap();
+    assert_eq!(
+      JsonValue::Object(super::Cmd::entries(ctx).unwrap()),
+      json!({ "theme": "light", "token": "abc" })
+    );
+  }
+}
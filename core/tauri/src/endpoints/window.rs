@@ -9,8 +9,9 @@ use super::{InvokeContext, InvokeResponse};
 use crate::runtime::{webview::WindowBuilder, Dispatch};
 use crate::{
   runtime::{
+    menu::{ContextMenu, CustomMenuItem, Menu, MenuEntry, MenuItem, Submenu},
     window::dpi::{Position, Size},
-    UserAttentionType,
+    FindInPageOptions, UserAttentionType,
   },
   utils::config::WindowConfig,
   CursorIcon, Icon, Manager, Runtime,
@@ -52,6 +53,86 @@ impl From<IconDto> for Icon {
   }
 }
 
+#[cfg(any(window_popup_menu, menu_any))]
+fn default_true() -> bool {
+  true
+}
+
+/// A custom menu item built by the frontend.
+#[cfg(any(window_popup_menu, menu_any))]
+#[derive(Deserialize)]
+pub struct CustomMenuItemDto {
+  id: String,
+  title: String,
+  #[serde(default = "default_true")]
+  enabled: bool,
+  #[serde(default)]
+  selected: bool,
+  accelerator: Option<String>,
+}
+
+#[cfg(any(window_popup_menu, menu_any))]
+impl From<CustomMenuItemDto> for CustomMenuItem {
+  fn from(dto: CustomMenuItemDto) -> Self {
+    let mut item = CustomMenuItem::new(dto.id, dto.title);
+    if !dto.enabled {
+      item = item.disabled();
+    }
+    if dto.selected {
+      item = item.selected();
+    }
+    if let Some(accelerator) = dto.accelerator {
+      item = item.accelerator(accelerator);
+    }
+    item
+  }
+}
+
+/// A menu entry built by the frontend.
+#[cfg(any(window_popup_menu, menu_any))]
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum MenuEntryDto {
+  Submenu {
+    title: String,
+    #[serde(default = "default_true")]
+    enabled: bool,
+    items: Vec<MenuEntryDto>,
+  },
+  CustomItem(CustomMenuItemDto),
+  NativeItem(NativeMenuItemDto),
+}
+
+/// The native menu items the frontend can use.
+#[cfg(any(window_popup_menu, menu_any))]
+#[derive(Deserialize)]
+pub enum NativeMenuItemDto {
+  Separator,
+}
+
+#[cfg(any(window_popup_menu, menu_any))]
+impl From<MenuEntryDto> for MenuEntry {
+  fn from(entry: MenuEntryDto) -> Self {
+    match entry {
+      MenuEntryDto::Submenu {
+        title,
+        enabled,
+        items,
+      } => {
+        let mut menu = Menu::new();
+        menu.items = items.into_iter().map(Into::into).collect();
+        let mut submenu = Submenu::new(title, menu);
+        submenu.enabled = enabled;
+        Self::Submenu(submenu)
+      }
+      MenuEntryDto::CustomItem(item) => Self::CustomItem(item.into()),
+      MenuEntryDto::NativeItem(NativeMenuItemDto::Separator) => {
+        Self::NativeItem(MenuItem::Separator)
+      }
+    }
+  }
+}
+
 /// Window management API descriptor.
 #[derive(Deserialize)]
 #[serde(tag = "type", content = "payload", rename_all = "camelCase")]
@@ -69,6 +150,8 @@ pub enum WindowManagerCmd {
   IsResizable,
   IsVisible,
   Title,
+  Url,
+  Zoom,
   CurrentMonitor,
   PrimaryMonitor,
   AvailableMonitors,
@@ -137,6 +220,33 @@ pub enum WindowManagerCmd {
   StartDragging,
   #[cfg(window_print)]
   Print,
+  #[cfg(window_navigate)]
+  Navigate(String),
+  #[cfg(window_reload)]
+  Reload,
+  #[cfg(window_go_back)]
+  GoBack,
+  #[cfg(window_go_forward)]
+  GoForward,
+  #[cfg(window_set_zoom)]
+  SetZoom(f64),
+  #[cfg(window_find_in_page)]
+  FindInPage {
+    text: String,
+    #[serde(default)]
+    options: FindInPageOptions,
+  },
+  #[cfg(window_find_in_page)]
+  FindNext,
+  #[cfg(window_find_in_page)]
+  FindPrevious,
+  #[cfg(window_find_in_page)]
+  ClearFind,
+  #[cfg(window_popup_menu)]
+  PopupMenu {
+    items: Vec<MenuEntryDto>,
+    position: Option<Position>,
+  },
   // internals
   #[cfg(all(window_maximize, window_unmaximize))]
   #[serde(rename = "__toggleMaximize")]
@@ -187,6 +297,15 @@ pub fn into_allowlist_error(variant: &str) -> crate::Error {
     }
     "startDragging" => crate::Error::ApiNotAllowlisted("window > startDragging".to_string()),
     "print" => crate::Error::ApiNotAllowlisted("window > print".to_string()),
+    "navigate" => crate::Error::ApiNotAllowlisted("window > navigate".to_string()),
+    "reload" => crate::Error::ApiNotAllowlisted("window > reload".to_string()),
+    "goBack" => crate::Error::ApiNotAllowlisted("window > goBack".to_string()),
+    "goForward" => crate::Error::ApiNotAllowlisted("window > goForward".to_string()),
+    "setZoom" => crate::Error::ApiNotAllowlisted("window > setZoom".to_string()),
+    "findInPage" | "findNext" | "findPrevious" | "clearFind" => {
+      crate::Error::ApiNotAllowlisted("window > findInPage".to_string())
+    }
+    "popupMenu" => crate::Error::ApiNotAllowlisted("window > popupMenu".to_string()),
     "__toggleMaximize" => {
       crate::Error::ApiNotAllowlisted("window > maximize and window > unmaximize".to_string())
     }
@@ -257,6 +376,8 @@ impl Cmd {
       WindowManagerCmd::IsResizable => return Ok(window.is_resizable()?.into()),
       WindowManagerCmd::IsVisible => return Ok(window.is_visible()?.into()),
       WindowManagerCmd::Title => return Ok(window.title()?.into()),
+      WindowManagerCmd::Url => return Ok(window.url()?.into()),
+      WindowManagerCmd::Zoom => return Ok(window.zoom()?.into()),
       WindowManagerCmd::CurrentMonitor => return Ok(window.current_monitor()?.into()),
       WindowManagerCmd::PrimaryMonitor => return Ok(window.primary_monitor()?.into()),
       WindowManagerCmd::AvailableMonitors => return Ok(window.available_monitors()?.into()),
@@ -331,6 +452,38 @@ impl Cmd {
       WindowManagerCmd::StartDragging => window.start_dragging()?,
       #[cfg(window_print)]
       WindowManagerCmd::Print => window.print()?,
+      // relative URLs are resolved against the current page, like an `href` would
+      #[cfg(window_navigate)]
+      WindowManagerCmd::Navigate(url) => {
+        let url = window.url()?.join(&url).map_err(crate::Error::InvalidUrl)?;
+        // the URL is loaded through the DOM location, which would run `javascript:` URLs
+        if !matches!(url.scheme(), "http" | "https" | "tauri" | "asset") {
+          return Err(crate::Error::UrlNotAllowed(url));
+        }
+        window.navigate(url)?
+      }
+      #[cfg(window_reload)]
+      WindowManagerCmd::Reload => window.reload()?,
+      #[cfg(window_go_back)]
+      WindowManagerCmd::GoBack => window.go_back()?,
+      #[cfg(window_go_forward)]
+      WindowManagerCmd::GoForward => window.go_forward()?,
+      #[cfg(window_set_zoom)]
+      WindowManagerCmd::SetZoom(scale_factor) => window.set_zoom(scale_factor)?,
+      #[cfg(window_find_in_page)]
+      WindowManagerCmd::FindInPage { text, options } => window.find_in_page(text, options)?,
+      #[cfg(window_find_in_page)]
+      WindowManagerCmd::FindNext => window.find_next()?,
+      #[cfg(window_find_in_page)]
+      WindowManagerCmd::FindPrevious => window.find_previous()?,
+      #[cfg(window_find_in_page)]
+      WindowManagerCmd::ClearFind => window.clear_find()?,
+      #[cfg(window_popup_menu)]
+      WindowManagerCmd::PopupMenu { items, position } => {
+        let mut menu = ContextMenu::new();
+        menu.items = items.into_iter().map(Into::into).collect();
+        window.popup_menu(menu, position)?
+      }
       // internals
       #[cfg(all(window_maximize, window_unmaximize))]
       WindowManagerCmd::InternalToggleMaximize => {
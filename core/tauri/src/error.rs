@@ -46,6 +46,12 @@ pub enum Error {
   /// Failed to send message to webview.
   #[error("failed to send message to the webview")]
   FailedToSendMessage,
+  /// System tray not found.
+  #[error("system tray not found: {0}")]
+  TrayNotFound(String),
+  /// Menu item not found.
+  #[error("menu item not found: {0}")]
+  MenuItemNotFound(String),
   /// Embedded asset not found.
   #[error("asset not found: {0}")]
   AssetNotFound(String),
@@ -131,6 +137,17 @@ pub enum Error {
   /// The Window's raw handle is invalid for the platform.
   #[error("Unexpected `raw_window_handle` for the current platform")]
   InvalidWindowHandle,
+  /// The zoom level is not a finite number greater than zero.
+  #[error("invalid zoom level {0}: it must be a finite number greater than zero")]
+  InvalidZoom(f64),
+  /// Another instance of the app is running and the launch was forwarded to it, see [`crate::Builder::single_instance`].
+  #[cfg(all(desktop, feature = "single-instance"))]
+  #[error("another instance of the app is running, the launch was forwarded to it")]
+  ForwardedToRunningInstance,
+  /// The value doesn't have the JSON type of the key default in the store.
+  #[cfg(feature = "store")]
+  #[error("the value of `{0}` must have the type of its default in the store")]
+  StoreValueType(String),
 }
 
 pub(crate) fn into_anyhow<T: std::fmt::Display>(err: T) -> anyhow::Error {
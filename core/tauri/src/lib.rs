@@ -26,12 +26,19 @@
 //! - **native-tls-vendored**: Compile and statically link to a vendored copy of OpenSSL (applies to the default HTTP client).
 //! - **reqwest-native-tls-vendored**: Compile and statically link to a vendored copy of OpenSSL (applies to the `reqwest` HTTP client).
 //! - **process-command-api**: Enables the [`api::process::Command`] APIs.
+//! - **process-pty**: Enables spawning [`api::process::Command`]s in a pseudo terminal with [`api::process::Command::pty`].
 //! - **global-shortcut**: Enables the global shortcut APIs.
 //! - **clipboard**: Enables the clipboard APIs.
+//! - **single-instance**: Enables [`Builder::single_instance`] to forward the launches of the app to the running instance.
+//! - **autostart**: Enables the [`api::autostart`] module.
+//! - **logging**: Enables the [`logging`] module, a logger writing to the app log directory.
+//! - **store**: Enables the [`store`] module, a persistent key-value store managed as state.
 //! - **process-relaunch-dangerous-allow-symlink-macos**: Allows the [`api::process::current_binary`] function to allow symlinks on macOS (this is dangerous, see the Security section in the documentation website).
 //! - **dialog**: Enables the [`api::dialog`] module.
 //! - **notification**: Enables the [`api::notification`] module.
 //! - **fs-extract-api**: Enabled the `tauri::api::file::Extract` API.
+//! - **fs-archive-api**: Enables the `tauri::api::file::Compress` API and deflate support for zip archives.
+//! - **secrets-api**: Enables the [`api::secrets`] module.
 //! - **cli**: Enables usage of `clap` for CLI argument parsing. Enabled by default if the `cli` config is defined on the `tauri.conf.json` file.
 //! - **system-tray**: Enables application system tray API. Enabled by default if the `systemTray` config is defined on the `tauri.conf.json` file.
 //! - **macos-private-api**: Enables features only available in **macOS**'s private APIs, currently the `transparent` window functionality and the `fullScreenEnabled` preference setting to `true`. Enabled by default if the `tauri > macosPrivateApi` config flag is set to `true` on the `tauri.conf.json` file.
@@ -55,12 +62,23 @@
 //! - **clipboard-all**: Enables all [Clipboard APIs](https://tauri.app/en/docs/api/js/modules/clipboard/).
 //! - **clipboard-read-text**: Enables the [`readText` API](https://tauri.app/en/docs/api/js/modules/clipboard/#readtext).
 //! - **clipboard-write-text**: Enables the [`writeText` API](https://tauri.app/en/docs/api/js/modules/clipboard/#writetext).
+//! - **clipboard-write-image**: Enables the [`writeImage` API](https://tauri.app/en/docs/api/js/modules/clipboard/#writeimage).
+//! - **clipboard-read-image**: Enables the [`readImage` API](https://tauri.app/en/docs/api/js/modules/clipboard/#readimage).
+//! - **clipboard-write-html**: Enables the [`writeHtml` API](https://tauri.app/en/docs/api/js/modules/clipboard/#writehtml).
+//! - **clipboard-read-html**: Enables the [`readHtml` API](https://tauri.app/en/docs/api/js/modules/clipboard/#readhtml).
+//! - **clipboard-write-files**: Enables the [`writeFiles` API](https://tauri.app/en/docs/api/js/modules/clipboard/#writefiles).
+//! - **clipboard-read-files**: Enables the [`readFiles` API](https://tauri.app/en/docs/api/js/modules/clipboard/#readfiles).
+//! - **clipboard-write-data**: Enables the [`writeData` API](https://tauri.app/en/docs/api/js/modules/clipboard/#writedata).
+//! - **clipboard-read-data**: Enables the [`readData`](https://tauri.app/en/docs/api/js/modules/clipboard/#readdata) and [`availableFormats`](https://tauri.app/en/docs/api/js/modules/clipboard/#availableformats) APIs.
+//! - **clipboard-clear**: Enables the [`clear` API](https://tauri.app/en/docs/api/js/modules/clipboard/#clear).
+//! - **clipboard-watch**: Enables the [`onClipboardChange` API](https://tauri.app/en/docs/api/js/modules/clipboard/#onclipboardchange) and the [`RunEvent::ClipboardChange`] event.
 //!
 //! ### Dialog allowlist
 //!
 //! - **dialog-all**: Enables all [Dialog APIs](https://tauri.app/en/docs/api/js/modules/dialog).
 //! - **dialog-ask**: Enables the [`ask` API](https://tauri.app/en/docs/api/js/modules/dialog#ask).
 //! - **dialog-confirm**: Enables the [`confirm` API](https://tauri.app/en/docs/api/js/modules/dialog#confirm).
+//! - **dialog-custom**: Enables the [`customDialog`](https://tauri.app/en/docs/api/js/modules/dialog#customdialog) and [`prompt`](https://tauri.app/en/docs/api/js/modules/dialog#prompt) APIs.
 //! - **dialog-message**: Enables the [`message` API](https://tauri.app/en/docs/api/js/modules/dialog#message).
 //! - **dialog-open**: Enables the [`open` API](https://tauri.app/en/docs/api/js/modules/dialog#open).
 //! - **dialog-save**: Enables the [`save` API](https://tauri.app/en/docs/api/js/modules/dialog#save).
@@ -116,6 +134,7 @@
 //! - **shell-execute**: Enables [executing arbitrary programs](https://tauri.app/en/docs/api/js/classes/shell.Command#constructor).
 //! - **shell-sidecar**: Enables [executing a `sidecar` program](https://tauri.app/en/docs/api/js/classes/shell.Command#sidecar).
 //! - **shell-open**: Enables the [`open` API](https://tauri.app/en/docs/api/js/modules/shell#open).
+//! - **shell-pty**: Enables [spawning programs in a pseudo terminal](https://tauri.app/en/docs/api/js/interfaces/shell.SpawnOptions#pty).
 //!
 //! ### Window allowlist
 //!
@@ -150,12 +169,51 @@
 //! - **window-set-ignore-cursor-events**: Enables the [`setIgnoreCursorEvents` API](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#setignorecursorevents).
 //! - **window-start-dragging**: Enables the [`startDragging` API](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#startdragging).
 //! - **window-print**: Enables the [`print` API](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#print).
+//! - **window-navigate**: Enables the [`navigate` API](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#navigate).
+//! - **window-reload**: Enables the [`reload` API](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#reload).
+//! - **window-go-back**: Enables the [`goBack` API](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#goback).
+//! - **window-go-forward**: Enables the [`goForward` API](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#goforward).
+//! - **window-set-zoom**: Enables the [`setZoom` API](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#setzoom).
+//! - **window-find-in-page**: Enables the [`findInPage`, `findNext`, `findPrevious` and `clearFind` APIs](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#findinpage).
+//! - **window-popup-menu**: Enables the [`popupMenu` API](https://tauri.app/en/docs/api/js/classes/window.WebviewWindow#popupmenu).
 //!
 //! ### App allowlist
 //!
 //! - **app-all**: Enables all [App APIs](https://tauri.app/en/docs/api/js/modules/app).
 //! - **app-show**: Enables the [`show` API](https://tauri.app/en/docs/api/js/modules/app#show).
 //! - **app-hide**: Enables the [`hide` API](https://tauri.app/en/docs/api/js/modules/app#hide).
+//!
+//! ### Archive allowlist
+//!
+//! - **archive-all**: Enables all [Archive APIs](https://tauri.app/en/docs/api/js/modules/archive).
+//! - **archive-extract**: Enables the [`extract` API](https://tauri.app/en/docs/api/js/modules/archive#extract).
+//! - **archive-create**: Enables the [`create` API](https://tauri.app/en/docs/api/js/modules/archive#create).
+//!
+//! ### Menu allowlist
+//!
+//! - **menu-all**: Enables all [Menu APIs](https://tauri.app/en/docs/api/js/modules/menu).
+//! - **menu-set-window-menu**: Enables the [`setWindowMenu` API](https://tauri.app/en/docs/api/js/modules/menu#setwindowmenu).
+//! - **menu-set-tray-menu**: Enables the [`setTrayMenu` API](https://tauri.app/en/docs/api/js/modules/menu#settraymenu).
+//! - **menu-update-item**: Enables the [`updateWindowMenuItem` and `updateTrayMenuItem` APIs](https://tauri.app/en/docs/api/js/modules/menu#updatewindowmenuitem).
+//!
+//! ### Secrets allowlist
+//!
+//! - **secrets-all**: Enables all [Secrets APIs](https://tauri.app/en/docs/api/js/modules/secrets).
+//! - **secrets-get**: Enables the [`getSecret` API](https://tauri.app/en/docs/api/js/modules/secrets#getsecret).
+//! - **secrets-set**: Enables the [`setSecret` API](https://tauri.app/en/docs/api/js/modules/secrets#setsecret).
+//! - **secrets-delete**: Enables the [`deleteSecret` API](https://tauri.app/en/docs/api/js/modules/secrets#deletesecret).
+//!
+//! ### Log allowlist
+//!
+//! - **log-all**: Enables all [Log APIs](https://tauri.app/en/docs/api/js/modules/log).
+//! - **log-write**: Enables the [`trace`, `debug`, `info`, `warn` and `error` APIs](https://tauri.app/en/docs/api/js/modules/log#trace).
+//!
+//! ### Store allowlist
+//!
+//! - **store-all**: Enables all [Store APIs](https://tauri.app/en/docs/api/js/modules/store).
+//! - **store-get**: Enables the [`get`, `has` and `entries` APIs](https://tauri.app/en/docs/api/js/modules/store#get).
+//! - **store-set**: Enables the [`set` API](https://tauri.app/en/docs/api/js/modules/store#set).
+//! - **store-remove**: Enables the [`remove` API](https://tauri.app/en/docs/api/js/modules/store#remove).
 
 #![warn(missing_docs, rust_2018_idioms)]
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
@@ -179,6 +237,9 @@ mod endpoints;
 mod error;
 mod event;
 mod hooks;
+#[cfg(feature = "logging")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "logging")))]
+pub mod logging;
 mod manager;
 mod pattern;
 pub mod plugin;
@@ -187,6 +248,9 @@ use tauri_runtime as runtime;
 /// The allowlist scopes.
 pub mod scope;
 mod state;
+#[cfg(feature = "store")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "store")))]
+pub mod store;
 #[cfg(updater)]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "updater")))]
 pub mod updater;
@@ -225,7 +289,10 @@ pub use {
 pub use {
   self::app::WindowMenuEvent,
   self::event::{Event, EventHandler},
-  self::runtime::menu::{AboutMetadata, CustomMenuItem, Menu, MenuEntry, MenuItem, Submenu},
+  self::runtime::menu::{
+    AboutMetadata, ContextMenu, CustomMenuItem, Menu, MenuEntry, MenuEntryRef, MenuItem,
+    MenuPosition, Submenu,
+  },
   self::window::menu::MenuEvent,
 };
 pub use {
@@ -244,7 +311,7 @@ pub use {
       dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Pixel, Position, Size},
       CursorIcon, FileDropEvent,
     },
-    DeviceEventFilter, RunIteration, UserAttentionType,
+    DeviceEventFilter, FindInPageOptions, RunIteration, UserAttentionType,
   },
   self::state::{State, StateManager},
   self::utils::{
@@ -258,7 +325,7 @@ pub use {
 
 #[cfg(feature = "clipboard")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clipboard")))]
-pub use self::runtime::ClipboardManager;
+pub use self::runtime::{ClipboardFormat, ClipboardImage, ClipboardManager};
 
 #[cfg(all(desktop, feature = "global-shortcut"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "global-shortcut")))]
@@ -319,6 +386,29 @@ pub enum EventLoopMessage {
   #[cfg(updater)]
   #[cfg_attr(doc_cfg, doc(cfg(feature = "updater")))]
   Updater(UpdaterEvent),
+  /// Notification event.
+  #[cfg(all(desktop, feature = "notification"))]
+  #[cfg_attr(doc_cfg, doc(cfg(feature = "notification")))]
+  Notification(api::notification::NotificationEvent),
+  /// Clipboard change event.
+  #[cfg(clipboard_watch)]
+  #[cfg_attr(doc_cfg, doc(cfg(feature = "clipboard-watch")))]
+  ClipboardChange(Vec<String>),
+  /// Launch forwarded by another instance of the app.
+  #[cfg(all(desktop, feature = "single-instance"))]
+  #[cfg_attr(doc_cfg, doc(cfg(feature = "single-instance")))]
+  SecondInstance {
+    /// The arguments of the other instance.
+    args: Vec<String>,
+    /// The working directory of the other instance.
+    cwd: std::path::PathBuf,
+    /// The CLI matches of the arguments.
+    #[cfg(cli)]
+    matches: Option<api::cli::Matches>,
+  },
+  /// The app was opened with deep link URLs.
+  #[cfg(desktop)]
+  Opened(Vec<url::Url>),
 }
 
 /// The webview runtime interface. A wrapper around [`runtime::Runtime`] with the proper user event type associated.
@@ -790,6 +880,11 @@ pub trait Manager<R: Runtime>: sealed::ManagerBase<R> {
   fn shell_scope(&self) -> ShellScope {
     self.state::<Scopes>().inner().shell.clone()
   }
+
+  /// Gets the filesystem access grants persisted across app restarts.
+  fn scope_grants(&self) -> ScopeGrants {
+    self.state::<Scopes>().inner().grants.clone()
+  }
 }
 
 /// Prevent implementation details from leaking out of the [`Manager`] trait.
@@ -882,9 +977,12 @@ mod tests {
     // features that look like an allowlist feature, but are not
     let allowed = [
       "fs-extract-api",
+      "fs-archive-api",
+      "secrets-api",
       "http-api",
       "http-multipart",
       "process-command-api",
+      "process-pty",
       "process-relaunch-dangerous-allow-symlink-macos",
       "window-data-url",
     ];
@@ -0,0 +1,378 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! A logger writing the [`log`] records of the app, its webviews and Tauri itself.
+//!
+//! The logger is registered as a plugin, and the records can be written to stdout, stderr
+//! and rotated files in the [app log directory](crate::api::path::app_log_dir).
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use tauri::logging::{LevelFilter, LogTarget, Logger};
+//!
+//! tauri::Builder::default()
+//!   .plugin(
+//!     Logger::new()
+//!       .level(LevelFilter::Info)
+//!       .level_for("tauri", LevelFilter::Warn)
+//!       .targets([LogTarget::Stdout, LogTarget::LogDir])
+//!       .capture_webview_console(true),
+//!   )
+//!   .setup(|_app| {
+//!     log::info!("app started");
+//!     Ok(())
+//!   });
+//! ```
+
+use std::{
+  fs::{self, File},
+  io::{self, Write},
+  path::{Path, PathBuf},
+  sync::Mutex,
+};
+
+use serde_json::Value as JsonValue;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{
+  plugin::{self, Plugin},
+  AppHandle, Runtime,
+};
+
+pub use log::{Level, LevelFilter};
+
+const DEFAULT_MAX_FILE_SIZE: u64 = 5 * 1024 * 1024;
+const DEFAULT_MAX_FILES: usize = 5;
+
+/// Where the log records are written.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum LogTarget {
+  /// Writes the records to the standard output.
+  Stdout,
+  /// Writes the records to the standard error.
+  Stderr,
+  /// Writes the records to the `{app name}.log` file in the [app log directory](crate::api::path::app_log_dir).
+  LogDir,
+  /// Writes the records to the `{app name}.log` file in the given directory.
+  Folder(PathBuf),
+}
+
+/// The logger plugin. See the [module documentation](self) for an example.
+#[derive(Debug)]
+pub struct Logger {
+  level: LevelFilter,
+  levels: Vec<(String, LevelFilter)>,
+  targets: Vec<LogTarget>,
+  max_file_size: u64,
+  max_files: usize,
+  capture_webview_console: bool,
+}
+
+impl Default for Logger {
+  fn default() -> Self {
+    Self {
+      level: LevelFilter::Info,
+      levels: Vec::new(),
+      targets: vec![LogTarget::Stdout, LogTarget::LogDir],
+      max_file_size: DEFAULT_MAX_FILE_SIZE,
+      max_files: DEFAULT_MAX_FILES,
+      capture_webview_console: false,
+    }
+  }
+}
+
+impl Logger {
+  /// Creates a logger writing the records of level [`Level::Info`] and above
+  /// to the standard output and to the app log directory.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the maximum level of the records that are logged.
+  #[must_use]
+  pub fn level(mut self, level: LevelFilter) -> Self {
+    self.level = level;
+    self
+  }
+
+  /// Sets the maximum level of the records of a target and its children, e.g. `tauri` or `my_app::db`.
+  ///
+  /// The records of the webviews use the `webview:{window label}` target.
+  /// When several filters match a record, the most specific one is used.
+  #[must_use]
+  pub fn level_for(mut self, target: impl Into<String>, level: LevelFilter) -> Self {
+    self.levels.push((target.into(), level));
+    self
+  }
+
+  /// Sets where the records are written. Defaults to [`LogTarget::Stdout`] and [`LogTarget::LogDir`].
+  #[must_use]
+  pub fn targets(mut self, targets: impl IntoIterator<Item = LogTarget>) -> Self {
+    self.targets = targets.into_iter().collect();
+    self
+  }
+
+  /// Sets the size in bytes after which the log file is rotated. Defaults to 5 MiB.
+  #[must_use]
+  pub fn max_file_size(mut self, max_file_size: u64) -> Self {
+    self.max_file_size = max_file_size;
+    self
+  }
+
+  /// Sets how many rotated log files are kept, named `{app name}.1.log` to `{app name}.{max_files}.log`
+  /// from the most recent to the oldest. Defaults to 5.
+  #[must_use]
+  pub fn max_files(mut self, max_files: usize) -> Self {
+    self.max_files = max_files;
+    self
+  }
+
+  /// Whether the `console.*` calls of the webviews are logged. Defaults to `false`.
+  ///
+  /// The calls are sent through the log API, so [`tauri.allowlist.log.write`](https://tauri.app/v1/api/config/#logallowlistconfig.write)
+  /// must be enabled in `tauri.conf.json`.
+  #[must_use]
+  pub fn capture_webview_console(mut self, capture: bool) -> Self {
+    self.capture_webview_console = capture;
+    self
+  }
+}
+
+impl<R: Runtime> Plugin<R> for Logger {
+  fn name(&self) -> &'static str {
+    "log"
+  }
+
+  fn initialize(&mut self, app: &AppHandle<R>, _config: JsonValue) -> plugin::Result<()> {
+    let file_name = format!("{}.log", app.package_info().name);
+    let mut outputs = Vec::new();
+    for target in &self.targets {
+      let output = match target {
+        LogTarget::Stdout => Output::Stdout,
+        LogTarget::Stderr => Output::Stderr,
+        LogTarget::LogDir => {
+          let dir = crate::api::path::app_log_dir(&app.config())
+            .ok_or("failed to resolve the app log directory")?;
+          Output::File(Mutex::new(RotatingFile::open(
+            dir.join(&file_name),
+            self.max_file_size,
+            self.max_files,
+          )?))
+        }
+        LogTarget::Folder(dir) => Output::File(Mutex::new(RotatingFile::open(
+          dir.join(&file_name),
+          self.max_file_size,
+          self.max_files,
+        )?)),
+      };
+      outputs.push(output);
+    }
+
+    let filter = Filter {
+      level: self.level,
+      levels: std::mem::take(&mut self.levels),
+    };
+    let max_level = filter.max_level();
+    log::set_boxed_logger(Box::new(AppLogger { filter, outputs }))?;
+    log::set_max_level(max_level);
+    Ok(())
+  }
+
+  fn initialization_script(&self) -> Option<String> {
+    if self.capture_webview_console {
+      Some(include_str!("../scripts/console.js").into())
+    } else {
+      None
+    }
+  }
+}
+
+struct Filter {
+  level: LevelFilter,
+  levels: Vec<(String, LevelFilter)>,
+}
+
+impl Filter {
+  fn level_for(&self, target: &str) -> LevelFilter {
+    self
+      .levels
+      .iter()
+      .filter(|(prefix, _)| {
+        target
+          .strip_prefix(prefix.as_str())
+          .map(|rest| rest.is_empty() || rest.starts_with(':'))
+          .unwrap_or(false)
+      })
+      .max_by_key(|(prefix, _)| prefix.len())
+      .map(|(_, level)| *level)
+      .unwrap_or(self.level)
+  }
+
+  fn max_level(&self) -> LevelFilter {
+    self
+      .levels
+      .iter()
+      .map(|(_, level)| *level)
+      .fold(self.level, std::cmp::max)
+  }
+}
+
+enum Output {
+  Stdout,
+  Stderr,
+  File(Mutex<RotatingFile>),
+}
+
+struct AppLogger {
+  filter: Filter,
+  outputs: Vec<Output>,
+}
+
+impl log::Log for AppLogger {
+  fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+    metadata.level() <= self.filter.level_for(metadata.target())
+  }
+
+  fn log(&self, record: &log::Record<'_>) {
+    if !self.enabled(record.metadata()) {
+      return;
+    }
+    let timestamp = OffsetDateTime::now_utc()
+      .format(&Rfc3339)
+      .unwrap_or_default();
+    let line = format!(
+      "{} [{}] [{}] {}\n",
+      timestamp,
+      record.level(),
+      record.target(),
+      record.args()
+    );
+    for output in &self.outputs {
+      // there's nowhere to report a failure to write a record
+      let _ = match output {
+        Output::Stdout => io::stdout().lock().write_all(line.as_bytes()),
+        Output::Stderr => io::stderr().lock().write_all(line.as_bytes()),
+        Output::File(file) => file.lock().unwrap().write(line.as_bytes()),
+      };
+    }
+  }
+
+  fn flush(&self) {
+    for output in &self.outputs {
+      let _ = match output {
+        Output::Stdout => io::stdout().flush(),
+        Output::Stderr => io::stderr().flush(),
+        Output::File(file) => file.lock().unwrap().file.flush(),
+      };
+    }
+  }
+}
+
+/// A log file that is moved to `{name}.1.log` when it grows past the maximum size.
+struct RotatingFile {
+  path: PathBuf,
+  file: File,
+  size: u64,
+  max_size: u64,
+  max_files: usize,
+}
+
+impl RotatingFile {
+  fn open(path: PathBuf, max_size: u64, max_files: usize) -> io::Result<Self> {
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+    let file = fs::OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&path)?;
+    let size = file.metadata()?.len();
+    Ok(Self {
+      path,
+      file,
+      size,
+      max_size,
+      max_files,
+    })
+  }
+
+  fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let stem = path
+      .file_stem()
+      .map(|stem| stem.to_string_lossy().into_owned())
+      .unwrap_or_default();
+    path.with_file_name(format!("{stem}.{index}.log"))
+  }
+
+  fn rotate(&mut self) -> io::Result<()> {
+    if self.max_files > 0 {
+      // the oldest file is replaced by the next one
+      for index in (1..self.max_files).rev() {
+        let from = Self::rotated_path(&self.path, index);
+        if from.exists() {
+          fs::rename(&from, Self::rotated_path(&self.path, index + 1))?;
+        }
+      }
+      fs::rename(&self.path, Self::rotated_path(&self.path, 1))?;
+    }
+    self.file = File::create(&self.path)?;
+    self.size = 0;
+    Ok(())
+  }
+
+  fn write(&mut self, bytes: &[u8]) -> io::Result<()> {
+    if self.size > 0 && self.size + bytes.len() as u64 > self.max_size {
+      self.rotate()?;
+    }
+    self.file.write_all(bytes)?;
+    self.size += bytes.len() as u64;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn filters_by_most_specific_target() {
+    let filter = Filter {
+      level: LevelFilter::Info,
+      levels: vec![
+        ("tauri".into(), LevelFilter::Warn),
+        ("tauri::manager".into(), LevelFilter::Trace),
+        ("webview".into(), LevelFilter::Off),
+      ],
+    };
+    assert_eq!(filter.level_for("my_app"), LevelFilter::Info);
+    assert_eq!(filter.level_for("tauri"), LevelFilter::Warn);
+    assert_eq!(filter.level_for("tauri::endpoints"), LevelFilter::Warn);
+    assert_eq!(filter.level_for("tauri::manager"), LevelFilter::Trace);
+    assert_eq!(filter.level_for("tauri_utils"), LevelFilter::Info);
+    assert_eq!(filter.level_for("webview:main"), LevelFilter::Off);
+    assert_eq!(filter.max_level(), LevelFilter::Trace);
+  }
+
+  #[test]
+  fn rotates_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.log");
+    let mut file = RotatingFile::open(path.clone(), 10, 2).unwrap();
+    for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+      file.write(line.as_bytes()).unwrap();
+    }
+    assert_eq!(fs::read_to_string(&path).unwrap(), "fourth\n");
+    assert_eq!(
+      fs::read_to_string(dir.path().join("app.1.log")).unwrap(),
+      "third\n"
+    );
+    assert_eq!(
+      fs::read_to_string(dir.path().join("app.2.log")).unwrap(),
+      "second\n"
+    );
+    assert!(!dir.path().join("app.3.log").exists());
+  }
+}
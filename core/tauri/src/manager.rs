@@ -16,7 +16,6 @@ use serialize_to_javascript::{default_template, DefaultTemplate, Template};
 use url::Url;
 
 use tauri_macros::default_runtime;
-use tauri_utils::debug_eprintln;
 #[cfg(feature = "isolation")]
 use tauri_utils::pattern::isolation::RawIsolationPayload;
 use tauri_utils::{
@@ -71,6 +70,7 @@ const WINDOW_THEME_CHANGED: &str = "tauri://theme-changed";
 const WINDOW_FILE_DROP_EVENT: &str = "tauri://file-drop";
 const WINDOW_FILE_DROP_HOVER_EVENT: &str = "tauri://file-drop-hover";
 const WINDOW_FILE_DROP_CANCELLED_EVENT: &str = "tauri://file-drop-cancelled";
+const WINDOW_URL_CHANGED_EVENT: &str = "tauri://url-changed";
 const MENU_EVENT: &str = "tauri://menu";
 
 pub(crate) const STRINGIFY_IPC_MESSAGE_FN: &str =
@@ -104,8 +104,8 @@ fn set_csp<R: Runtime>(
           CspHash::Style(hash) => {
             acc.style.push(hash.into());
           }
-          _csp_hash => {
-            debug_eprintln!("Unknown CspHash variant encountered: {:?}", _csp_hash);
+          csp_hash => {
+            log::warn!("Unknown CspHash variant encountered: {:?}", csp_hash);
           }
         }
 
@@ -204,6 +204,9 @@ pub struct InnerWindowManager<R: Runtime> {
   windows: Mutex<HashMap<String, Window<R>>>,
   #[cfg(all(desktop, feature = "system-tray"))]
   pub(crate) trays: Mutex<HashMap<String, crate::SystemTrayHandle<R>>>,
+  /// The id of the tray configured with [`crate::Builder::system_tray`].
+  #[cfg(all(desktop, feature = "system-tray"))]
+  pub(crate) default_tray: Mutex<Option<String>>,
   pub(crate) plugins: Mutex<PluginStore<R>>,
   listeners: Listeners,
   pub(crate) state: Arc<StateManager>,
@@ -312,6 +315,8 @@ impl<R: Runtime> WindowManager<R> {
         windows: Mutex::default(),
         #[cfg(all(desktop, feature = "system-tray"))]
         trays: Default::default(),
+        #[cfg(all(desktop, feature = "system-tray"))]
+        default_tray: Default::default(),
         plugins: Mutex::new(plugins),
         listeners: Listeners::default(),
         state: Arc::new(state),
@@ -524,12 +529,12 @@ impl<R: Runtime> WindowManager<R> {
           .to_string();
 
         if let Err(e) = SafePathBuf::new(path.clone().into()) {
-          debug_eprintln!("asset protocol path \"{}\" is not valid: {}", path, e);
+          log::warn!("asset protocol path \"{}\" is not valid: {}", path, e);
           return HttpResponseBuilder::new().status(403).body(Vec::new());
         }
 
         if !asset_scope.is_allowed(&path) {
-          debug_eprintln!("asset protocol not configured to allow the path: {}", path);
+          log::warn!("asset protocol not configured to allow the path: {}", path);
           return HttpResponseBuilder::new().status(403).body(Vec::new());
         }
 
@@ -560,7 +565,7 @@ impl<R: Runtime> WindowManager<R> {
             let mut file = match tokio::fs::File::open(path_.clone()).await {
               Ok(file) => file,
               Err(e) => {
-                debug_eprintln!("Failed to open asset: {}", e);
+                log::error!("Failed to open asset: {}", e);
                 data.status_code = 404;
                 return data;
               }
@@ -573,7 +578,7 @@ impl<R: Runtime> WindowManager<R> {
                 len
               }
               Err(e) => {
-                debug_eprintln!("Failed to read asset metadata: {}", e);
+                log::error!("Failed to read asset metadata: {}", e);
                 data.file.replace(file);
                 data.status_code = 404;
                 return data;
@@ -590,7 +595,7 @@ impl<R: Runtime> WindowManager<R> {
             ) {
               Ok(r) => r,
               Err(e) => {
-                debug_eprintln!("Failed to parse range {}: {:?}", range, e);
+                log::error!("Failed to parse range {}: {:?}", range, e);
                 data.file.replace(file);
                 data.status_code = 400;
                 return data;
@@ -625,7 +630,7 @@ impl<R: Runtime> WindowManager<R> {
               );
 
               if let Err(e) = file.seek(std::io::SeekFrom::Start(range.start)).await {
-                debug_eprintln!("Failed to seek file to {}: {}", range.start, e);
+                log::error!("Failed to seek file to {}: {}", range.start, e);
                 data.file.replace(file);
                 data.status_code = 422;
                 return data;
@@ -637,7 +642,7 @@ impl<R: Runtime> WindowManager<R> {
               data.file.replace(file);
 
               if let Err(e) = r {
-                debug_eprintln!("Failed read file: {}", e);
+                log::error!("Failed read file: {}", e);
                 data.status_code = 422;
                 return data;
               }
@@ -666,7 +671,7 @@ impl<R: Runtime> WindowManager<R> {
               let (status, bytes) = crate::async_runtime::safe_block_on(async move {
                 let mut status = None;
                 if let Err(e) = file.rewind().await {
-                  debug_eprintln!("Failed to rewind file: {}", e);
+                  log::error!("Failed to rewind file: {}", e);
                   status.replace(422);
                   (status, Vec::with_capacity(0))
                 } else {
@@ -674,7 +679,7 @@ impl<R: Runtime> WindowManager<R> {
                   let limit = std::cmp::min(metadata.len(), 8192) as usize + 1;
                   let mut bytes = Vec::with_capacity(limit);
                   if let Err(e) = file.take(8192).read_to_end(&mut bytes).await {
-                    debug_eprintln!("Failed read file: {}", e);
+                    log::error!("Failed read file: {}", e);
                     status.replace(422);
                   }
                   (status, bytes)
@@ -699,7 +704,7 @@ impl<R: Runtime> WindowManager<R> {
               response.mimetype(&mime_type).body(data)
             }
             Err(e) => {
-              debug_eprintln!("Failed to read file: {}", e);
+              log::error!("Failed to read file: {}", e);
               response.status(404).body(Vec::new())
             }
           }
@@ -772,6 +777,7 @@ impl<R: Runtime> WindowManager<R> {
           Ok(json) => request = json,
           Err(e) => {
             let error: crate::Error = e.into();
+            log::error!("failed to decrypt the IPC message: {}", error);
             let _ = window.eval(&format!(
               r#"console.error({})"#,
               JsonValue::String(error.to_string())
@@ -787,6 +793,7 @@ impl<R: Runtime> WindowManager<R> {
         }
         Err(e) => {
           let error: crate::Error = e.into();
+          log::error!("failed to parse the IPC message: {}", error);
           let _ = window.eval(&format!(
             r#"console.error({})"#,
             JsonValue::String(error.to_string())
@@ -817,14 +824,14 @@ impl<R: Runtime> WindowManager<R> {
     let asset_response = assets
       .get(&path.as_str().into())
       .or_else(|| {
-        eprintln!("Asset `{path}` not found; fallback to {path}.html");
+        log::debug!("Asset `{path}` not found; fallback to {path}.html");
         let fallback = format!("{}.html", path.as_str()).into();
         let asset = assets.get(&fallback);
         asset_path = fallback;
         asset
       })
       .or_else(|| {
-        debug_eprintln!(
+        log::debug!(
           "Asset `{}` not found; fallback to {}/index.html",
           path,
           path
@@ -835,7 +842,7 @@ impl<R: Runtime> WindowManager<R> {
         asset
       })
       .or_else(|| {
-        debug_eprintln!("Asset `{}` not found; fallback to index.html", path);
+        log::debug!("Asset `{}` not found; fallback to index.html", path);
         let fallback = AssetKey::from("index.html");
         let asset = assets.get(&fallback);
         asset_path = fallback;
@@ -873,7 +880,7 @@ impl<R: Runtime> WindowManager<R> {
         })
       }
       Err(e) => {
-        debug_eprintln!("{:?}", e); // TODO log::error!
+        log::error!("{}", e);
         Err(Box::new(e))
       }
     }
@@ -1387,6 +1394,15 @@ impl<R: Runtime> WindowManager<R> {
     self.inner.trays.lock().unwrap().insert(id, tray);
   }
 
+  pub fn default_tray(&self) -> Option<crate::SystemTrayHandle<R>> {
+    let id = self.inner.default_tray.lock().unwrap().clone()?;
+    self.get_tray(&id)
+  }
+
+  pub fn set_default_tray(&self, id: String) {
+    self.inner.default_tray.lock().unwrap().replace(id);
+  }
+
   pub fn get_tray_by_runtime_id(&self, id: u16) -> Option<(String, crate::SystemTrayHandle<R>)> {
     let trays = self.inner.trays.lock().unwrap();
     let iter = trays.iter();
@@ -1460,6 +1476,7 @@ fn on_window_event<R: Runtime>(
       _ => unimplemented!(),
     },
     WindowEvent::ThemeChanged(theme) => window.emit(WINDOW_THEME_CHANGED, theme.to_string())?,
+    WindowEvent::UrlChanged(url) => window.emit(WINDOW_URL_CHANGED_EVENT, url.as_str())?,
   }
   Ok(())
 }
@@ -16,6 +16,7 @@ use tauri_utils::{
 };
 use uuid::Uuid;
 
+use super::ScopeGrant;
 use crate::api::path::parse as parse_path;
 
 /// Scope change event.
@@ -29,12 +30,21 @@ pub enum Event {
 
 type EventListener = Box<dyn Fn(&Event) + Send>;
 
+/// A runtime change to the allowed paths, reported to the persisted scope grants.
+pub(crate) enum GrantChange<'a> {
+  Allowed(ScopeGrant),
+  Revoked(&'a Path),
+}
+
+type GrantRecorder = Box<dyn Fn(GrantChange<'_>) -> crate::Result<()> + Send>;
+
 /// Scope for filesystem access.
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct Scope {
   allowed_patterns: Arc<Mutex<HashSet<Pattern>>>,
   forbidden_patterns: Arc<Mutex<HashSet<Pattern>>>,
   event_listeners: Arc<Mutex<HashMap<Uuid, EventListener>>>,
+  grant_recorder: Arc<Mutex<Option<GrantRecorder>>>,
 }
 
 impl fmt::Debug for Scope {
@@ -82,6 +92,24 @@ fn push_pattern<P: AsRef<Path>, F: Fn(&str) -> Result<Pattern, glob::PatternErro
   Ok(())
 }
 
+fn remove_pattern<P: AsRef<Path>, F: Fn(&str) -> Result<Pattern, glob::PatternError>>(
+  list: &mut HashSet<Pattern>,
+  pattern: P,
+  f: F,
+) -> crate::Result<()> {
+  let path: PathBuf = pattern.as_ref().components().collect();
+  list.remove(&f(&path.to_string_lossy())?);
+  #[cfg(windows)]
+  {
+    if let Ok(p) = std::fs::canonicalize(&path) {
+      list.remove(&f(&p.to_string_lossy())?);
+    } else {
+      list.remove(&f(&format!("\\\\?\\{}", path.display()))?);
+    }
+  }
+  Ok(())
+}
+
 impl Scope {
   /// Creates a new scope from a `FsAllowlistScope` configuration.
   pub(crate) fn for_fs_api(
@@ -110,6 +138,7 @@ impl Scope {
       allowed_patterns: Arc::new(Mutex::new(allowed_patterns)),
       forbidden_patterns: Arc::new(Mutex::new(forbidden_patterns)),
       event_listeners: Default::default(),
+      grant_recorder: Default::default(),
     })
   }
 
@@ -138,6 +167,20 @@ impl Scope {
     }
   }
 
+  pub(crate) fn set_grant_recorder<F: Fn(GrantChange<'_>) -> crate::Result<()> + Send + 'static>(
+    &self,
+    f: F,
+  ) {
+    self.grant_recorder.lock().unwrap().replace(Box::new(f));
+  }
+
+  fn record(&self, change: GrantChange<'_>) -> crate::Result<()> {
+    match &*self.grant_recorder.lock().unwrap() {
+      Some(recorder) => recorder(change),
+      None => Ok(()),
+    }
+  }
+
   /// Extend the allowed patterns with the given directory.
   ///
   /// After this function has been called, the frontend will be able to use the Tauri API to read
@@ -155,7 +198,10 @@ impl Scope {
       })?;
     }
     self.trigger(Event::PathAllowed(path.to_path_buf()));
-    Ok(())
+    self.record(GrantChange::Allowed(ScopeGrant::Directory {
+      path: path.to_path_buf(),
+      recursive,
+    }))
   }
 
   /// Extend the allowed patterns with the given file path.
@@ -169,7 +215,37 @@ impl Scope {
       escaped_pattern,
     )?;
     self.trigger(Event::PathAllowed(path.to_path_buf()));
-    Ok(())
+    self.record(GrantChange::Allowed(ScopeGrant::File {
+      path: path.to_path_buf(),
+    }))
+  }
+
+  /// Removes the patterns added by [`Self::allow_directory`] for the given directory.
+  ///
+  /// Patterns allowing the directory through other means, such as a parent directory or the configuration, are kept.
+  pub fn revoke_directory<P: AsRef<Path>>(&self, path: P, recursive: bool) -> crate::Result<()> {
+    let path = path.as_ref();
+    {
+      let mut list = self.allowed_patterns.lock().unwrap();
+      remove_pattern(&mut list, path, escaped_pattern)?;
+      remove_pattern(&mut list, path, |p| {
+        escaped_pattern_with(p, if recursive { "**" } else { "*" })
+      })?;
+    }
+    self.record(GrantChange::Revoked(path))
+  }
+
+  /// Removes the pattern added by [`Self::allow_file`] for the given file path.
+  ///
+  /// Patterns allowing the file through other means, such as its directory or the configuration, are kept.
+  pub fn revoke_file<P: AsRef<Path>>(&self, path: P) -> crate::Result<()> {
+    let path = path.as_ref();
+    remove_pattern(
+      &mut self.allowed_patterns.lock().unwrap(),
+      path,
+      escaped_pattern,
+    )?;
+    self.record(GrantChange::Revoked(path))
   }
 
   /// Set the given directory path to be forbidden by this scope.
@@ -271,6 +347,7 @@ mod tests {
       allowed_patterns: Default::default(),
       forbidden_patterns: Default::default(),
       event_listeners: Default::default(),
+      grant_recorder: Default::default(),
     }
   }
 
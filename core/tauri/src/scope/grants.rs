@@ -0,0 +1,452 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+  fs,
+  io::Write,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+};
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::{fs::GrantChange, FsScope};
+
+/// The name of the file storing the persisted grants, relative to the app data directory.
+pub(crate) const GRANTS_FILE_NAME: &str = ".scope-grants.json";
+/// The name of the file storing the key signing the persisted grants, relative to the app local data directory.
+pub(crate) const GRANTS_KEY_FILE_NAME: &str = ".scope-grants.key";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A filesystem access grant.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Grant {
+  /// Access to a single file.
+  File {
+    /// The file path.
+    path: PathBuf,
+  },
+  /// Access to a directory and its files.
+  Directory {
+    /// The directory path.
+    path: PathBuf,
+    /// Whether subdirectories are accessible too.
+    recursive: bool,
+  },
+}
+
+impl Grant {
+  /// The granted path.
+  pub fn path(&self) -> &Path {
+    match self {
+      Self::File { path } | Self::Directory { path, .. } => path,
+    }
+  }
+
+  fn apply(&self, scope: &FsScope) -> crate::Result<()> {
+    match self {
+      Self::File { path } => scope.allow_file(path),
+      Self::Directory { path, recursive } => scope.allow_directory(path, *recursive),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Record {
+  grant: Grant,
+  /// Whether the grant also applies to the asset protocol scope.
+  asset_protocol: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignedRecords {
+  records: Vec<Record>,
+  signature: String,
+}
+
+#[derive(Debug)]
+struct Store {
+  file: Option<PathBuf>,
+  key_file: Option<PathBuf>,
+  records: Mutex<Vec<Record>>,
+}
+
+impl Store {
+  fn read(&self) -> Option<Vec<Record>> {
+    let contents = fs::read(self.file.as_ref()?).ok()?;
+    let signed: SignedRecords = serde_json::from_slice(&contents).ok()?;
+    let key = fs::read(self.key_file.as_ref()?).ok()?;
+    let mut mac = HmacSha256::new_from_slice(&key).ok()?;
+    mac.update(&serde_json::to_vec(&signed.records).ok()?);
+    mac
+      .verify_slice(&decode_hex(&signed.signature)?)
+      .ok()
+      .map(|_| signed.records)
+  }
+
+  fn key(&self, key_file: &Path) -> crate::Result<Vec<u8>> {
+    if let Ok(key) = fs::read(key_file) {
+      return Ok(key);
+    }
+    if let Some(parent) = key_file.parent() {
+      fs::create_dir_all(parent)?;
+    }
+    let key = rand::random::<[u8; 32]>().to_vec();
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::OpenOptionsExt;
+      options.mode(0o600);
+    }
+    std::io::Write::write_all(&mut options.open(key_file)?, &key)?;
+    Ok(key)
+  }
+
+  /// Writes the records to a temporary file and moves it to the grants file,
+  /// so the grants are not lost if the app exits while writing them.
+  fn save(&self, records: &[Record]) -> crate::Result<()> {
+    if let (Some(file), Some(key_file)) = (&self.file, &self.key_file) {
+      let key = self.key(key_file)?;
+      let serialized = serde_json::to_vec(records).map_err(crate::Error::Json)?;
+      let mut mac = HmacSha256::new_from_slice(&key).expect("HMAC accepts keys of any size");
+      mac.update(&serialized);
+      let signed = SignedRecords {
+        records: records.to_vec(),
+        signature: encode_hex(&mac.finalize().into_bytes()),
+      };
+      if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+      }
+      let temp_file = temp_file(file);
+      let mut temp = fs::File::create(&temp_file)?;
+      temp.write_all(&serde_json::to_vec(&signed).map_err(crate::Error::Json)?)?;
+      temp.sync_all()?;
+      fs::rename(&temp_file, file)?;
+    }
+    Ok(())
+  }
+
+  fn record(&self, change: GrantChange<'_>) -> crate::Result<()> {
+    let mut records = self.records.lock().unwrap();
+    match change {
+      GrantChange::Allowed(grant) => {
+        // the change comes from the filesystem scope, so it replaces any asset protocol grant for the path
+        records.retain(|r| r.grant.path() != grant.path());
+        records.push(Record {
+          grant,
+          asset_protocol: false,
+        });
+      }
+      GrantChange::Revoked(path) => {
+        let len = records.len();
+        records.retain(|r| r.grant.path() != path);
+        if records.len() == len {
+          return Ok(());
+        }
+      }
+    }
+    self.save(&records)
+  }
+}
+
+/// Filesystem access grants persisted across app restarts.
+///
+/// Grants added with [`Self::allow`] are applied to the filesystem and asset protocol scopes
+/// and restored on them when the app is restarted, until they are revoked with [`Self::revoke`].
+/// When [`persistScope`](https://tauri.app/v1/api/config/#fsallowlistconfig.persistscope) is enabled,
+/// the paths allowed at runtime with [`FsScope::allow_directory`] and [`FsScope::allow_file`] on the filesystem scope are persisted too.
+///
+/// The grants are stored in the app data directory and signed with a key generated on the first run,
+/// so the file is discarded if it is edited by hand.
+/// This does not protect against a process that can read the key from the app local data directory.
+#[derive(Debug, Clone)]
+pub struct Grants {
+  store: Arc<Store>,
+  fs: FsScope,
+  asset_protocol: Option<FsScope>,
+}
+
+impl Grants {
+  /// Loads the grants stored in `file` and applies them to the given scopes.
+  ///
+  /// The grants file and its key are forbidden on the scopes, so the webview can't read or replace them.
+  /// A missing file, or a file whose signature doesn't match the key stored in `key_file`, is treated as an empty list.
+  /// If `record_fs_scope` is `true`, subsequent changes to the filesystem scope are persisted.
+  pub(crate) fn load(
+    file: Option<PathBuf>,
+    key_file: Option<PathBuf>,
+    fs: FsScope,
+    asset_protocol: Option<FsScope>,
+    record_fs_scope: bool,
+  ) -> crate::Result<Self> {
+    for scope in std::iter::once(&fs).chain(&asset_protocol) {
+      for path in file.iter().chain(&key_file) {
+        scope.forbid_file(path)?;
+      }
+      if let Some(file) = &file {
+        scope.forbid_file(temp_file(file))?;
+      }
+    }
+
+    let mut store = Store {
+      file,
+      key_file,
+      records: Default::default(),
+    };
+    let records = store.read().unwrap_or_default();
+
+    for record in &records {
+      record.grant.apply(&fs)?;
+      if let (true, Some(scope)) = (record.asset_protocol, &asset_protocol) {
+        record.grant.apply(scope)?;
+      }
+    }
+    store.records = Mutex::new(records);
+
+    let store = Arc::new(store);
+    if record_fs_scope {
+      let store_ = store.clone();
+      fs.set_grant_recorder(move |change| store_.record(change));
+    }
+
+    Ok(Self {
+      store,
+      fs,
+      asset_protocol,
+    })
+  }
+
+  /// The list of persisted grants.
+  pub fn list(&self) -> Vec<Grant> {
+    self
+      .store
+      .records
+      .lock()
+      .unwrap()
+      .iter()
+      .map(|r| r.grant.clone())
+      .collect()
+  }
+
+  /// Applies the grant to the filesystem and asset protocol scopes and persists it.
+  ///
+  /// The asset protocol scope is only granted access when it exists, and the grant is restored on it only in that case.
+  /// A grant replaces any previously persisted grant for the same path.
+  pub fn allow(&self, grant: Grant) -> crate::Result<()> {
+    grant.apply(&self.fs)?;
+    let asset_protocol = match &self.asset_protocol {
+      Some(scope) => {
+        grant.apply(scope)?;
+        true
+      }
+      None => false,
+    };
+    let mut records = self.store.records.lock().unwrap();
+    records.retain(|r| r.grant.path() != grant.path());
+    records.push(Record {
+      grant,
+      asset_protocol,
+    });
+    self.store.save(&records)
+  }
+
+  /// Revokes the access to the given path on the filesystem and asset protocol scopes,
+  /// whether it was granted persistently or not.
+  ///
+  /// Access granted by the allowlist configuration for a different pattern is not affected.
+  pub fn revoke<P: AsRef<Path>>(&self, path: P) -> crate::Result<()> {
+    let path = path.as_ref();
+    for scope in std::iter::once(&self.fs).chain(&self.asset_protocol) {
+      scope.revoke_file(path)?;
+      scope.revoke_directory(path, false)?;
+      scope.revoke_directory(path, true)?;
+    }
+    self.store.record(GrantChange::Revoked(path))
+  }
+}
+
+/// The temporary file the grants are written to before replacing `file`.
+fn temp_file(file: &Path) -> PathBuf {
+  file.with_extension("json.tmp")
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+  bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+  if s.len() % 2 != 0 {
+    return None;
+  }
+  (0..s.len())
+    .step_by(2)
+    .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Grant, Grants};
+  use crate::scope::FsScope;
+
+  #[test]
+  fn persists_and_revokes() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("grants.json");
+    let key_file = dir.path().join("grants.key");
+    let granted = dir.path().join("project");
+    let load = |scope: FsScope, record: bool| {
+      Grants::load(
+        Some(file.clone()),
+        Some(key_file.clone()),
+        scope,
+        None,
+        record,
+      )
+      .unwrap()
+    };
+
+    let scope = FsScope::default();
+    let grants = load(scope.clone(), false);
+    grants
+      .allow(Grant::Directory {
+        path: granted.clone(),
+        recursive: true,
+      })
+      .unwrap();
+    assert!(scope.is_allowed(granted.join("src/main.rs")));
+
+    // restored on a new scope
+    let restored_scope = FsScope::default();
+    let restored = load(restored_scope.clone(), false);
+    assert_eq!(restored.list(), grants.list());
+    assert!(restored_scope.is_allowed(granted.join("src/main.rs")));
+
+    restored.revoke(&granted).unwrap();
+    assert!(!restored_scope.is_allowed(granted.join("src/main.rs")));
+    assert!(restored.list().is_empty());
+    assert!(load(FsScope::default(), false).list().is_empty());
+  }
+
+  #[test]
+  fn records_fs_scope_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("grants.json");
+    let key_file = dir.path().join("grants.key");
+    let granted = dir.path().join("notes.txt");
+    let load = |scope: FsScope| {
+      Grants::load(
+        Some(file.clone()),
+        Some(key_file.clone()),
+        scope,
+        None,
+        true,
+      )
+      .unwrap()
+    };
+
+    let scope = FsScope::default();
+    let grants = load(scope.clone());
+    scope.allow_file(&granted).unwrap();
+    assert_eq!(
+      grants.list(),
+      vec![Grant::File {
+        path: granted.clone()
+      }]
+    );
+
+    let restored_scope = FsScope::default();
+    load(restored_scope.clone());
+    assert!(restored_scope.is_allowed(&granted));
+
+    restored_scope.revoke_file(&granted).unwrap();
+    assert!(load(FsScope::default()).list().is_empty());
+  }
+
+  #[test]
+  fn restores_asset_protocol_grants() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("grants.json");
+    let key_file = dir.path().join("grants.key");
+    let granted = dir.path().join("image.png");
+    let load = |scope: FsScope, asset_protocol: Option<FsScope>| {
+      Grants::load(
+        Some(file.clone()),
+        Some(key_file.clone()),
+        scope,
+        asset_protocol,
+        true,
+      )
+      .unwrap()
+    };
+
+    // granted without an asset protocol scope, so it is not restored on one
+    load(FsScope::default(), None)
+      .allow(Grant::File {
+        path: granted.clone(),
+      })
+      .unwrap();
+    let asset_scope = FsScope::default();
+    let grants = load(FsScope::default(), Some(asset_scope.clone()));
+    assert!(!asset_scope.is_allowed(&granted));
+
+    grants
+      .allow(Grant::File {
+        path: granted.clone(),
+      })
+      .unwrap();
+    let asset_scope = FsScope::default();
+    load(FsScope::default(), Some(asset_scope.clone()));
+    assert!(asset_scope.is_allowed(&granted));
+
+    // a later filesystem scope change replaces the asset protocol grant
+    let scope = FsScope::default();
+    load(scope.clone(), Some(FsScope::default()));
+    scope.allow_file(&granted).unwrap();
+    let asset_scope = FsScope::default();
+    load(FsScope::default(), Some(asset_scope.clone()));
+    assert!(!asset_scope.is_allowed(&granted));
+  }
+
+  #[test]
+  fn discards_tampered_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("grants.json");
+    let key_file = dir.path().join("grants.key");
+    let load = |scope: FsScope| {
+      Grants::load(
+        Some(file.clone()),
+        Some(key_file.clone()),
+        scope,
+        None,
+        false,
+      )
+      .unwrap()
+    };
+
+    load(FsScope::default())
+      .allow(Grant::Directory {
+        path: dir.path().join("project"),
+        recursive: false,
+      })
+      .unwrap();
+
+    let contents = std::fs::read_to_string(&file).unwrap();
+    std::fs::write(
+      &file,
+      contents.replace("\"recursive\":false", "\"recursive\":true"),
+    )
+    .unwrap();
+
+    let scope = FsScope::default();
+    assert!(load(scope.clone()).list().is_empty());
+    assert!(!scope.is_allowed(dir.path().join("project/file")));
+  }
+}
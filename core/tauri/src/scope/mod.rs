@@ -3,12 +3,15 @@
 // SPDX-License-Identifier: MIT
 
 mod fs;
+mod grants;
 mod http;
 #[cfg(shell_scope)]
 mod shell;
 
 pub use self::http::Scope as HttpScope;
 pub use fs::{Event as FsScopeEvent, Pattern as GlobPattern, Scope as FsScope};
+pub use grants::{Grant as ScopeGrant, Grants as ScopeGrants};
+pub(crate) use grants::{GRANTS_FILE_NAME, GRANTS_KEY_FILE_NAME};
 #[cfg(shell_scope)]
 pub use shell::{
   ExecuteArgs, Scope as ShellScope, ScopeAllowedArg as ShellScopeAllowedArg,
@@ -25,6 +28,7 @@ pub(crate) struct Scopes {
   pub http: HttpScope,
   #[cfg(shell_scope)]
   pub shell: ShellScope,
+  pub grants: ScopeGrants,
 }
 
 impl Scopes {
@@ -0,0 +1,350 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! A persistent key-value store shared by the app and its windows.
+//!
+//! The store is managed as state, so it can be accessed with [`Manager::state`](crate::Manager::state)
+//! or the [`State`](crate::State) command argument, and with the `store` module of the JS API.
+//! The values are saved in the `store.json` file of the [app config directory](crate::api::path::app_config_dir)
+//! on every change, and each change is emitted to the windows and to the global listeners
+//! as the [`STORE_CHANGE_EVENT`] event with a [`StoreChange`] payload.
+//!
+//! # Defaults
+//!
+//! The `plugins > store > defaults` object of the `tauri.conf.json` file defines the values returned for
+//! the keys that are not set. A value can only be replaced by a value of the same JSON type as its default,
+//! unless the default is `null`.
+//!
+//! ```json
+//! {
+//!   "plugins": {
+//!     "store": {
+//!       "defaults": {
+//!         "theme": "light",
+//!         "fontSize": 14
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use tauri::store::Store;
+//!
+//! #[tauri::command]
+//! fn set_theme(store: tauri::State<'_, Store>, theme: String) -> Result<(), String> {
+//!   store.set("theme", theme).map_err(|e| e.to_string())
+//! }
+//!
+//! tauri::Builder::default()
+//!   .invoke_handler(tauri::generate_handler![set_theme])
+//!   .setup(|app| {
+//!     let font_size: Option<u32> = app.state::<Store>().get("fontSize")?;
+//!     println!("font size: {:?}", font_size);
+//!     Ok(())
+//!   });
+//! ```
+
+use std::{
+  fs::{self, File},
+  io::Write,
+  path::PathBuf,
+  sync::Mutex,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value as JsonValue};
+
+use crate::{utils::config::Config, AppHandle, Manager, Runtime};
+
+/// The event emitted when a value of the store changes.
+pub const STORE_CHANGE_EVENT: &str = "tauri://store-change";
+
+/// The name of the file storing the values, relative to the app config directory.
+const STORE_FILE_NAME: &str = "store.json";
+
+/// The payload of the [`STORE_CHANGE_EVENT`] event.
+#[derive(Debug, Clone, Serialize)]
+pub struct StoreChange {
+  /// The key of the value.
+  pub key: String,
+  /// The new value, or its default if it was removed.
+  pub value: Option<JsonValue>,
+}
+
+type OnChange = Box<dyn Fn(&StoreChange) + Send + Sync>;
+
+/// The persistent key-value store. See the [module documentation](self) for more information.
+pub struct Store {
+  file: Option<PathBuf>,
+  defaults: Map<String, JsonValue>,
+  values: Mutex<Map<String, JsonValue>>,
+  on_change: OnChange,
+}
+
+impl std::fmt::Debug for Store {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Store")
+      .field("file", &self.file)
+      .field("defaults", &self.defaults)
+      .field("values", &self.values)
+      .finish()
+  }
+}
+
+impl Store {
+  /// Loads the store of the app, emitting its changes with the given app handle.
+  pub(crate) fn for_app<R: Runtime>(app: &AppHandle<R>) -> Self {
+    let app = app.clone();
+    let config = app.config();
+    Self::load(
+      crate::api::path::app_config_dir(&config).map(|dir| dir.join(STORE_FILE_NAME)),
+      defaults(&config),
+      Box::new(move |change| {
+        if let Ok(payload) = serde_json::to_string(change) {
+          app.trigger_global(STORE_CHANGE_EVENT, Some(payload));
+        }
+        let _ = app.emit_all(STORE_CHANGE_EVENT, change.clone());
+      }),
+    )
+  }
+
+  /// Loads the values stored in `file`. A missing or invalid file is treated as empty.
+  pub(crate) fn load(
+    file: Option<PathBuf>,
+    defaults: Map<String, JsonValue>,
+    on_change: OnChange,
+  ) -> Self {
+    let values = file
+      .as_ref()
+      .and_then(|file| fs::read(file).ok())
+      .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+      .unwrap_or_default();
+    Self {
+      file,
+      defaults,
+      values: Mutex::new(values),
+      on_change,
+    }
+  }
+
+  /// Gets the value of a key, or its default if it is not set.
+  pub fn get<T: DeserializeOwned>(&self, key: &str) -> crate::Result<Option<T>> {
+    self
+      .get_value(key)
+      .map(|value| serde_json::from_value(value).map_err(crate::Error::Json))
+      .transpose()
+  }
+
+  /// Gets the JSON value of a key, or its default if it is not set.
+  pub fn get_value(&self, key: &str) -> Option<JsonValue> {
+    self
+      .values
+      .lock()
+      .unwrap()
+      .get(key)
+      .or_else(|| self.defaults.get(key))
+      .cloned()
+  }
+
+  /// Whether the key is set or has a default.
+  pub fn has(&self, key: &str) -> bool {
+    self.values.lock().unwrap().contains_key(key) || self.defaults.contains_key(key)
+  }
+
+  /// All the values of the store, including the defaults of the keys that are not set.
+  pub fn entries(&self) -> Map<String, JsonValue> {
+    let mut entries = self.defaults.clone();
+    entries.extend(self.values.lock().unwrap().clone());
+    entries
+  }
+
+  /// Sets the value of a key and saves the store.
+  ///
+  /// Returns an error if the value doesn't have the JSON type of the key default.
+  /// A `null` default accepts a value of any type.
+  /// The previous value is kept if the store can't be saved.
+  pub fn set<T: Serialize>(&self, key: impl Into<String>, value: T) -> crate::Result<()> {
+    let key = key.into();
+    let value = serde_json::to_value(value).map_err(crate::Error::Json)?;
+    if let Some(default) = self.defaults.get(&key).filter(|default| !default.is_null()) {
+      if std::mem::discriminant(default) != std::mem::discriminant(&value) {
+        return Err(crate::Error::StoreValueType(key));
+      }
+    }
+    {
+      let mut values = self.values.lock().unwrap();
+      let previous = values.insert(key.clone(), value.clone());
+      if let Err(e) = self.save(&values) {
+        match previous {
+          Some(previous) => values.insert(key, previous),
+          None => values.remove(&key),
+        };
+        return Err(e);
+      }
+    }
+    (self.on_change)(&StoreChange {
+      key,
+      value: Some(value),
+    });
+    Ok(())
+  }
+
+  /// Removes the value of a key and saves the store. Its default is returned by [`Self::get`] afterwards.
+  ///
+  /// Returns whether the key was set. The value is kept if the store can't be saved.
+  pub fn remove(&self, key: &str) -> crate::Result<bool> {
+    {
+      let mut values = self.values.lock().unwrap();
+      let previous = match values.remove(key) {
+        Some(previous) => previous,
+        None => return Ok(false),
+      };
+      if let Err(e) = self.save(&values) {
+        values.insert(key.into(), previous);
+        return Err(e);
+      }
+    }
+    (self.on_change)(&StoreChange {
+      key: key.into(),
+      value: self.defaults.get(key).cloned(),
+    });
+    Ok(true)
+  }
+
+  /// Writes the values to a temporary file and moves it to the store file,
+  /// so the store is not corrupted if the app exits while writing it.
+  fn save(&self, values: &Map<String, JsonValue>) -> crate::Result<()> {
+    if let Some(file) = &self.file {
+      if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+      }
+      let temp_file = file.with_extension("json.tmp");
+      let mut temp = File::create(&temp_file)?;
+      temp.write_all(&serde_json::to_vec_pretty(values).map_err(crate::Error::Json)?)?;
+      temp.sync_all()?;
+      fs::rename(&temp_file, file)?;
+    }
+    Ok(())
+  }
+}
+
+/// The `plugins > store > defaults` object of the config.
+fn defaults(config: &Config) -> Map<String, JsonValue> {
+  config
+    .plugins
+    .0
+    .get("store")
+    .and_then(|store| store.get("defaults"))
+    .and_then(|defaults| defaults.as_object())
+    .cloned()
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::{Arc, Mutex};
+
+  type Changes = Arc<Mutex<Vec<(String, Option<JsonValue>)>>>;
+
+  fn open(file: PathBuf) -> (Store, Changes) {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_ = changes.clone();
+    let mut config = Config::default();
+    config.plugins.0.insert(
+      "store".into(),
+      serde_json::json!({ "defaults": { "theme": "light", "fontSize": 14, "user": null } }),
+    );
+    let store = Store::load(
+      Some(file),
+      defaults(&config),
+      Box::new(move |change| {
+        changes_
+          .lock()
+          .unwrap()
+          .push((change.key.clone(), change.value.clone()))
+      }),
+    );
+    (store, changes)
+  }
+
+  #[test]
+  fn falls_back_to_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    let (store, _) = open(dir.path().join(STORE_FILE_NAME));
+    assert_eq!(store.get::<String>("theme").unwrap(), Some("light".into()));
+    assert_eq!(store.get::<u32>("fontSize").unwrap(), Some(14));
+    assert_eq!(store.get::<bool>("missing").unwrap(), None);
+    assert!(store.has("theme"));
+    assert!(matches!(
+      store.set("fontSize", "large"),
+      Err(crate::Error::StoreValueType(key)) if key == "fontSize"
+    ));
+  }
+
+  #[test]
+  fn null_default_accepts_any_type() {
+    let dir = tempfile::tempdir().unwrap();
+    let (store, _) = open(dir.path().join(STORE_FILE_NAME));
+    assert!(store.has("user"));
+    store.set("user", "tauri").unwrap();
+    assert_eq!(store.get::<String>("user").unwrap(), Some("tauri".into()));
+    store.set("user", 1).unwrap();
+    assert_eq!(store.get::<u32>("user").unwrap(), Some(1));
+  }
+
+  #[test]
+  fn persists_and_emits_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("config").join(STORE_FILE_NAME);
+    let (store, changes) = open(file.clone());
+    store.set("theme", "dark").unwrap();
+    store.set("token", vec![1, 2]).unwrap();
+    assert!(store.remove("token").unwrap());
+    assert!(!store.remove("token").unwrap());
+    assert_eq!(
+      *changes.lock().unwrap(),
+      vec![
+        ("theme".into(), Some("dark".into())),
+        ("token".into(), Some(serde_json::json!([1, 2]))),
+        ("token".into(), None),
+      ]
+    );
+
+    let (store, _) = open(file.clone());
+    assert_eq!(store.get::<String>("theme").unwrap(), Some("dark".into()));
+    assert!(!store.has("token"));
+    assert_eq!(
+      store.entries(),
+      serde_json::json!({ "theme": "dark", "fontSize": 14, "user": null })
+        .as_object()
+        .cloned()
+        .unwrap()
+    );
+    assert!(!file.with_extension("json.tmp").exists());
+  }
+
+  #[test]
+  fn keeps_values_if_saving_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let parent = dir.path().join("config");
+    let (store, changes) = open(parent.join(STORE_FILE_NAME));
+    store.set("theme", "dark").unwrap();
+    store.set("token", "abc").unwrap();
+
+    // the store directory can't be created over a regular file
+    fs::remove_dir_all(&parent).unwrap();
+    fs::write(&parent, "").unwrap();
+    assert!(store.set("theme", "blue").is_err());
+    assert!(store.set("user", "tauri").is_err());
+    assert!(store.remove("token").is_err());
+    assert_eq!(store.get::<String>("theme").unwrap(), Some("dark".into()));
+    assert_eq!(store.get_value("user"), Some(JsonValue::Null));
+    assert_eq!(store.get::<String>("token").unwrap(), Some("abc".into()));
+    assert_eq!(changes.lock().unwrap().len(), 2);
+  }
+}
@@ -5,19 +5,19 @@
 #![allow(dead_code)]
 
 use tauri_runtime::{
-  menu::{Menu, MenuUpdate},
+  menu::{ContextMenu, Menu, MenuEntry, MenuHash, MenuStructureUpdate, MenuUpdate},
   monitor::Monitor,
   webview::{WindowBuilder, WindowBuilderBase},
   window::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     CursorIcon, DetachedWindow, MenuEvent, PendingWindow, WindowEvent,
   },
-  DeviceEventFilter, Dispatch, EventLoopProxy, Icon, Result, RunEvent, Runtime, RuntimeHandle,
-  UserAttentionType, UserEvent,
+  DeviceEventFilter, Dispatch, EventLoopProxy, FindInPageOptions, Icon, Result, RunEvent, Runtime,
+  RuntimeHandle, UserAttentionType, UserEvent,
 };
 #[cfg(all(desktop, feature = "system-tray"))]
 use tauri_runtime::{
-  menu::{SystemTrayMenu, TrayHandle},
+  menu::{SystemTrayMenu, SystemTrayMenuEntry, TrayHandle},
   SystemTray, SystemTrayEvent, TrayId,
 };
 #[cfg(target_os = "macos")]
@@ -39,14 +39,16 @@ type ShortcutMap = HashMap<String, Box<dyn Fn() + Send + 'static>>;
 #[derive(Clone)]
 pub struct RuntimeContext {
   shortcuts: Arc<Mutex<ShortcutMap>>,
-  clipboard: Arc<Mutex<Option<String>>>,
+  #[cfg(feature = "clipboard")]
+  clipboard: Arc<Mutex<Option<MockClipboardContent>>>,
 }
 
 impl fmt::Debug for RuntimeContext {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    f.debug_struct("RuntimeContext")
-      .field("clipboard", &self.clipboard)
-      .finish()
+    let mut d = f.debug_struct("RuntimeContext");
+    #[cfg(feature = "clipboard")]
+    d.field("clipboard", &self.clipboard);
+    d.finish()
   }
 }
 
@@ -157,17 +159,136 @@ impl tauri_runtime::GlobalShortcutManager for MockGlobalShortcutManager {
 #[derive(Debug, Clone)]
 pub struct MockClipboardManager {
   context: RuntimeContext,
+  change_listener: tauri_runtime::clipboard::ClipboardChangeListener,
+  poll_watcher: Arc<Mutex<Option<tauri_runtime::clipboard::ClipboardPollWatcher>>>,
+}
+
+#[cfg(feature = "clipboard")]
+#[derive(Debug, Clone)]
+enum MockClipboardContent {
+  Text(String),
+  Image(tauri_runtime::ClipboardImage),
+  Html(String, Option<String>),
+  Files(Vec<std::path::PathBuf>),
+  Formats(Vec<tauri_runtime::ClipboardFormat>),
+}
+
+#[cfg(feature = "clipboard")]
+impl MockClipboardManager {
+  fn write(&self, content: MockClipboardContent) -> Result<()> {
+    self.context.clipboard.lock().unwrap().replace(content);
+    Ok(())
+  }
+
+  fn read(&self) -> Option<MockClipboardContent> {
+    self.context.clipboard.lock().unwrap().clone()
+  }
 }
 
 #[cfg(feature = "clipboard")]
 impl tauri_runtime::ClipboardManager for MockClipboardManager {
   fn write_text<T: Into<String>>(&mut self, text: T) -> Result<()> {
-    self.context.clipboard.lock().unwrap().replace(text.into());
-    Ok(())
+    self.write(MockClipboardContent::Text(text.into()))
   }
 
   fn read_text(&self) -> Result<Option<String>> {
-    Ok(self.context.clipboard.lock().unwrap().clone())
+    Ok(match self.read() {
+      Some(MockClipboardContent::Text(text)) | Some(MockClipboardContent::Html(_, Some(text))) => {
+        Some(text)
+      }
+      _ => None,
+    })
+  }
+
+  fn write_image(&mut self, image: tauri_runtime::ClipboardImage) -> Result<()> {
+    self.write(MockClipboardContent::Image(image))
+  }
+
+  fn read_image(&self) -> Result<Option<tauri_runtime::ClipboardImage>> {
+    Ok(match self.read() {
+      Some(MockClipboardContent::Image(image)) => Some(image),
+      _ => None,
+    })
+  }
+
+  fn write_html(&mut self, html: String, alt_text: Option<String>) -> Result<()> {
+    self.write(MockClipboardContent::Html(html, alt_text))
+  }
+
+  fn read_html(&self) -> Result<Option<String>> {
+    Ok(match self.read() {
+      Some(MockClipboardContent::Html(html, _)) => Some(html),
+      _ => None,
+    })
+  }
+
+  fn write_files(&mut self, paths: Vec<std::path::PathBuf>) -> Result<()> {
+    self.write(MockClipboardContent::Files(paths))
+  }
+
+  fn read_files(&self) -> Result<Option<Vec<std::path::PathBuf>>> {
+    Ok(match self.read() {
+      Some(MockClipboardContent::Files(paths)) => Some(paths),
+      _ => None,
+    })
+  }
+
+  fn write_formats(&mut self, formats: Vec<tauri_runtime::ClipboardFormat>) -> Result<()> {
+    self.write(MockClipboardContent::Formats(formats))
+  }
+
+  fn read_format(&self, mime_type: String) -> Result<Option<Vec<u8>>> {
+    Ok(match self.read() {
+      Some(MockClipboardContent::Formats(formats)) => formats
+        .into_iter()
+        .find(|format| format.mime_type == mime_type)
+        .map(|format| format.data),
+      _ => None,
+    })
+  }
+
+  fn available_formats(&self) -> Result<Vec<String>> {
+    Ok(match self.read() {
+      Some(MockClipboardContent::Text(_)) => vec!["text/plain".into()],
+      Some(MockClipboardContent::Image(_)) => vec!["image/png".into()],
+      Some(MockClipboardContent::Html(_, alt_text)) => {
+        let mut formats = vec!["text/html".into()];
+        if alt_text.is_some() {
+          formats.push("text/plain".into());
+        }
+        formats
+      }
+      Some(MockClipboardContent::Files(_)) => vec!["text/uri-list".into()],
+      Some(MockClipboardContent::Formats(formats)) => {
+        formats.into_iter().map(|format| format.mime_type).collect()
+      }
+      None => Vec::new(),
+    })
+  }
+
+  fn clear(&mut self) -> Result<()> {
+    self.context.clipboard.lock().unwrap().take();
+    Ok(())
+  }
+
+  fn watch<F: Fn(Vec<String>) + Send + 'static>(&mut self, handler: F) -> Result<()> {
+    self.change_listener.set(Box::new(handler));
+    let mut poll_watcher = self.poll_watcher.lock().unwrap();
+    if poll_watcher.is_none() {
+      poll_watcher.replace(tauri_runtime::clipboard::ClipboardPollWatcher::start(
+        self.clone(),
+        || None,
+        std::time::Duration::from_millis(10),
+        self.change_listener.clone(),
+      ));
+    }
+    Ok(())
+  }
+
+  fn unwatch(&mut self) -> Result<()> {
+    self.change_listener.clear();
+    self.poll_watcher.lock().unwrap().take();
+    Ok(())
   }
 }
 
@@ -337,6 +458,10 @@ impl<T: UserEvent> Dispatch<T> for MockDispatcher {
     todo!()
   }
 
+  fn zoom(&self) -> Result<f64> {
+    Ok(1.0)
+  }
+
   fn scale_factor(&self) -> Result<f64> {
     Ok(1.0)
   }
@@ -434,6 +559,42 @@ impl<T: UserEvent> Dispatch<T> for MockDispatcher {
     Ok(())
   }
 
+  fn navigate(&self, url: url::Url) -> Result<()> {
+    Ok(())
+  }
+
+  fn reload(&self) -> Result<()> {
+    Ok(())
+  }
+
+  fn go_back(&self) -> Result<()> {
+    Ok(())
+  }
+
+  fn go_forward(&self) -> Result<()> {
+    Ok(())
+  }
+
+  fn set_zoom(&self, scale_factor: f64) -> Result<()> {
+    Ok(())
+  }
+
+  fn find_in_page(&self, text: String, options: FindInPageOptions) -> Result<()> {
+    Ok(())
+  }
+
+  fn find_next(&self) -> Result<()> {
+    Ok(())
+  }
+
+  fn find_previous(&self) -> Result<()> {
+    Ok(())
+  }
+
+  fn clear_find(&self) -> Result<()> {
+    Ok(())
+  }
+
   fn request_user_attention(&self, request_type: Option<UserAttentionType>) -> Result<()> {
     Ok(())
   }
@@ -564,6 +725,24 @@ impl<T: UserEvent> Dispatch<T> for MockDispatcher {
   fn update_menu_item(&self, id: u16, update: MenuUpdate) -> Result<()> {
     Ok(())
   }
+
+  fn set_menu(&self, menu: Menu) -> Result<()> {
+    Ok(())
+  }
+
+  fn update_menu_structure(&self, update: MenuStructureUpdate<MenuEntry>) -> Result<Vec<MenuHash>> {
+    Ok(Vec::new())
+  }
+
+  fn popup_menu<F: FnOnce() + Send + 'static>(
+    &self,
+    menu: ContextMenu,
+    position: Option<Position>,
+    on_close: F,
+  ) -> Result<()> {
+    on_close();
+    Ok(())
+  }
 }
 
 #[cfg(all(desktop, feature = "system-tray"))]
@@ -583,6 +762,12 @@ impl TrayHandle for MockTrayHandler {
   fn update_item(&self, id: u16, update: MenuUpdate) -> Result<()> {
     Ok(())
   }
+  fn update_menu_structure(
+    &self,
+    update: MenuStructureUpdate<SystemTrayMenuEntry>,
+  ) -> Result<Vec<MenuHash>> {
+    Ok(Vec::new())
+  }
   #[cfg(target_os = "macos")]
   fn set_icon_as_template(&self, is_template: bool) -> Result<()> {
     Ok(())
@@ -626,6 +811,7 @@ impl MockRuntime {
   fn init() -> Self {
     let context = RuntimeContext {
       shortcuts: Default::default(),
+      #[cfg(feature = "clipboard")]
       clipboard: Default::default(),
     };
     Self {
@@ -636,6 +822,8 @@ impl MockRuntime {
       #[cfg(feature = "clipboard")]
       clipboard_manager: MockClipboardManager {
         context: context.clone(),
+        change_listener: Default::default(),
+        poll_watcher: Default::default(),
       },
       #[cfg(all(desktop, feature = "system-tray"))]
       tray_handler: MockTrayHandler {
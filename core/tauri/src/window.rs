@@ -5,6 +5,8 @@
 //! The Tauri window types and functions.
 
 pub(crate) mod menu;
+#[cfg(desktop)]
+pub(crate) mod state;
 
 pub use menu::{MenuEvent, MenuHandle};
 use url::Url;
@@ -19,14 +21,14 @@ use crate::{
   manager::WindowManager,
   runtime::{
     http::{Request as HttpRequest, Response as HttpResponse},
-    menu::Menu,
+    menu::{ContextMenu, Menu},
     monitor::Monitor as RuntimeMonitor,
     webview::{WebviewAttributes, WindowBuilder as _},
     window::{
       dpi::{PhysicalPosition, PhysicalSize, Position, Size},
       DetachedWindow, JsEventListenerKey, PendingWindow,
     },
-    Dispatch, RuntimeHandle, UserAttentionType,
+    Dispatch, FindInPageOptions, RuntimeHandle, UserAttentionType,
   },
   sealed::ManagerBase,
   sealed::RuntimeOrDispatch,
@@ -42,6 +44,7 @@ use windows::Win32::Foundation::HWND;
 use tauri_macros::default_runtime;
 
 use std::{
+  collections::HashMap,
   fmt,
   hash::{Hash, Hasher},
   path::PathBuf,
@@ -111,6 +114,9 @@ pub struct WindowBuilder<'a, R: Runtime> {
   pub(crate) webview_attributes: WebviewAttributes,
   web_resource_request_handler: Option<Box<WebResourceRequestHandler>>,
   navigation_handler: Option<Box<NavigationHandler>>,
+  /// Whether the window must be shown after restoring its state, if it is restored.
+  #[cfg(desktop)]
+  restore_state: Option<bool>,
 }
 
 impl<'a, R: Runtime> fmt::Debug for WindowBuilder<'a, R> {
@@ -185,6 +191,8 @@ impl<'a, R: Runtime> WindowBuilder<'a, R> {
       webview_attributes: WebviewAttributes::new(url),
       web_resource_request_handler: None,
       navigation_handler: None,
+      #[cfg(desktop)]
+      restore_state: None,
     }
   }
 
@@ -212,6 +220,12 @@ impl<'a, R: Runtime> WindowBuilder<'a, R> {
   ///
   /// [the Webview2 issue]: https://github.com/tauri-apps/wry/issues/583
   pub fn from_config<M: Manager<R>>(manager: &'a M, config: WindowConfig) -> Self {
+    #[allow(unused_mut)]
+    let mut config = config;
+    #[cfg(desktop)]
+    let restore_state = manager
+      .try_state::<state::WindowStateStore>()
+      .and_then(|store| store.prepare(&mut config));
     let runtime = manager.runtime();
     let app_handle = manager.app_handle();
     let url = config.url.clone();
@@ -227,6 +241,8 @@ impl<'a, R: Runtime> WindowBuilder<'a, R> {
       webview_attributes: WebviewAttributes::new(url),
       web_resource_request_handler: None,
       navigation_handler: None,
+      #[cfg(desktop)]
+      restore_state,
     };
 
     if !file_drop_enabled {
@@ -331,6 +347,19 @@ impl<'a, R: Runtime> WindowBuilder<'a, R> {
     }
     .map(|window| self.manager.attach_window(self.app_handle.clone(), window))?;
 
+    #[cfg(desktop)]
+    if let Some(show) = self.restore_state {
+      if let Some(store) = self.app_handle.try_state::<state::WindowStateStore>() {
+        if let Err(e) = store.restore(&window, show) {
+          log::error!(
+            "failed to restore the state of window `{}`: {}",
+            window.label(),
+            e
+          );
+        }
+      }
+    }
+
     self.manager.eval_script_all(format!(
       "window.__TAURI_METADATA__.__windows = {window_labels_array}.map(function (label) {{ return {{ label: label }} }})",
       window_labels_array = serde_json::to_string(&self.manager.labels())?,
@@ -1388,6 +1417,136 @@ impl<R: Runtime> Window<R> {
     self.window.dispatcher.url().map_err(Into::into)
   }
 
+  /// Navigates the webview to the given URL.
+  ///
+  /// The navigation goes through the [`WindowBuilder::on_navigation`] handler,
+  /// so it can still be blocked by it.
+  pub fn navigate(&self, url: Url) -> crate::Result<()> {
+    self.window.dispatcher.navigate(url).map_err(Into::into)
+  }
+
+  /// Reloads the current page of the webview.
+  pub fn reload(&self) -> crate::Result<()> {
+    self.window.dispatcher.reload().map_err(Into::into)
+  }
+
+  /// Navigates back to the previous page of the webview history.
+  pub fn go_back(&self) -> crate::Result<()> {
+    self.window.dispatcher.go_back().map_err(Into::into)
+  }
+
+  /// Navigates forward to the next page of the webview history.
+  pub fn go_forward(&self) -> crate::Result<()> {
+    self.window.dispatcher.go_forward().map_err(Into::into)
+  }
+
+  /// Returns the current zoom level of the webview, where `1.0` is the default size.
+  pub fn zoom(&self) -> crate::Result<f64> {
+    self.window.dispatcher.zoom().map_err(Into::into)
+  }
+
+  /// Sets the zoom level of the webview, where `1.0` is the default size.
+  ///
+  /// Returns [`crate::Error::InvalidZoom`] if `scale_factor` is not a finite number greater than zero.
+  pub fn set_zoom(&self, scale_factor: f64) -> crate::Result<()> {
+    if !scale_factor.is_finite() || scale_factor <= 0.0 {
+      return Err(crate::Error::InvalidZoom(scale_factor));
+    }
+    self
+      .window
+      .dispatcher
+      .set_zoom(scale_factor)
+      .map_err(Into::into)
+  }
+
+  /// Searches the webview content for the given text and selects its first match.
+  ///
+  /// Use [`Self::find_next`] and [`Self::find_previous`] to move through the matches.
+  ///
+  /// The search runs the non-standard `window.find` function of the webview, so:
+  /// - only the text of the main document is searched, not iframes or shadow roots;
+  /// - the match replaces the page selection, and all matches are not highlighted or counted;
+  /// - the search state is lost when the page navigates.
+  pub fn find_in_page<S: Into<String>>(
+    &self,
+    text: S,
+    options: FindInPageOptions,
+  ) -> crate::Result<()> {
+    self
+      .window
+      .dispatcher
+      .find_in_page(text.into(), options)
+      .map_err(Into::into)
+  }
+
+  /// Selects the next match of the current [`Self::find_in_page`] search.
+  pub fn find_next(&self) -> crate::Result<()> {
+    self.window.dispatcher.find_next().map_err(Into::into)
+  }
+
+  /// Selects the previous match of the current [`Self::find_in_page`] search.
+  pub fn find_previous(&self) -> crate::Result<()> {
+    self.window.dispatcher.find_previous().map_err(Into::into)
+  }
+
+  /// Clears the current [`Self::find_in_page`] search and its selection.
+  pub fn clear_find(&self) -> crate::Result<()> {
+    self.window.dispatcher.clear_find().map_err(Into::into)
+  }
+
+  /// Shows a native context menu at the given position, relative to the window's client area.
+  ///
+  /// If `position` is `None`, the menu is shown at the cursor position.
+  /// Item clicks are emitted as menu events, see [`Self::on_menu_event`].
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS:** Unsupported.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::{ContextMenu, CustomMenuItem, LogicalPosition, MenuItem};
+  ///
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     # use tauri::Manager;
+  ///     let window = app.get_window("main").unwrap();
+  ///     window.popup_menu(
+  ///       ContextMenu::new()
+  ///         .add_item(CustomMenuItem::new("copy", "Copy"))
+  ///         .add_native_item(MenuItem::Separator)
+  ///         .add_item(CustomMenuItem::new("inspect", "Inspect")),
+  ///       Some(LogicalPosition::new(100.0, 100.0).into()),
+  ///     )?;
+  ///     Ok(())
+  ///   });
+  /// ```
+  pub fn popup_menu(&self, menu: ContextMenu, position: Option<Position>) -> crate::Result<()> {
+    // only the ids that are not used by the window menu are removed when the popup closes
+    let popup_ids = {
+      let mut popup_ids = HashMap::new();
+      for entry in &menu.items {
+        menu::entry_ids(&mut popup_ids, entry);
+      }
+      let mut ids = self.window.menu_ids.lock().unwrap();
+      popup_ids.retain(|id, _| !ids.contains_key(id));
+      ids.extend(popup_ids.clone());
+      popup_ids
+    };
+    let menu_ids = self.window.menu_ids.clone();
+    self
+      .window
+      .dispatcher
+      .popup_menu(menu, position, move || {
+        let mut ids = menu_ids.lock().unwrap();
+        for id in popup_ids.keys() {
+          ids.remove(id);
+        }
+      })
+      .map_err(Into::into)
+  }
+
   /// Handles this window receiving an [`InvokeMessage`].
   pub fn on_message(self, payload: InvokePayload) -> crate::Result<()> {
     let manager = self.manager.clone();
@@ -1652,4 +1811,16 @@ mod tests {
     crate::test_utils::assert_send::<super::Window>();
     crate::test_utils::assert_sync::<super::Window>();
   }
+
+  #[test]
+  fn set_zoom_rejects_invalid_levels() {
+    let window = crate::test::mock_invoke_context().window;
+    for scale_factor in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+      assert!(matches!(
+        window.set_zoom(scale_factor),
+        Err(crate::Error::InvalidZoom(_))
+      ));
+    }
+    assert!(window.set_zoom(1.5).is_ok());
+  }
 }
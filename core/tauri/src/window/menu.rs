@@ -4,7 +4,10 @@
 
 use crate::{
   runtime::{
-    menu::{MenuHash, MenuId, MenuIdRef, MenuUpdate},
+    menu::{
+      Menu, MenuEntry, MenuEntryRef, MenuHash, MenuId, MenuIdRef, MenuPosition,
+      MenuStructureUpdate, MenuUpdate,
+    },
     Dispatch,
   },
   Runtime,
@@ -17,6 +20,20 @@ use std::{
   sync::{Arc, Mutex},
 };
 
+pub(crate) fn entry_ids(map: &mut HashMap<MenuHash, MenuId>, entry: &MenuEntry) {
+  match entry {
+    MenuEntry::CustomItem(c) => {
+      map.insert(c.id, c.id_str.clone());
+    }
+    MenuEntry::Submenu(s) => {
+      for entry in &s.inner.items {
+        entry_ids(map, entry);
+      }
+    }
+    _ => {}
+  }
+}
+
 /// The window menu event.
 #[derive(Debug, Clone)]
 pub struct MenuEvent {
@@ -66,18 +83,38 @@ impl<R: Runtime> Clone for MenuItemHandle<R> {
 
 impl<R: Runtime> MenuHandle<R> {
   /// Gets a handle to the menu item that has the specified `id`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the menu has no item with the given `id`. See [`Self::try_get_item`].
   pub fn get_item(&self, id: MenuIdRef<'_>) -> MenuItemHandle<R> {
+    self.try_get_item(id).expect("item id not found")
+  }
+
+  /// Gets a handle to the menu item that has the specified `id`, if it exists.
+  pub fn try_get_item(&self, id: MenuIdRef<'_>) -> Option<MenuItemHandle<R>> {
     let ids = self.ids.lock().unwrap();
     let iter = ids.iter();
     for (raw, item_id) in iter {
       if item_id == id {
-        return MenuItemHandle {
+        return Some(MenuItemHandle {
           id: *raw,
           dispatcher: self.dispatcher.clone(),
-        };
+        });
       }
     }
-    panic!("item id not found")
+    None
+  }
+
+  /// Replaces the window menu.
+  pub fn set_menu(&self, menu: Menu) -> crate::Result<()> {
+    let mut ids = HashMap::new();
+    for entry in &menu.items {
+      entry_ids(&mut ids, entry);
+    }
+    self.dispatcher.set_menu(menu)?;
+    *self.ids.lock().unwrap() = ids;
+    Ok(())
   }
 
   /// Shows the menu.
@@ -103,6 +140,73 @@ impl<R: Runtime> MenuHandle<R> {
       self.show()
     }
   }
+
+  /// Inserts a menu entry at the given position.
+  ///
+  /// The ids of the inserted items can be used with [`Self::get_item`] right away.
+  /// Returns an error if the entry referenced by `position` does not exist.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / Windows / macOS**: The whole menu is rebuilt and set again, which can make it flicker.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::{CustomMenuItem, MenuEntryRef, MenuPosition};
+  ///
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     # use tauri::Manager;
+  ///     let window = app.get_window("main").unwrap();
+  ///     window.menu_handle().insert(
+  ///       CustomMenuItem::new("reload", "Reload").into(),
+  ///       MenuPosition::After(MenuEntryRef::item("quit")),
+  ///     )?;
+  ///     Ok(())
+  ///   });
+  /// ```
+  pub fn insert(&self, entry: MenuEntry, position: MenuPosition) -> crate::Result<()> {
+    let mut ids = HashMap::new();
+    entry_ids(&mut ids, &entry);
+    self
+      .dispatcher
+      .update_menu_structure(MenuStructureUpdate::Insert(entry, position))?;
+    self.ids.lock().unwrap().extend(ids);
+    Ok(())
+  }
+
+  /// Moves an existing menu entry to the given position, preserving its id and state.
+  ///
+  /// Returns an error if `entry` or the entry referenced by `position` does not exist.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / Windows / macOS**: The whole menu is rebuilt and set again, which can make it flicker.
+  pub fn move_entry(&self, entry: MenuEntryRef, position: MenuPosition) -> crate::Result<()> {
+    self
+      .dispatcher
+      .update_menu_structure(MenuStructureUpdate::Move(entry, position))?;
+    Ok(())
+  }
+
+  /// Removes an existing menu entry.
+  ///
+  /// Removing a submenu also removes the items nested in it.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / Windows / macOS**: The whole menu is rebuilt and set again, which can make it flicker.
+  pub fn remove(&self, entry: MenuEntryRef) -> crate::Result<()> {
+    let removed = self
+      .dispatcher
+      .update_menu_structure(MenuStructureUpdate::Remove(entry))?;
+    let mut ids = self.ids.lock().unwrap();
+    for id in removed {
+      ids.remove(&id);
+    }
+    Ok(())
+  }
 }
 
 impl<R: Runtime> MenuItemHandle<R> {
@@ -0,0 +1,272 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+  collections::{HashMap, HashSet},
+  fs::{self, File},
+  io::Write,
+  path::PathBuf,
+  sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  runtime::window::dpi::{PhysicalPosition, PhysicalSize},
+  utils::config::WindowConfig,
+  Monitor, Runtime, Window,
+};
+
+/// The name of the file storing the window states, relative to the app config directory.
+pub(crate) const WINDOW_STATE_FILE_NAME: &str = ".window-state.json";
+
+/// The saved geometry of a window.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WindowState {
+  x: i32,
+  y: i32,
+  width: u32,
+  height: u32,
+  maximized: bool,
+  monitor: Option<String>,
+}
+
+impl WindowState {
+  /// Whether the center of the window is on one of the monitors, and it is the monitor the window was on.
+  fn is_on_monitor(&self, monitors: &[Monitor]) -> bool {
+    let center_x = self.x as i64 + self.width as i64 / 2;
+    let center_y = self.y as i64 + self.height as i64 / 2;
+    monitors.iter().any(|monitor| {
+      let position = monitor.position();
+      let size = monitor.size();
+      let contains_center = (position.x as i64..position.x as i64 + size.width as i64)
+        .contains(&center_x)
+        && (position.y as i64..position.y as i64 + size.height as i64).contains(&center_y);
+      let same_monitor = match (&self.monitor, monitor.name()) {
+        (Some(saved), Some(name)) => saved == name,
+        _ => true,
+      };
+      contains_center && same_monitor
+    })
+  }
+}
+
+/// The window states persisted in the app config directory, keyed by window label.
+pub(crate) struct WindowStateStore {
+  file: Option<PathBuf>,
+  states: Mutex<HashMap<String, WindowState>>,
+  /// The labels of the windows whose state is tracked.
+  tracked: Mutex<HashSet<String>>,
+}
+
+impl WindowStateStore {
+  /// Loads the states stored in `file`. A missing or invalid file is treated as empty.
+  pub(crate) fn load(file: Option<PathBuf>) -> Self {
+    let states = file
+      .as_ref()
+      .and_then(|file| fs::read(file).ok())
+      .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+      .unwrap_or_default();
+    Self {
+      file,
+      states: Mutex::new(states),
+      tracked: Default::default(),
+    }
+  }
+
+  /// Prepares the creation of a window from its config.
+  ///
+  /// Returns `None` if the window state is not restored, or whether the window must be shown after restoring its state.
+  /// The window is created hidden when there is a saved state, so it doesn't show up at its initial position.
+  pub(crate) fn prepare(&self, config: &mut WindowConfig) -> Option<bool> {
+    if !config.restore_state {
+      return None;
+    }
+    if config.visible && self.states.lock().unwrap().contains_key(&config.label) {
+      config.visible = false;
+      Some(true)
+    } else {
+      Some(false)
+    }
+  }
+
+  /// Applies the saved state of the window, and tracks its changes.
+  ///
+  /// The window is shown if `show` is `true`, even if its state could not be applied,
+  /// since it was created hidden.
+  pub(crate) fn restore<R: Runtime>(&self, window: &Window<R>, show: bool) -> crate::Result<()> {
+    self
+      .tracked
+      .lock()
+      .unwrap()
+      .insert(window.label().to_string());
+    let restored = self.apply(window);
+    if show {
+      window.show()?;
+    }
+    restored
+  }
+
+  fn apply<R: Runtime>(&self, window: &Window<R>) -> crate::Result<()> {
+    let state = self.states.lock().unwrap().get(window.label()).cloned();
+    if let Some(state) = state {
+      if state.width > 0 && state.height > 0 {
+        window.set_size(PhysicalSize::new(state.width, state.height))?;
+      }
+      if state.is_on_monitor(&window.available_monitors()?) {
+        window.set_position(PhysicalPosition::new(state.x, state.y))?;
+      }
+      if state.maximized {
+        window.maximize()?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Updates the state of the window if it is tracked.
+  pub(crate) fn update<R: Runtime>(&self, window: &Window<R>) -> crate::Result<()> {
+    if !self.tracked.lock().unwrap().contains(window.label()) {
+      return Ok(());
+    }
+    let maximized = window.is_maximized()?;
+    // keep the geometry the window is restored to when it is unmaximized
+    let geometry = if !maximized && !window.is_minimized()? && !window.is_fullscreen()? {
+      Some((
+        window.outer_position()?,
+        window.inner_size()?,
+        window
+          .current_monitor()?
+          .and_then(|monitor| monitor.name().cloned()),
+      ))
+    } else {
+      None
+    };
+
+    let mut states = self.states.lock().unwrap();
+    let state = states.entry(window.label().to_string()).or_default();
+    state.maximized = maximized;
+    if let Some((position, size, monitor)) = geometry {
+      state.x = position.x;
+      state.y = position.y;
+      state.width = size.width;
+      state.height = size.height;
+      state.monitor = monitor;
+    }
+    Ok(())
+  }
+
+  /// Stops tracking the window and saves the states if it was tracked.
+  pub(crate) fn on_window_close(&self, label: &str) -> crate::Result<()> {
+    if self.tracked.lock().unwrap().remove(label) {
+      self.save()
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Saves the states if a window is still tracked.
+  pub(crate) fn on_exit(&self) -> crate::Result<()> {
+    if self.tracked.lock().unwrap().is_empty() {
+      Ok(())
+    } else {
+      self.save()
+    }
+  }
+
+  /// Writes the states to a temporary file and moves it to the app config directory,
+  /// so the states are not corrupted if the app exits while writing them.
+  fn save(&self) -> crate::Result<()> {
+    if let Some(file) = &self.file {
+      if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+      }
+      let temp_file = file.with_extension("json.tmp");
+      let mut temp = File::create(&temp_file)?;
+      temp.write_all(
+        &serde_json::to_vec(&*self.states.lock().unwrap()).map_err(crate::Error::Json)?,
+      )?;
+      temp.sync_all()?;
+      fs::rename(&temp_file, file)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn monitor(name: &str, x: i32, width: u32) -> Monitor {
+    Monitor {
+      name: Some(name.into()),
+      size: PhysicalSize::new(width, 1080),
+      position: PhysicalPosition::new(x, 0),
+      scale_factor: 1.0,
+    }
+  }
+
+  fn state(x: i32, monitor: &str) -> WindowState {
+    WindowState {
+      x,
+      y: 100,
+      width: 800,
+      height: 600,
+      maximized: false,
+      monitor: Some(monitor.into()),
+    }
+  }
+
+  #[test]
+  fn validates_position_against_monitors() {
+    let monitors = [
+      monitor("primary", 0, 1920),
+      monitor("secondary", 1920, 1920),
+    ];
+    assert!(state(100, "primary").is_on_monitor(&monitors));
+    assert!(state(2500, "secondary").is_on_monitor(&monitors));
+    // the window is mostly out of the monitor
+    assert!(!state(1800, "primary").is_on_monitor(&monitors));
+    // the secondary monitor was disconnected
+    assert!(!state(2500, "secondary").is_on_monitor(&monitors[..1]));
+    // another monitor took its place
+    assert!(!state(2500, "secondary")
+      .is_on_monitor(&[monitor("primary", 0, 1920), monitor("other", 1920, 1920)]));
+  }
+
+  #[test]
+  fn persists_states() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("config").join(WINDOW_STATE_FILE_NAME);
+    let store = WindowStateStore::load(Some(file.clone()));
+    store
+      .states
+      .lock()
+      .unwrap()
+      .insert("main".into(), state(100, "primary"));
+    store.tracked.lock().unwrap().insert("main".into());
+    store.on_window_close("main").unwrap();
+
+    let store = WindowStateStore::load(Some(file));
+    assert_eq!(
+      store.states.lock().unwrap().get("main"),
+      Some(&state(100, "primary"))
+    );
+
+    let mut config = WindowConfig {
+      restore_state: true,
+      ..Default::default()
+    };
+    assert_eq!(store.prepare(&mut config), Some(true));
+    assert!(!config.visible);
+    let mut config = WindowConfig {
+      label: "other".into(),
+      restore_state: true,
+      ..Default::default()
+    };
+    assert_eq!(store.prepare(&mut config), Some(false));
+    assert!(config.visible);
+    assert_eq!(store.prepare(&mut WindowConfig::default()), None);
+  }
+}
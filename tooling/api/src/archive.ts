@@ -0,0 +1,160 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/**
+ * Extract and create zip and tar archives.
+ *
+ * This package is also accessible with `window.__TAURI__.archive` when [`build.withGlobalTauri`](https://tauri.app/v1/api/config/#buildconfig.withglobaltauri) in `tauri.conf.json` is set to `true`.
+ *
+ * The APIs must be added to [`tauri.allowlist.archive`](https://tauri.app/v1/api/config/#allowlistconfig.archive) in `tauri.conf.json`:
+ * ```json
+ * {
+ *   "tauri": {
+ *     "allowlist": {
+ *       "archive": {
+ *         "all": true, // enable all archive APIs
+ *         "extract": true,
+ *         "create": true
+ *       }
+ *     }
+ *   }
+ * }
+ * ```
+ * It is recommended to allowlist only the APIs you use for optimal bundle size and security.
+ *
+ * ## Security
+ *
+ * This module shares the [`tauri.allowlist.fs.scope`](https://tauri.app/v1/api/config/#fsallowlistconfig.scope) configuration with the {@link fs} module.
+ * The archive, the destination and every extracted or archived file must be allowed by the scope.
+ *
+ * Entries that would be extracted outside of the destination directory (e.g. `../file`) are rejected,
+ * and the extraction is aborted once the extracted content exceeds the configured maximum size (1 GiB by default).
+ *
+ * @module
+ */
+
+import { invokeTauriCommand } from './helpers/tauri'
+import { transformCallback } from './tauri'
+import type { BaseDirectory } from './fs'
+
+/**
+ * The supported archive formats.
+ *
+ * @since 1.3.0
+ */
+type ArchiveFormat = 'zip' | 'tar' | 'tarGz'
+
+/**
+ * Progress of an archive operation.
+ *
+ * @since 1.3.0
+ */
+interface ArchiveProgress {
+  /** The path that is being extracted or archived. */
+  path: string
+  /** The amount of bytes processed so far. */
+  bytes: number
+}
+
+/**
+ * @since 1.3.0
+ */
+interface ExtractOptions {
+  /** The archive format. Inferred from the archive extension if not provided. */
+  format?: ArchiveFormat
+  /** The base directory of the archive and destination paths. */
+  dir?: BaseDirectory
+  /** The maximum amount of bytes the archive is allowed to be extracted into. Defaults to 1 GiB. */
+  maxSize?: number
+  /** Called before each entry is extracted. */
+  onProgress?: (progress: ArchiveProgress) => void
+}
+
+/**
+ * @since 1.3.0
+ */
+interface CreateOptions {
+  /** The archive format. Inferred from the destination extension if not provided. */
+  format?: ArchiveFormat
+  /** The base directory of the destination and source paths. */
+  dir?: BaseDirectory
+  /** Called before each file is added to the archive. */
+  onProgress?: (progress: ArchiveProgress) => void
+}
+
+/**
+ * Extracts an archive into the given directory.
+ * @example
+ * ```typescript
+ * import { extract } from '@tauri-apps/api/archive';
+ * import { BaseDirectory } from '@tauri-apps/api/fs';
+ * // Extract the `$APPDATA/plugins.zip` archive into the `$APPDATA/plugins` directory
+ * await extract('plugins.zip', 'plugins', {
+ *   dir: BaseDirectory.AppData,
+ *   onProgress: ({ path }) => console.log(`extracting ${path}`)
+ * });
+ * ```
+ *
+ * @param archive Path to the archive.
+ * @param destination Path to the directory where the archive is extracted.
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function extract(
+  archive: string,
+  destination: string,
+  options: ExtractOptions = {}
+): Promise<void> {
+  const { onProgress, ...extractOptions } = options
+  return invokeTauriCommand({
+    __tauriModule: 'Archive',
+    message: {
+      cmd: 'extract',
+      archive,
+      destination,
+      onProgressFn: onProgress ? transformCallback(onProgress) : null,
+      options: extractOptions
+    }
+  })
+}
+
+/**
+ * Creates an archive from the given files and directories.
+ * Directories are added recursively.
+ * @example
+ * ```typescript
+ * import { create } from '@tauri-apps/api/archive';
+ * import { BaseDirectory } from '@tauri-apps/api/fs';
+ * // Archive the `$APPDATA/logs` directory into `$APPDATA/logs.tar.gz`
+ * await create('logs.tar.gz', ['logs'], { dir: BaseDirectory.AppData });
+ * ```
+ *
+ * @param destination Path to the archive that is created.
+ * @param paths Files and directories to add to the archive.
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function create(
+  destination: string,
+  paths: string[],
+  options: CreateOptions = {}
+): Promise<void> {
+  const { onProgress, ...createOptions } = options
+  return invokeTauriCommand({
+    __tauriModule: 'Archive',
+    message: {
+      cmd: 'create',
+      destination,
+      paths,
+      onProgressFn: onProgress ? transformCallback(onProgress) : null,
+      options: createOptions
+    }
+  })
+}
+
+export type { ArchiveFormat, ArchiveProgress, ExtractOptions, CreateOptions }
+
+export { extract, create }
@@ -15,7 +15,17 @@
  *       "clipboard": {
  *         "all": true, // enable all Clipboard APIs
  *         "writeText": true,
- *         "readText": true
+ *         "readText": true,
+ *         "writeImage": true,
+ *         "readImage": true,
+ *         "writeHtml": true,
+ *         "readHtml": true,
+ *         "writeFiles": true,
+ *         "readFiles": true,
+ *         "writeData": true,
+ *         "readData": true,
+ *         "clear": true,
+ *         "watch": true
  *       }
  *     }
  *   }
@@ -27,6 +37,8 @@
  */
 
 import { invokeTauriCommand } from './helpers/tauri'
+import { listen, TauriEvent } from './event'
+import type { UnlistenFn } from './event'
 
 /**
  * Writes plain text to the clipboard.
@@ -72,4 +84,317 @@ async function readText(): Promise<string | null> {
   })
 }
 
-export { writeText, readText }
+/**
+ * A clipboard payload identified by its MIME type.
+ *
+ * @since 1.3.0
+ */
+interface ClipboardData {
+  /** The MIME type of the payload, e.g. `application/json`. */
+  mimeType: string
+  /** The payload bytes. */
+  data: Uint8Array | number[]
+}
+
+/**
+ * Writes a PNG image to the clipboard.
+ * @example
+ * ```typescript
+ * import { writeImage } from '@tauri-apps/api/clipboard';
+ * const png = new Uint8Array(await (await fetch('/screenshot.png')).arrayBuffer());
+ * await writeImage(png);
+ * ```
+ *
+ * @param png The PNG image bytes.
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function writeImage(png: Uint8Array | number[]): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'writeImage',
+      data: Array.from(png)
+    }
+  })
+}
+
+/**
+ * Gets the clipboard content as a PNG image.
+ * @example
+ * ```typescript
+ * import { readImage } from '@tauri-apps/api/clipboard';
+ * const png = await readImage();
+ * if (png) {
+ *   const url = URL.createObjectURL(new Blob([png], { type: 'image/png' }));
+ * }
+ * ```
+ *
+ * @returns A promise resolving to the PNG image bytes, or `null` if the clipboard does not contain an image.
+ *
+ * @since 1.3.0
+ */
+async function readImage(): Promise<Uint8Array | null> {
+  return invokeTauriCommand<number[] | null>({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'readImage',
+      data: null
+    }
+  }).then((png) => (png ? Uint8Array.from(png) : null))
+}
+
+/**
+ * Writes HTML to the clipboard.
+ * @example
+ * ```typescript
+ * import { writeHtml } from '@tauri-apps/api/clipboard';
+ * await writeHtml('<b>Tauri</b> is awesome!', 'Tauri is awesome!');
+ * ```
+ *
+ * @param html The HTML content.
+ * @param altText The plain text alternative used by apps that do not support HTML.
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function writeHtml(html: string, altText?: string): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'writeHtml',
+      data: {
+        html,
+        altText
+      }
+    }
+  })
+}
+
+/**
+ * Gets the clipboard content as HTML.
+ *
+ * @example
+ * ```typescript
+ * import { readHtml } from '@tauri-apps/api/clipboard';
+ * const html = await readHtml();
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function readHtml(): Promise<string | null> {
+  return invokeTauriCommand({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'readHtml',
+      data: null
+    }
+  })
+}
+
+/**
+ * Writes a list of file paths to the clipboard, so they can be pasted in a file manager.
+ * The paths must be allowed by the `fs` scope.
+ *
+ * @example
+ * ```typescript
+ * import { writeFiles } from '@tauri-apps/api/clipboard';
+ * await writeFiles(['/home/tauri/report.pdf']);
+ * ```
+ *
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function writeFiles(paths: string[]): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'writeFiles',
+      data: paths
+    }
+  })
+}
+
+/**
+ * Gets the clipboard content as a list of file paths.
+ * Paths that are not allowed by the `fs` scope are left out.
+ *
+ * @example
+ * ```typescript
+ * import { readFiles } from '@tauri-apps/api/clipboard';
+ * const paths = await readFiles();
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function readFiles(): Promise<string[] | null> {
+  return invokeTauriCommand({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'readFiles',
+      data: null
+    }
+  })
+}
+
+/**
+ * Writes payloads with arbitrary MIME types to the clipboard, replacing its content.
+ *
+ * ## Platform-specific
+ *
+ * - **Windows / macOS:** Unsupported.
+ *
+ * @example
+ * ```typescript
+ * import { writeData } from '@tauri-apps/api/clipboard';
+ * await writeData([
+ *   { mimeType: 'application/json', data: new TextEncoder().encode('{"tauri":true}') },
+ *   { mimeType: 'text/plain', data: new TextEncoder().encode('tauri') }
+ * ]);
+ * ```
+ *
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function writeData(formats: ClipboardData[]): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'writeData',
+      data: formats.map(({ mimeType, data }) => ({
+        mimeType,
+        data: Array.from(data)
+      }))
+    }
+  })
+}
+
+/**
+ * Gets the clipboard payload with the given MIME type.
+ *
+ * ## Platform-specific
+ *
+ * - **Windows / macOS:** Only the payloads with a MIME type are available: text, HTML, file lists, PNG images
+ * and the formats the system maps to a MIME type.
+ *
+ * @example
+ * ```typescript
+ * import { readData } from '@tauri-apps/api/clipboard';
+ * const json = await readData('application/json');
+ * ```
+ *
+ * @returns A promise resolving to the payload bytes, or `null` if the clipboard does not contain the MIME type.
+ *
+ * @since 1.3.0
+ */
+async function readData(mimeType: string): Promise<Uint8Array | null> {
+  return invokeTauriCommand<number[] | null>({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'readData',
+      data: mimeType
+    }
+  }).then((data) => (data ? Uint8Array.from(data) : null))
+}
+
+/**
+ * Gets the MIME types available in the clipboard. Requires the `readData` allowlist flag.
+ *
+ * ## Platform-specific
+ *
+ * - **Windows / macOS:** Only the payloads with a MIME type are available: text, HTML, file lists, PNG images
+ * and the formats the system maps to a MIME type.
+ *
+ * @example
+ * ```typescript
+ * import { availableFormats } from '@tauri-apps/api/clipboard';
+ * const hasImage = (await availableFormats()).includes('image/png');
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function availableFormats(): Promise<string[]> {
+  return invokeTauriCommand({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'availableFormats',
+      data: null
+    }
+  })
+}
+
+/**
+ * Clears the clipboard.
+ * @example
+ * ```typescript
+ * import { clear } from '@tauri-apps/api/clipboard';
+ * await clear();
+ * ```
+ *
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function clear(): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Clipboard',
+    message: {
+      cmd: 'clear',
+      data: null
+    }
+  })
+}
+
+/**
+ * Listens to clipboard content changes. Requires the `watch` allowlist flag.
+ *
+ * ## Platform-specific
+ *
+ * - **Windows / macOS:** The clipboard is polled every 500ms.
+ *
+ * @example
+ * ```typescript
+ * import { onClipboardChange, readText } from '@tauri-apps/api/clipboard';
+ * const unlisten = await onClipboardChange(async (formats) => {
+ *   if (formats.some((format) => format.startsWith('text/'))) {
+ *     console.log(await readText());
+ *   }
+ * });
+ *
+ * // you need to call unlisten if your handler goes out of scope e.g. the component is unmounted
+ * unlisten();
+ * ```
+ *
+ * @param handler Handler called with the MIME types available in the clipboard.
+ * @returns A promise resolving to a function to unlisten to the event.
+ *
+ * @since 1.3.0
+ */
+async function onClipboardChange(
+  handler: (formats: string[]) => void
+): Promise<UnlistenFn> {
+  return listen<string[]>(TauriEvent.CLIPBOARD_CHANGE, (event) => {
+    handler(event.payload)
+  })
+}
+
+export type { ClipboardData }
+
+export {
+  writeText,
+  readText,
+  writeImage,
+  readImage,
+  writeHtml,
+  readHtml,
+  writeFiles,
+  readFiles,
+  writeData,
+  readData,
+  availableFormats,
+  clear,
+  onClipboardChange
+}
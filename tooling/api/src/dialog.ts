@@ -16,6 +16,7 @@
  *         "all": true, // enable all dialog APIs
  *         "ask": true, // enable dialog ask API
  *         "confirm": true, // enable dialog confirm API
+ *         "custom": true, // enable dialog customDialog and prompt APIs
  *         "message": true, // enable dialog message API
  *         "open": true, // enable file open API
  *         "save": true // enable file save API
@@ -69,6 +70,19 @@ interface OpenDialogOptions {
    * Defines whether subdirectories will be allowed on the scope or not.
    */
   recursive?: boolean
+  /**
+   * The name of the filter selected when the dialog opens.
+   *
+   * @since 1.3.0
+   */
+  defaultFilter?: string
+  /**
+   * Whether the access to the selected paths is persisted across app restarts.
+   * Persisted access can be removed with {@link revokeAccess}.
+   *
+   * @since 1.3.0
+   */
+  persist?: boolean
 }
 
 /**
@@ -87,6 +101,28 @@ interface SaveDialogOptions {
    * If it's not an existing directory, the file name will be set to the dialog's file name input and the dialog will be set to the parent folder.
    */
   defaultPath?: string
+  /**
+   * The name of the filter selected when the dialog opens.
+   *
+   * @since 1.3.0
+   */
+  defaultFilter?: string
+}
+
+/**
+ * The selected path(s) of a file dialog and the filter matching them.
+ *
+ * @since 1.3.0
+ */
+interface DialogSelection<T> {
+  /** The selected path(s), or `null` if the selection was cancelled. */
+  path: T | null
+  /**
+   * The filter matching the extension of the selected path, or `null` if no filter matches.
+   * Since the native dialogs don't report the filter chosen by the user,
+   * the first matching filter in the list, starting with the `defaultFilter`, is assumed to be the selected one.
+   */
+  filter: DialogFilter | null
 }
 
 /**
@@ -112,6 +148,57 @@ interface ConfirmDialogOptions {
   cancelLabel?: string
 }
 
+/**
+ * @since 1.3.0
+ */
+interface CustomDialogOptions {
+  /** The title of the dialog. Defaults to the app name. */
+  title?: string
+  /** The type of the dialog. Defaults to `info`. */
+  type?: 'info' | 'warning' | 'error'
+  /** The button labels, in display order. Defaults to a single `OK` button. */
+  buttons?: string[]
+  /** Shows a text input with the given default value. */
+  input?: string
+  /** Shows a checkbox below the message. */
+  checkbox?: {
+    /** The checkbox label. */
+    label: string
+    /** Whether the checkbox is initially checked. */
+    checked?: boolean
+  }
+}
+
+/**
+ * The response of a {@link customDialog}.
+ *
+ * @since 1.3.0
+ */
+interface CustomDialogResponse {
+  /** The index of the clicked button, or `null` if the dialog was dismissed. */
+  button: number | null
+  /** The value of the text input, if any. */
+  input: string | null
+  /** Whether the checkbox was checked. */
+  checked: boolean
+}
+
+/**
+ * @since 1.3.0
+ */
+interface PromptDialogOptions {
+  /** The title of the dialog. Defaults to the app name. */
+  title?: string
+  /** The type of the dialog. Defaults to `info`. */
+  type?: 'info' | 'warning' | 'error'
+  /** The initial value of the text input. */
+  defaultValue?: string
+  /** The label of the confirm button. */
+  okLabel?: string
+  /** The label of the cancel button. */
+  cancelLabel?: string
+}
+
 /**
  * Open a file/directory selection dialog.
  *
@@ -119,8 +206,8 @@ interface ConfirmDialogOptions {
  * When security is more important than the easy of use of this API,
  * prefer writing a dedicated command instead.
  *
- * Note that the allowlist scope change is not persisted, so the values are cleared when the application is restarted.
- * You can save it to the filesystem using [tauri-plugin-persisted-scope](https://github.com/tauri-apps/tauri-plugin-persisted-scope).
+ * Note that the allowlist scope change is not persisted unless the `persist` option is set,
+ * so the values are cleared when the application is restarted.
  * @example
  * ```typescript
  * import { open } from '@tauri-apps/api/dialog';
@@ -218,6 +305,92 @@ async function save(options: SaveDialogOptions = {}): Promise<string | null> {
   })
 }
 
+/**
+ * Open a file/directory selection dialog, resolving to the selected path(s) and the filter matching them.
+ * See {@link open} for the scope behavior.
+ * @example
+ * ```typescript
+ * import { openWithFilter } from '@tauri-apps/api/dialog';
+ * const { path, filter } = await openWithFilter({
+ *   filters: [
+ *     { name: 'Image', extensions: ['png', 'jpeg'] },
+ *     { name: 'Text', extensions: ['txt'] }
+ *   ],
+ *   defaultFilter: 'Text'
+ * });
+ * ```
+ *
+ * @returns A promise resolving to the selected path(s) and filter.
+ *
+ * @since 1.3.0
+ */
+async function openWithFilter(
+  options: OpenDialogOptions = {}
+): Promise<DialogSelection<string | string[]>> {
+  return invokeTauriCommand({
+    __tauriModule: 'Dialog',
+    message: {
+      cmd: 'openDialog',
+      options: { ...options, withFilter: true }
+    }
+  })
+}
+
+/**
+ * Open a file/directory save dialog, resolving to the selected path and the filter matching it.
+ * See {@link save} for the scope behavior.
+ * @example
+ * ```typescript
+ * import { saveWithFilter } from '@tauri-apps/api/dialog';
+ * const { path, filter } = await saveWithFilter({
+ *   filters: [
+ *     { name: 'PNG', extensions: ['png'] },
+ *     { name: 'JPEG', extensions: ['jpg', 'jpeg'] }
+ *   ]
+ * });
+ * ```
+ *
+ * @returns A promise resolving to the selected path and filter.
+ *
+ * @since 1.3.0
+ */
+async function saveWithFilter(
+  options: SaveDialogOptions = {}
+): Promise<DialogSelection<string>> {
+  return invokeTauriCommand({
+    __tauriModule: 'Dialog',
+    message: {
+      cmd: 'saveDialog',
+      options: { ...options, withFilter: true }
+    }
+  })
+}
+
+/**
+ * Revokes the filesystem and asset protocol access granted to a path by a file dialog,
+ * including access persisted with the `persist` option.
+ * @example
+ * ```typescript
+ * import { open, revokeAccess } from '@tauri-apps/api/dialog';
+ * const project = await open({ directory: true, recursive: true, persist: true });
+ * // later, when the user closes the project
+ * await revokeAccess(project as string);
+ * ```
+ *
+ * @param path The path passed to the scope by the dialog.
+ *
+ * @since 1.3.0
+ */
+async function revokeAccess(path: string): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Dialog',
+    message: {
+      cmd: 'revokeAccess',
+      path
+    }
+  })
+}
+
 /**
  * Shows a message dialog with an `Ok` button.
  * @example
@@ -324,12 +497,106 @@ async function confirm(
   })
 }
 
+/**
+ * Shows a message dialog with custom buttons, an optional text input and an optional checkbox.
+ * @example
+ * ```typescript
+ * import { customDialog } from '@tauri-apps/api/dialog';
+ * const { button, checked } = await customDialog('Save changes before closing?', {
+ *   buttons: ['Save', 'Discard', 'Cancel'],
+ *   checkbox: { label: 'Do not ask again' }
+ * });
+ * ```
+ *
+ * ## Platform-specific
+ *
+ * - **Windows / macOS:** At most two buttons are supported, and the text input and checkbox are unsupported.
+ * The promise is rejected if the dialog uses unsupported options.
+ *
+ * @param message The message to show.
+ * @param options The dialog's options.
+ *
+ * @returns A promise resolving to the clicked button index, the input value and the checkbox state.
+ *
+ * @since 1.3.0
+ */
+async function customDialog(
+  message: string,
+  options?: CustomDialogOptions
+): Promise<CustomDialogResponse> {
+  return invokeTauriCommand({
+    __tauriModule: 'Dialog',
+    message: {
+      cmd: 'customDialog',
+      message: message.toString(),
+      title: options?.title?.toString(),
+      type: options?.type,
+      buttons: options?.buttons?.map((b) => b.toString()) ?? [],
+      input: options?.input?.toString(),
+      checkbox: options?.checkbox
+        ? {
+            label: options.checkbox.label.toString(),
+            checked: options.checkbox.checked ?? false
+          }
+        : undefined
+    }
+  })
+}
+
+/**
+ * Shows a dialog asking the user to enter a value, with `OK` and `Cancel` buttons.
+ * @example
+ * ```typescript
+ * import { prompt } from '@tauri-apps/api/dialog';
+ * const name = await prompt('Project name', { defaultValue: 'my-app' });
+ * ```
+ *
+ * ## Platform-specific
+ *
+ * - **Windows / macOS:** Unsupported, the promise is rejected.
+ *
+ * @param message The message to show.
+ * @param options The dialog's options. If a string, it represents the dialog title.
+ *
+ * @returns A promise resolving to the entered value, or `null` if the dialog was cancelled.
+ *
+ * @since 1.3.0
+ */
+async function prompt(
+  message: string,
+  options?: string | PromptDialogOptions
+): Promise<string | null> {
+  const opts = typeof options === 'string' ? { title: options } : options
+  const response = await customDialog(message, {
+    title: opts?.title,
+    type: opts?.type,
+    buttons: [opts?.okLabel ?? 'OK', opts?.cancelLabel ?? 'Cancel'],
+    input: opts?.defaultValue ?? ''
+  })
+  return response.button === 0 ? response.input : null
+}
+
 export type {
   DialogFilter,
   OpenDialogOptions,
   SaveDialogOptions,
   MessageDialogOptions,
-  ConfirmDialogOptions
+  ConfirmDialogOptions,
+  DialogSelection,
+  CustomDialogOptions,
+  CustomDialogResponse,
+  PromptDialogOptions
 }
 
-export { open, save, message, ask, confirm }
+export {
+  open,
+  save,
+  openWithFilter,
+  saveWithFilter,
+  revokeAccess,
+  message,
+  ask,
+  confirm,
+  customDialog,
+  prompt
+}
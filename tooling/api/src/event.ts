@@ -30,7 +30,12 @@ export enum TauriEvent {
   WINDOW_FILE_DROP = 'tauri://file-drop',
   WINDOW_FILE_DROP_HOVER = 'tauri://file-drop-hover',
   WINDOW_FILE_DROP_CANCELLED = 'tauri://file-drop-cancelled',
+  WINDOW_URL_CHANGED = 'tauri://url-changed',
   MENU = 'tauri://menu',
+  TRAY_MENU = 'tauri://tray-menu',
+  NOTIFICATION = 'tauri://notification',
+  CLIPBOARD_CHANGE = 'tauri://clipboard-change',
+  STORE_CHANGE = 'tauri://store-change',
   CHECK_UPDATE = 'tauri://update',
   UPDATE_AVAILABLE = 'tauri://update-available',
   INSTALL_UPDATE = 'tauri://update-install',
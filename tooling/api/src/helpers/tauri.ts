@@ -22,6 +22,11 @@ type TauriModule =
   | 'GlobalShortcut'
   | 'Process'
   | 'Clipboard'
+  | 'Archive'
+  | 'Menu'
+  | 'Log'
+  | 'Store'
+  | 'Secrets'
 
 interface TauriCommand {
   __tauriModule: TauriModule
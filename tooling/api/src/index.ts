@@ -14,6 +14,7 @@
  */
 
 import * as app from './app'
+import * as archive from './archive'
 import * as cli from './cli'
 import * as clipboard from './clipboard'
 import * as dialog from './dialog'
@@ -21,10 +22,14 @@ import * as event from './event'
 import * as fs from './fs'
 import * as globalShortcut from './globalShortcut'
 import * as http from './http'
+import * as log from './log'
+import * as menu from './menu'
 import * as notification from './notification'
 import * as path from './path'
 import * as process from './process'
+import * as secrets from './secrets'
 import * as shell from './shell'
+import * as store from './store'
 import * as tauri from './tauri'
 import * as updater from './updater'
 import * as window from './window'
@@ -36,6 +41,7 @@ const invoke = tauri.invoke
 export {
   invoke,
   app,
+  archive,
   cli,
   clipboard,
   dialog,
@@ -43,10 +49,14 @@ export {
   fs,
   globalShortcut,
   http,
+  log,
+  menu,
   notification,
   path,
   process,
+  secrets,
   shell,
+  store,
   tauri,
   updater,
   window,
@@ -0,0 +1,122 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/**
+ * Write messages to the app logs.
+ *
+ * The messages are logged with the `webview:{window label}` target,
+ * and are written by the logger registered on the Rust side, such as the `tauri::logging::Logger` plugin.
+ *
+ * This package is also accessible with `window.__TAURI__.log` when [`build.withGlobalTauri`](https://tauri.app/v1/api/config/#buildconfig.withglobaltauri) in `tauri.conf.json` is set to `true`.
+ *
+ * The APIs must be added to [`tauri.allowlist.log`](https://tauri.app/v1/api/config/#allowlistconfig.log) in `tauri.conf.json`:
+ * ```json
+ * {
+ *   "tauri": {
+ *     "allowlist": {
+ *       "log": {
+ *         "all": true, // enable all log APIs
+ *         "write": true
+ *       }
+ *     }
+ *   }
+ * }
+ * ```
+ * It is recommended to allowlist only the APIs you use for optimal bundle size and security.
+ * @module
+ */
+
+import { invokeTauriCommand } from './helpers/tauri'
+
+/**
+ * The level of a log message.
+ *
+ * @since 1.3.0
+ */
+type LogLevel = 'trace' | 'debug' | 'info' | 'warn' | 'error'
+
+async function log(level: LogLevel, message: string): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Log',
+    message: {
+      cmd: 'log',
+      level,
+      message
+    }
+  })
+}
+
+/**
+ * Logs a message at the trace level.
+ * @example
+ * ```typescript
+ * import { trace } from '@tauri-apps/api/log';
+ * await trace('entering the render loop');
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function trace(message: string): Promise<void> {
+  return log('trace', message)
+}
+
+/**
+ * Logs a message at the debug level.
+ * @example
+ * ```typescript
+ * import { debug } from '@tauri-apps/api/log';
+ * await debug('loaded 3 projects');
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function debug(message: string): Promise<void> {
+  return log('debug', message)
+}
+
+/**
+ * Logs a message at the info level.
+ * @example
+ * ```typescript
+ * import { info } from '@tauri-apps/api/log';
+ * await info('user signed in');
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function info(message: string): Promise<void> {
+  return log('info', message)
+}
+
+/**
+ * Logs a message at the warn level.
+ * @example
+ * ```typescript
+ * import { warn } from '@tauri-apps/api/log';
+ * await warn('the cache is getting large');
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function warn(message: string): Promise<void> {
+  return log('warn', message)
+}
+
+/**
+ * Logs a message at the error level.
+ * @example
+ * ```typescript
+ * import { error } from '@tauri-apps/api/log';
+ * await error('failed to save the project');
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function error(message: string): Promise<void> {
+  return log('error', message)
+}
+
+export type { LogLevel }
+
+export { trace, debug, info, warn, error }
@@ -0,0 +1,256 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/**
+ * Create and update window menus and system tray menus.
+ *
+ * This package is also accessible with `window.__TAURI__.menu` when [`build.withGlobalTauri`](https://tauri.app/v1/api/config/#buildconfig.withglobaltauri) in `tauri.conf.json` is set to `true`.
+ *
+ * The APIs must be added to [`tauri.allowlist.menu`](https://tauri.app/v1/api/config/#allowlistconfig.menu) in `tauri.conf.json`:
+ * ```json
+ * {
+ *   "tauri": {
+ *     "allowlist": {
+ *       "menu": {
+ *         "all": true, // enable all menu APIs
+ *         "setWindowMenu": true,
+ *         "setTrayMenu": true,
+ *         "updateItem": true
+ *       }
+ *     }
+ *   }
+ * }
+ * ```
+ * It is recommended to allowlist only the APIs you use for optimal bundle size and security.
+ *
+ * The system tray APIs require the system tray to be enabled, see [`tauri.systemTray`](https://tauri.app/v1/api/config/#tauriconfig.systemtray).
+ *
+ * @module
+ */
+
+import { invokeTauriCommand } from './helpers/tauri'
+import { listen, TauriEvent } from './event'
+import type { UnlistenFn } from './event'
+import { getCurrent } from './window'
+import type { CustomMenuItem, Submenu, MenuEntry } from './window'
+
+/**
+ * The changes to apply to a menu item. Fields that are not set are left untouched.
+ *
+ * @since 1.3.0
+ */
+interface MenuItemUpdate {
+  enabled?: boolean
+  title?: string
+  selected?: boolean
+}
+
+/**
+ * A click on a window menu item.
+ *
+ * @since 1.3.0
+ */
+interface WindowMenuEvent {
+  /** The label of the window that owns the menu. */
+  windowLabel: string
+  /** The id of the clicked item. */
+  id: string
+}
+
+/**
+ * A click on a system tray menu item.
+ *
+ * @since 1.3.0
+ */
+interface TrayMenuEvent {
+  /** The id of the tray that owns the menu. */
+  trayId: string
+  /** The id of the clicked item. */
+  id: string
+}
+
+/**
+ * Replaces the menu of a window.
+ * @example
+ * ```typescript
+ * import { setWindowMenu } from '@tauri-apps/api/menu';
+ * await setWindowMenu([
+ *   { title: 'File', items: [{ id: 'open', title: 'Open', accelerator: 'CmdOrCtrl+O' }, 'Separator', { id: 'quit', title: 'Quit' }] }
+ * ]);
+ * ```
+ *
+ * @param items The menu entries.
+ * @param label The window label. Defaults to the current window.
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function setWindowMenu(
+  items: MenuEntry[],
+  label?: string
+): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Menu',
+    message: {
+      cmd: 'setWindowMenu',
+      label,
+      items
+    }
+  })
+}
+
+/**
+ * Replaces the menu of a system tray.
+ * @example
+ * ```typescript
+ * import { setTrayMenu } from '@tauri-apps/api/menu';
+ * await setTrayMenu([{ id: 'show', title: 'Show' }, 'Separator', { id: 'quit', title: 'Quit' }]);
+ * ```
+ *
+ * @param items The menu entries.
+ * @param trayId The tray id. Defaults to the tray configured with `tauri::Builder::system_tray`.
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function setTrayMenu(items: MenuEntry[], trayId?: string): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Menu',
+    message: {
+      cmd: 'setTrayMenu',
+      trayId,
+      items
+    }
+  })
+}
+
+/**
+ * Updates an item of a window menu.
+ * @example
+ * ```typescript
+ * import { updateWindowMenuItem } from '@tauri-apps/api/menu';
+ * await updateWindowMenuItem('save', { enabled: false });
+ * ```
+ *
+ * @param id The item id.
+ * @param update The changes to apply.
+ * @param label The window label. Defaults to the current window.
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function updateWindowMenuItem(
+  id: string,
+  update: MenuItemUpdate,
+  label?: string
+): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Menu',
+    message: {
+      cmd: 'updateWindowMenuItem',
+      label,
+      id,
+      update
+    }
+  })
+}
+
+/**
+ * Updates an item of a system tray menu.
+ * @example
+ * ```typescript
+ * import { updateTrayMenuItem } from '@tauri-apps/api/menu';
+ * await updateTrayMenuItem('show', { title: 'Hide' });
+ * ```
+ *
+ * @param id The item id.
+ * @param update The changes to apply.
+ * @param trayId The tray id. Defaults to the tray configured with `tauri::Builder::system_tray`.
+ * @returns A promise indicating the success or failure of the operation.
+ *
+ * @since 1.3.0
+ */
+async function updateTrayMenuItem(
+  id: string,
+  update: MenuItemUpdate,
+  trayId?: string
+): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Menu',
+    message: {
+      cmd: 'updateTrayMenuItem',
+      trayId,
+      id,
+      update
+    }
+  })
+}
+
+/**
+ * Listens to clicks on the current window menu items, including its context menus.
+ * @example
+ * ```typescript
+ * import { onWindowMenuEvent } from '@tauri-apps/api/menu';
+ * const unlisten = await onWindowMenuEvent(({ id }) => {
+ *   console.log(`clicked ${id}`);
+ * });
+ *
+ * // you need to call unlisten if your handler goes out of scope e.g. the component is unmounted
+ * unlisten();
+ * ```
+ *
+ * @returns A promise resolving to a function to unlisten to the event.
+ *
+ * @since 1.3.0
+ */
+async function onWindowMenuEvent(
+  handler: (event: WindowMenuEvent) => void
+): Promise<UnlistenFn> {
+  return getCurrent().listen<string>(TauriEvent.MENU, (event) => {
+    handler({ windowLabel: event.windowLabel, id: event.payload })
+  })
+}
+
+/**
+ * Listens to clicks on the system tray menu items.
+ * @example
+ * ```typescript
+ * import { onTrayMenuEvent } from '@tauri-apps/api/menu';
+ * const unlisten = await onTrayMenuEvent(({ trayId, id }) => {
+ *   console.log(`clicked ${id} on tray ${trayId}`);
+ * });
+ *
+ * // you need to call unlisten if your handler goes out of scope e.g. the component is unmounted
+ * unlisten();
+ * ```
+ *
+ * @returns A promise resolving to a function to unlisten to the event.
+ *
+ * @since 1.3.0
+ */
+async function onTrayMenuEvent(
+  handler: (event: TrayMenuEvent) => void
+): Promise<UnlistenFn> {
+  return listen<TrayMenuEvent>(TauriEvent.TRAY_MENU, (event) => {
+    handler(event.payload)
+  })
+}
+
+export type {
+  CustomMenuItem,
+  Submenu,
+  MenuEntry,
+  MenuItemUpdate,
+  WindowMenuEvent,
+  TrayMenuEvent
+}
+
+export {
+  setWindowMenu,
+  setTrayMenu,
+  updateWindowMenuItem,
+  updateTrayMenuItem,
+  onWindowMenuEvent,
+  onTrayMenuEvent
+}
@@ -25,6 +25,41 @@
  */
 
 import { invokeTauriCommand } from './helpers/tauri'
+import { listen, TauriEvent } from './event'
+import type { UnlistenFn } from './event'
+
+/**
+ * A button shown on a notification.
+ * Inline replies are not supported, so the app should ask for the reply itself when the action is invoked.
+ *
+ * @since 1.3.0
+ */
+interface Action {
+  /** The action identifier, sent back on the `action` event. */
+  id: string
+  /** The button label. */
+  title: string
+}
+
+/**
+ * When a notification is delivered.
+ * `at` is a time in milliseconds since the Unix epoch and `after` is a delay in milliseconds.
+ *
+ * @since 1.3.0
+ */
+type Schedule = { at: number } | { after: number }
+
+/**
+ * An interaction with a notification.
+ *
+ * Only delivered on Linux.
+ *
+ * @since 1.3.0
+ */
+type NotificationEvent =
+  | { type: 'clicked'; id: string | null }
+  | { type: 'action'; id: string | null; action: string }
+  | { type: 'closed'; id: string | null }
 
 /**
  * Options to send a notification.
@@ -38,6 +73,44 @@ interface Options {
   body?: string
   /** Optional notification icon. */
   icon?: string
+  /**
+   * Identifier sent back on the notification events.
+   *
+   * @since 1.3.0
+   */
+  id?: string
+  /**
+   * Buttons shown on the notification.
+   * Only supported on Linux, the notification is not sent on Windows and macOS when actions are set.
+   *
+   * @since 1.3.0
+   */
+  actions?: Action[]
+  /**
+   * Name of the sound played when the notification is shown. Not supported on Windows.
+   *
+   * @since 1.3.0
+   */
+  sound?: string
+  /**
+   * The notification urgency. Only supported on Linux.
+   *
+   * @since 1.3.0
+   */
+  urgency?: 'low' | 'normal' | 'critical'
+  /**
+   * How long the notification is shown, in milliseconds. Only supported on Linux.
+   *
+   * @since 1.3.0
+   */
+  timeout?: number
+  /**
+   * Delays the notification delivery.
+   * Scheduled notifications are discarded if the app exits before their delivery time.
+   *
+   * @since 1.3.0
+   */
+  schedule?: Schedule
 }
 
 /** Possible permission values. */
@@ -113,6 +186,43 @@ function sendNotification(options: Options | string): void {
   }
 }
 
-export type { Options, Permission }
+/**
+ * Listens to clicks, action clicks and dismissals of the notifications sent by the app.
+ * @example
+ * ```typescript
+ * import { sendNotification, onNotificationEvent } from '@tauri-apps/api/notification';
+ * const unlisten = await onNotificationEvent((event) => {
+ *   if (event.type === 'action' && event.action === 'reply') {
+ *     console.log(`reply to ${event.id}`);
+ *   }
+ * });
+ * sendNotification({ id: 'message-1', title: 'New message', actions: [{ id: 'reply', title: 'Reply' }] });
+ *
+ * // you need to call unlisten if your handler goes out of scope e.g. the component is unmounted
+ * unlisten();
+ * ```
+ *
+ * ## Platform-specific
+ *
+ * - **Windows / macOS:** Unsupported, no events are delivered.
+ *
+ * @returns A promise resolving to a function to unlisten to the event.
+ *
+ * @since 1.3.0
+ */
+async function onNotificationEvent(
+  handler: (event: NotificationEvent) => void
+): Promise<UnlistenFn> {
+  return listen<NotificationEvent>(TauriEvent.NOTIFICATION, (event) => {
+    handler(event.payload)
+  })
+}
+
+export type { Options, Permission, Action, Schedule, NotificationEvent }
 
-export { sendNotification, requestPermission, isPermissionGranted }
+export {
+  sendNotification,
+  requestPermission,
+  isPermissionGranted,
+  onNotificationEvent
+}
@@ -0,0 +1,120 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/**
+ * Store secrets such as passwords and tokens in the credential store of the operating system.
+ *
+ * The secrets are stored with the Secret Service API on Linux, in the Keychain on macOS and in the Credential Manager on Windows.
+ * On Linux, when the Secret Service is not available, they are stored in an encrypted file in the app local data directory.
+ *
+ * This package is also accessible with `window.__TAURI__.secrets` when [`build.withGlobalTauri`](https://tauri.app/v1/api/config/#buildconfig.withglobaltauri) in `tauri.conf.json` is set to `true`.
+ *
+ * The APIs must be added to [`tauri.allowlist.secrets`](https://tauri.app/v1/api/config/#allowlistconfig.secrets) in `tauri.conf.json`:
+ * ```json
+ * {
+ *   "tauri": {
+ *     "allowlist": {
+ *       "secrets": {
+ *         "all": true, // enable all secrets APIs
+ *         "get": true,
+ *         "set": true,
+ *         "delete": true
+ *       }
+ *     }
+ *   }
+ * }
+ * ```
+ * It is recommended to allowlist only the APIs you use for optimal bundle size and security.
+ *
+ * ## Security
+ *
+ * The service is prefixed with the [bundle identifier](https://tauri.app/v1/api/config/#bundleconfig.identifier) of the app,
+ * e.g. the `oauth` service is stored as `com.tauri.dev.oauth`, so the secrets of other apps can't be accessed.
+ *
+ * @module
+ */
+
+import { invokeTauriCommand } from './helpers/tauri'
+
+/**
+ * Gets the secret of the account for the service.
+ * @example
+ * ```typescript
+ * import { getSecret } from '@tauri-apps/api/secrets';
+ * const refreshToken = await getSecret('oauth', 'user@tauri.app');
+ * ```
+ *
+ * @returns A promise resolving to the secret, or `null` if it is not stored.
+ *
+ * @since 1.3.0
+ */
+async function getSecret(
+  service: string,
+  account: string
+): Promise<string | null> {
+  return invokeTauriCommand({
+    __tauriModule: 'Secrets',
+    message: {
+      cmd: 'get',
+      service,
+      account
+    }
+  })
+}
+
+/**
+ * Stores the secret of the account for the service, replacing the existing one.
+ *
+ * On Windows, the secret must not exceed 2560 bytes.
+ * @example
+ * ```typescript
+ * import { setSecret } from '@tauri-apps/api/secrets';
+ * await setSecret('oauth', 'user@tauri.app', refreshToken);
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function setSecret(
+  service: string,
+  account: string,
+  secret: string
+): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Secrets',
+    message: {
+      cmd: 'set',
+      service,
+      account,
+      secret
+    }
+  })
+}
+
+/**
+ * Deletes the secret of the account for the service.
+ * @example
+ * ```typescript
+ * import { deleteSecret } from '@tauri-apps/api/secrets';
+ * await deleteSecret('oauth', 'user@tauri.app');
+ * ```
+ *
+ * @returns A promise resolving to whether the secret was stored.
+ *
+ * @since 1.3.0
+ */
+async function deleteSecret(
+  service: string,
+  account: string
+): Promise<boolean> {
+  return invokeTauriCommand({
+    __tauriModule: 'Secrets',
+    message: {
+      cmd: 'delete',
+      service,
+      account
+    }
+  })
+}
+
+export { getSecret, setSecret, deleteSecret }
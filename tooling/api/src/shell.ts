@@ -17,7 +17,8 @@
  *         "all": true, // enable all shell APIs
  *         "execute": true, // enable process spawn APIs
  *         "sidecar": true, // enable spawning sidecars
- *         "open": true // enable opening files/URLs using the default program
+ *         "open": true, // enable opening files/URLs using the default program
+ *         "pty": true // enable spawning processes in a pseudo terminal
  *       }
  *     }
  *   }
@@ -92,6 +93,69 @@ interface SpawnOptions {
    * @since 1.1.0
    *  */
   encoding?: string
+  /**
+   * Emits the `stdout` and `stderr` output as `Uint8Array` chunks as soon as they are read,
+   * instead of lines decoded to strings. `encoding` is ignored.
+   * Use this mode for binary output or for output that isn't terminated by a newline, such as progress bars.
+   *
+   * @since 1.3.0
+   */
+  raw?: boolean
+  /**
+   * Spawns the process in a pseudo terminal of the given size, so interactive programs detect a terminal.
+   * The terminal merges stdout and stderr, so the output is emitted as `Uint8Array` chunks on the `stdout` emitter
+   * and `encoding` is ignored. Requires the `pty` flag on the shell allowlist.
+   *
+   * @since 1.3.0
+   */
+  pty?: PtySize
+  /**
+   * Spawns the process in a new process group, so the processes it spawns are terminated with it.
+   *
+   * @since 1.3.0
+   */
+  processGroup?: boolean
+  /**
+   * Time in milliseconds the process is given to exit when it is killed or when the app exits,
+   * before it is forcefully killed. By default the process is killed immediately.
+   *
+   * @since 1.3.0
+   */
+  killTimeout?: number
+}
+
+/**
+ * The size of a pseudo terminal, in characters.
+ *
+ * @since 1.3.0
+ */
+interface PtySize {
+  /** The number of rows. */
+  rows: number
+  /** The number of columns. */
+  cols: number
+}
+
+/**
+ * Options for {@link Command.supervise}. Durations are in milliseconds.
+ *
+ * @since 1.3.0
+ */
+interface SuperviseOptions {
+  /** The process is ready once its stdout contains this text. */
+  readyPattern?: string
+  /** The process is ready once it accepts connections on this local TCP port. Ignored if `readyPattern` is set. */
+  readyPort?: number
+  /** How long the process is given to be ready before it is restarted. Defaults to 30 seconds. */
+  readyTimeout?: number
+  /** The delay before the first restart, doubled after each restart. Defaults to 500 milliseconds. */
+  initialBackoff?: number
+  /** The maximum delay before a restart. Defaults to 30 seconds. */
+  maxBackoff?: number
+  /** Gives up restarting the process after this number of restarts within `restartPeriod`. By default the process is always restarted. */
+  maxRestarts?: number
+  /** The period `maxRestarts` applies to. Defaults to 60 seconds. */
+  restartPeriod?: number
 }
 
 /** @ignore */
@@ -354,6 +418,10 @@ class Child {
   /**
    * Kills the child process.
    *
+   * If the `killTimeout` option is set, the process is asked to exit first
+   * and only forcefully killed if it is still running after the timeout.
+   * The promise resolves once the process is asked to exit, without waiting for the timeout.
+   *
    * @returns A promise indicating the success or failure of the operation.
    */
   async kill(): Promise<void> {
@@ -365,6 +433,33 @@ class Child {
       }
     })
   }
+
+  /**
+   * Resizes the pseudo terminal of a process spawned with the `pty` option.
+   * @example
+   * ```typescript
+   * import { Command } from '@tauri-apps/api/shell';
+   * const command = new Command('bash', [], { pty: { rows: 24, cols: 80 } });
+   * const child = await command.spawn();
+   * await child.resize({ rows: 40, cols: 120 });
+   * ```
+   *
+   * @param size The new terminal size.
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async resize(size: PtySize): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Shell',
+      message: {
+        cmd: 'resizePty',
+        pid: this.pid,
+        rows: size.rows,
+        cols: size.cols
+      }
+    })
+  }
 }
 
 /**
@@ -441,6 +536,27 @@ class Command extends EventEmitter<'close' | 'error'> {
     return instance
   }
 
+  /**
+   * Creates a supervisor that keeps the command running, restarting it when it terminates.
+   * Call {@link Supervisor.start} once the event listeners are registered.
+   * @example
+   * ```typescript
+   * import { Command } from '@tauri-apps/api/shell';
+   * const supervisor = Command.sidecar('server').supervise({ readyPort: 8080, maxRestarts: 5 });
+   * supervisor.on('ready', pid => console.log(`server ${pid} is ready`));
+   * supervisor.on('restart', ({ attempt, delay }) => console.log(`restart #${attempt} in ${delay}ms`));
+   * supervisor.stdout.on('data', line => console.log(`server stdout: "${line}"`));
+   * await supervisor.start();
+   * ```
+   *
+   * @param options Restart and readiness options.
+   *
+   * @since 1.3.0
+   */
+  supervise(options?: SuperviseOptions): Supervisor {
+    return new Supervisor(this.program, this.args, this.options, options ?? {})
+  }
+
   /**
    * Executes the command as a child process, returning a handle to it.
    *
@@ -459,6 +575,12 @@ class Command extends EventEmitter<'close' | 'error'> {
           case 'Stdout':
             this.stdout.emit('data', event.payload)
             break
+          case 'StdoutBytes':
+            this.stdout.emit('data', new Uint8Array(event.payload))
+            break
+          case 'StderrBytes':
+            this.stderr.emit('data', new Uint8Array(event.payload))
+            break
           case 'Stderr':
             this.stderr.emit('data', event.payload)
             break
@@ -489,18 +611,31 @@ class Command extends EventEmitter<'close' | 'error'> {
       this.on('error', reject)
       const stdout: string[] = []
       const stderr: string[] = []
-      this.stdout.on('data', (line: string) => {
-        stdout.push(line)
+      const stdoutDecoder = new TextDecoder()
+      const stderrDecoder = new TextDecoder()
+      let chunked = false
+      this.stdout.on('data', (data: string | Uint8Array) => {
+        if (typeof data === 'string') {
+          stdout.push(data)
+        } else {
+          chunked = true
+          stdout.push(stdoutDecoder.decode(data, { stream: true }))
+        }
       })
-      this.stderr.on('data', (line: string) => {
-        stderr.push(line)
+      this.stderr.on('data', (data: string | Uint8Array) => {
+        if (typeof data === 'string') {
+          stderr.push(data)
+        } else {
+          chunked = true
+          stderr.push(stderrDecoder.decode(data, { stream: true }))
+        }
       })
       this.on('close', (payload: TerminatedPayload) => {
         resolve({
           code: payload.code,
           signal: payload.signal,
-          stdout: stdout.join('\n'),
-          stderr: stderr.join('\n')
+          stdout: stdout.join(chunked ? '' : '\n'),
+          stderr: stderr.join(chunked ? '' : '\n')
         })
       })
       this.spawn().catch(reject)
@@ -508,6 +643,150 @@ class Command extends EventEmitter<'close' | 'error'> {
   }
 }
 
+/**
+ * Keeps a command running, restarting it with an exponential backoff when it terminates.
+ * Created with {@link Command.supervise}.
+ *
+ * It emits the following events:
+ * - `spawn`: the process was spawned, with its pid.
+ * - `ready`: the process passed the readiness probe, or was spawned if there's none, with its pid.
+ * - `not-ready`: the process wasn't ready in time and is restarted, with its pid.
+ * - `close`: the process terminated, with a {@link TerminatedPayload}.
+ * - `restart`: the process is restarted, with the `attempt` number and the `delay` in milliseconds.
+ * - `restart-limit`: the process terminated too many times and won't be restarted.
+ * - `stop`: the supervisor was stopped.
+ * - `error`: an error happened spawning or reading the process.
+ *
+ * @since 1.3.0
+ */
+class Supervisor extends EventEmitter<
+  | 'spawn'
+  | 'ready'
+  | 'not-ready'
+  | 'close'
+  | 'restart'
+  | 'restart-limit'
+  | 'stop'
+  | 'error'
+> {
+  /** @ignore Program to execute. */
+  private readonly program: string
+  /** @ignore Program arguments */
+  private readonly args: string[]
+  /** @ignore Spawn options. */
+  private readonly options: InternalSpawnOptions
+  /** @ignore Supervise options. */
+  private readonly superviseOptions: SuperviseOptions
+  /** @ignore The supervisor id, set once it is started. */
+  private id: number | null = null
+  /** Event emitter for the `stdout`. Emits the `data` event. */
+  readonly stdout = new EventEmitter<'data'>()
+  /** Event emitter for the `stderr`. Emits the `data` event. */
+  readonly stderr = new EventEmitter<'data'>()
+
+  /** @ignore */
+  constructor(
+    program: string,
+    args: string[],
+    options: InternalSpawnOptions,
+    superviseOptions: SuperviseOptions
+  ) {
+    super()
+    this.program = program
+    this.args = args
+    this.options = options
+    this.superviseOptions = superviseOptions
+  }
+
+  /**
+   * Spawns the process and starts supervising it.
+   *
+   * @returns A promise rejecting if the process can't be spawned the first time.
+   */
+  async start(): Promise<void> {
+    const args = this.args
+    Object.freeze(args)
+
+    this.id = await invokeTauriCommand<number>({
+      __tauriModule: 'Shell',
+      message: {
+        cmd: 'supervise',
+        program: this.program,
+        args,
+        options: this.options,
+        supervisor: this.superviseOptions,
+        onEventFn: transformCallback((event: SupervisorEvent) => {
+          switch (event.event) {
+            case 'Started':
+              this.emit('spawn', event.payload.pid)
+              break
+            case 'Ready':
+              this.emit('ready', event.payload.pid)
+              break
+            case 'NotReady':
+              this.emit('not-ready', event.payload.pid)
+              break
+            case 'Terminated':
+              this.emit('close', event.payload)
+              break
+            case 'Restarting':
+              this.emit('restart', event.payload)
+              break
+            case 'RestartLimitReached':
+              this.emit('restart-limit')
+              break
+            case 'Stopped':
+              this.emit('stop')
+              break
+            case 'Output':
+              this.emitOutput(event.payload)
+              break
+          }
+        })
+      }
+    })
+  }
+
+  /**
+   * Stops the supervisor, terminating the process. The `stop` event is emitted once it is terminated.
+   *
+   * @returns A promise indicating the success or failure of the operation.
+   */
+  async stop(): Promise<void> {
+    if (this.id === null) {
+      return
+    }
+    return invokeTauriCommand({
+      __tauriModule: 'Shell',
+      message: {
+        cmd: 'stopSupervisor',
+        id: this.id
+      }
+    })
+  }
+
+  /** @ignore */
+  private emitOutput(event: CommandEvent): void {
+    switch (event.event) {
+      case 'Error':
+        this.emit('error', event.payload)
+        break
+      case 'Stdout':
+        this.stdout.emit('data', event.payload)
+        break
+      case 'StdoutBytes':
+        this.stdout.emit('data', new Uint8Array(event.payload))
+        break
+      case 'StderrBytes':
+        this.stderr.emit('data', new Uint8Array(event.payload))
+        break
+      case 'Stderr':
+        this.stderr.emit('data', event.payload)
+        break
+    }
+  }
+}
+
 /**
  * Describes the event message received from the command.
  */
@@ -529,10 +808,23 @@ interface TerminatedPayload {
 /** Events emitted by the child process. */
 type CommandEvent =
   | Event<'Stdout', string>
+  | Event<'StdoutBytes', number[]>
+  | Event<'StderrBytes', number[]>
   | Event<'Stderr', string>
   | Event<'Terminated', TerminatedPayload>
   | Event<'Error', string>
 
+/** Events emitted by a supervisor. */
+type SupervisorEvent =
+  | Event<'Started', { pid: number }>
+  | Event<'Ready', { pid: number }>
+  | Event<'NotReady', { pid: number }>
+  | Event<'Output', CommandEvent>
+  | Event<'Terminated', TerminatedPayload>
+  | Event<'Restarting', { attempt: number; delay: number }>
+  | Event<'RestartLimitReached', undefined>
+  | Event<'Stopped', undefined>
+
 /**
  * Opens a path or URL with the system's default app,
  * or the one specified with `openWith`.
@@ -570,5 +862,5 @@ async function open(path: string, openWith?: string): Promise<void> {
   })
 }
 
-export { Command, Child, EventEmitter, open }
-export type { ChildProcess, SpawnOptions }
+export { Command, Child, EventEmitter, Supervisor, open }
+export type { ChildProcess, SpawnOptions, PtySize, SuperviseOptions }
@@ -0,0 +1,184 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/**
+ * Read and write the persistent key-value store of the app.
+ *
+ * The store is shared by the app and all its windows, and saved in the app config directory on every change.
+ * The values of the keys that are not set default to the `plugins > store > defaults` object of `tauri.conf.json`.
+ *
+ * This package is also accessible with `window.__TAURI__.store` when [`build.withGlobalTauri`](https://tauri.app/v1/api/config/#buildconfig.withglobaltauri) in `tauri.conf.json` is set to `true`.
+ *
+ * The APIs must be added to [`tauri.allowlist.store`](https://tauri.app/v1/api/config/#allowlistconfig.store) in `tauri.conf.json`:
+ * ```json
+ * {
+ *   "tauri": {
+ *     "allowlist": {
+ *       "store": {
+ *         "all": true, // enable all store APIs
+ *         "get": true, // enables `get`, `has` and `entries`
+ *         "set": true,
+ *         "remove": true
+ *       }
+ *     }
+ *   }
+ * }
+ * ```
+ * It is recommended to allowlist only the APIs you use for optimal bundle size and security.
+ * @module
+ */
+
+import { invokeTauriCommand } from './helpers/tauri'
+import { listen, TauriEvent } from './event'
+import type { UnlistenFn } from './event'
+
+/**
+ * A change of the store.
+ *
+ * @since 1.3.0
+ */
+interface StoreChange<T = unknown> {
+  /** The key of the value. */
+  key: string
+  /** The new value, or its default if it was removed. */
+  value: T | null
+}
+
+/**
+ * Gets the value of a key, or its default if it is not set.
+ * @example
+ * ```typescript
+ * import { get } from '@tauri-apps/api/store';
+ * const theme = await get<string>('theme');
+ * ```
+ *
+ * @returns A promise resolving to the value, or `null` if the key is not set and has no default.
+ *
+ * @since 1.3.0
+ */
+async function get<T>(key: string): Promise<T | null> {
+  return invokeTauriCommand({
+    __tauriModule: 'Store',
+    message: {
+      cmd: 'get',
+      key
+    }
+  })
+}
+
+/**
+ * Sets the value of a key and saves the store.
+ *
+ * The value must have the JSON type of the key default, if it has one.
+ * @example
+ * ```typescript
+ * import { set } from '@tauri-apps/api/store';
+ * await set('theme', 'dark');
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function set(key: string, value: unknown): Promise<void> {
+  return invokeTauriCommand({
+    __tauriModule: 'Store',
+    message: {
+      cmd: 'set',
+      key,
+      value
+    }
+  })
+}
+
+/**
+ * Removes the value of a key and saves the store.
+ * @example
+ * ```typescript
+ * import { remove } from '@tauri-apps/api/store';
+ * await remove('theme');
+ * ```
+ *
+ * @returns A promise resolving to whether the key was set.
+ *
+ * @since 1.3.0
+ */
+async function remove(key: string): Promise<boolean> {
+  return invokeTauriCommand({
+    __tauriModule: 'Store',
+    message: {
+      cmd: 'remove',
+      key
+    }
+  })
+}
+
+/**
+ * Checks whether a key is set or has a default.
+ * @example
+ * ```typescript
+ * import { has } from '@tauri-apps/api/store';
+ * const hasTheme = await has('theme');
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function has(key: string): Promise<boolean> {
+  return invokeTauriCommand({
+    __tauriModule: 'Store',
+    message: {
+      cmd: 'has',
+      key
+    }
+  })
+}
+
+/**
+ * Gets all the values of the store, including the defaults of the keys that are not set.
+ * @example
+ * ```typescript
+ * import { entries } from '@tauri-apps/api/store';
+ * const values = await entries();
+ * ```
+ *
+ * @since 1.3.0
+ */
+async function entries(): Promise<Record<string, unknown>> {
+  return invokeTauriCommand({
+    __tauriModule: 'Store',
+    message: {
+      cmd: 'entries'
+    }
+  })
+}
+
+/**
+ * Listens to the changes of the store, made by the app or any window.
+ * @example
+ * ```typescript
+ * import { onChange } from '@tauri-apps/api/store';
+ * const unlisten = await onChange<string>((change) => {
+ *   if (change.key === 'theme') {
+ *     document.body.dataset.theme = change.value ?? 'light';
+ *   }
+ * });
+ *
+ * // you need to call unlisten if your handler goes out of scope e.g. the component is unmounted
+ * unlisten();
+ * ```
+ *
+ * @param handler Handler called with each change.
+ * @returns A promise resolving to a function to unlisten to the event.
+ *
+ * @since 1.3.0
+ */
+async function onChange<T = unknown>(
+  handler: (change: StoreChange<T>) => void
+): Promise<UnlistenFn> {
+  return listen<StoreChange<T>>(TauriEvent.STORE_CHANGE, (event) => {
+    handler(event.payload)
+  })
+}
+
+export type { StoreChange }
+
+export { get, set, remove, has, entries, onChange }
@@ -43,7 +43,14 @@
  *         "setCursorPosition": true,
  *         "setIgnoreCursorEvents": true,
  *         "startDragging": true,
- *         "print": true
+ *         "print": true,
+ *         "navigate": true,
+ *         "reload": true,
+ *         "goBack": true,
+ *         "goForward": true,
+ *         "setZoom": true,
+ *         "findInPage": true,
+ *         "popupMenu": true
  *       }
  *     }
  *   }
@@ -730,6 +737,56 @@ class WindowManager extends WebviewWindowHandle {
     })
   }
 
+  /**
+   * Gets the webview's current URL.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * const url = await appWindow.url();
+   * ```
+   *
+   * @since 1.3.0
+   * */
+  async url(): Promise<string> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'url'
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Gets the webview's current zoom level, where `1.0` is the default size.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * const zoom = await appWindow.zoom();
+   * ```
+   *
+   * @since 1.3.0
+   * */
+  async zoom(): Promise<number> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'zoom'
+          }
+        }
+      }
+    })
+  }
+
   /**
    * Gets the window's current theme.
    *
@@ -1647,6 +1704,317 @@ class WindowManager extends WebviewWindowHandle {
     })
   }
 
+  /**
+   * Navigates the webview to the given URL.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * await appWindow.navigate('https://tauri.app');
+   * ```
+   *
+   * @param url The URL to navigate to. Relative URLs are resolved against the current page URL.
+   * Only `http`, `https`, `tauri` and `asset` URLs are allowed.
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async navigate(url: string): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'navigate',
+            payload: url
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Reloads the current page of the webview.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * await appWindow.reload();
+   * ```
+   *
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async reload(): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'reload'
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Navigates back to the previous page of the webview history.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * await appWindow.goBack();
+   * ```
+   *
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async goBack(): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'goBack'
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Navigates forward to the next page of the webview history.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * await appWindow.goForward();
+   * ```
+   *
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async goForward(): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'goForward'
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Sets the webview zoom level, where `1.0` is the default size.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * await appWindow.setZoom(1.5);
+   * ```
+   *
+   * @param scaleFactor The new zoom level, a finite number greater than zero.
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async setZoom(scaleFactor: number): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'setZoom',
+            payload: scaleFactor
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Searches the webview content for the given text and selects its first match.
+   *
+   * The search uses the non-standard `window.find` function, so only the text of the main document is searched (not iframes or shadow roots),
+   * the match replaces the page selection, all matches are not highlighted or counted and the search state is lost when the page navigates.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * await appWindow.findInPage('tauri', { caseSensitive: false, wrapAround: true });
+   * ```
+   *
+   * @param text The text to search for.
+   * @param options The search options.
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async findInPage(text: string, options: FindInPageOptions = {}): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'findInPage',
+            payload: {
+              text,
+              options
+            }
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Selects the next match of the current {@link WindowManager.findInPage} search.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * await appWindow.findNext();
+   * ```
+   *
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async findNext(): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'findNext'
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Selects the previous match of the current {@link WindowManager.findInPage} search.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * await appWindow.findPrevious();
+   * ```
+   *
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async findPrevious(): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'findPrevious'
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Clears the current {@link WindowManager.findInPage} search and its selection.
+   * @example
+   * ```typescript
+   * import { appWindow } from '@tauri-apps/api/window';
+   * await appWindow.clearFind();
+   * ```
+   *
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async clearFind(): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'clearFind'
+          }
+        }
+      }
+    })
+  }
+
+  /**
+   * Shows a native context menu at the given position, relative to the window's client area.
+   * Item clicks are emitted as {@link TauriEvent.MENU} events with the item id as payload.
+   *
+   * - **Android / iOS:** Unsupported.
+   * @example
+   * ```typescript
+   * import { appWindow, LogicalPosition } from '@tauri-apps/api/window';
+   * import { TauriEvent } from '@tauri-apps/api/event';
+   * await appWindow.listen<string>(TauriEvent.MENU, ({ payload }) => {
+   *   console.log(`clicked ${payload}`);
+   * });
+   * await appWindow.popupMenu(
+   *   [{ id: 'copy', title: 'Copy' }, 'Separator', { id: 'inspect', title: 'Inspect' }],
+   *   new LogicalPosition(100, 100)
+   * );
+   * ```
+   *
+   * @param items The menu entries.
+   * @param position The menu position. Defaults to the cursor position.
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 1.3.0
+   */
+  async popupMenu(
+    items: MenuEntry[],
+    position?: LogicalPosition | PhysicalPosition
+  ): Promise<void> {
+    return invokeTauriCommand({
+      __tauriModule: 'Window',
+      message: {
+        cmd: 'manage',
+        data: {
+          label: this.label,
+          cmd: {
+            type: 'popupMenu',
+            payload: {
+              items,
+              position: position
+                ? {
+                    type: position.type,
+                    data: {
+                      x: position.x,
+                      y: position.y
+                    }
+                  }
+                : null
+            }
+          }
+        }
+      }
+    })
+  }
+
   // Listeners
 
   /**
@@ -1905,6 +2273,31 @@ class WindowManager extends WebviewWindowHandle {
   async onThemeChanged(handler: EventCallback<Theme>): Promise<UnlistenFn> {
     return this.listen<Theme>(TauriEvent.WINDOW_THEME_CHANGED, handler)
   }
+
+  /**
+   * Listen to the webview URL changes.
+   * The event is emitted when the navigation of the main frame is committed,
+   * so navigations blocked by the window's navigation handler and iframe navigations are ignored.
+   *
+   * @example
+   * ```typescript
+   * import { appWindow } from "@tauri-apps/api/window";
+   * const unlisten = await appWindow.onUrlChanged(({ payload: url }) => {
+   *  console.log('Navigated to ' + url);
+   * });
+   *
+   * // you need to call unlisten if your handler goes out of scope e.g. the component is unmounted
+   * unlisten();
+   * ```
+   *
+   * @returns A promise resolving to a function to unlisten to the event.
+   * Note that removing the listener is required if your listener goes out of scope e.g. the component is unmounted.
+   *
+   * @since 1.3.0
+   */
+  async onUrlChanged(handler: EventCallback<string>): Promise<UnlistenFn> {
+    return this.listen<string>(TauriEvent.WINDOW_URL_CHANGED, handler)
+  }
 }
 
 /**
@@ -2048,6 +2441,53 @@ if ('__TAURI_METADATA__' in window) {
   })
 }
 
+/**
+ * Options for the find in page API.
+ *
+ * @since 1.3.0
+ */
+interface FindInPageOptions {
+  /** Whether the search is case sensitive or not. Defaults to `false`. */
+  caseSensitive?: boolean
+  /** Whether the search continues from the beginning of the page when reaching the end. Defaults to `false`. */
+  wrapAround?: boolean
+}
+
+/**
+ * A custom menu item. Its `id` is the payload of the menu event emitted when it is clicked.
+ *
+ * @since 1.3.0
+ */
+interface CustomMenuItem {
+  id: string
+  title: string
+  /** Defaults to `true`. */
+  enabled?: boolean
+  /** Defaults to `false`. */
+  selected?: boolean
+  /** The keyboard accelerator, e.g. `CmdOrCtrl+C`. */
+  accelerator?: string
+}
+
+/**
+ * A menu entry with nested entries.
+ *
+ * @since 1.3.0
+ */
+interface Submenu {
+  title: string
+  /** Defaults to `true`. */
+  enabled?: boolean
+  items: MenuEntry[]
+}
+
+/**
+ * An entry of a menu.
+ *
+ * @since 1.3.0
+ */
+type MenuEntry = CustomMenuItem | Submenu | 'Separator'
+
 /**
  * Configuration for the window to create.
  *
@@ -2145,6 +2585,11 @@ interface WindowOptions {
    * Additional arguments for the webview. **Windows Only**
    */
   additionalBrowserArguments?: string
+  /**
+   * Whether the size, position and maximized state of the window are saved when it changes,
+   * and restored the next time a window with the same label is created.
+   */
+  restoreState?: boolean
 }
 
 function mapMonitor(m: Monitor | null): Monitor | null {
@@ -2256,5 +2701,9 @@ export type {
   Monitor,
   ScaleFactorChanged,
   FileDropEvent,
+  FindInPageOptions,
+  CustomMenuItem,
+  Submenu,
+  MenuEntry,
   WindowOptions
 }
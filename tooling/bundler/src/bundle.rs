@@ -20,8 +20,9 @@ use tauri_utils::display_path;
 pub use self::{
   category::AppCategory,
   settings::{
-    BundleBinary, BundleSettings, DebianSettings, MacOsSettings, PackageSettings, PackageType,
-    Settings, SettingsBuilder, UpdaterSettings,
+    BundleBinary, BundleSettings, BundleTypeRole, DebianSettings, DeepLinkProtocol,
+    FileAssociation, MacOsSettings, PackageSettings, PackageType, Settings, SettingsBuilder,
+    UpdaterSettings,
   },
 };
 use log::{info, warn};
@@ -29,7 +29,8 @@ pub fn bundle_project(settings: &Settings) -> crate::Result<Vec<PathBuf>> {
   };
   let package_dir = settings.project_out_directory().join("bundle/appimage_deb");
 
-  // generate deb_folder structure
+  // generate deb_folder structure, the AppDir reuses its desktop and MIME info files so the
+  // deep link schemes and file associations are registered when the AppImage is integrated
   let (_, icons) = debian::generate_data(settings, &package_dir)?;
   let icons: Vec<debian::DebIcon> = icons.into_iter().collect();
 
@@ -16,6 +16,7 @@
 //         usr/bin/foobar                            # Binary executable file
 //         usr/share/applications/foobar.desktop     # Desktop file (for apps)
 //         usr/share/icons/hicolor/...               # Icon files (for apps)
+//         usr/share/mime/packages/foobar.xml        # MIME types of the file associations
 //         usr/lib/foobar/...                        # Other resource files
 //
 // For cargo-bundle, we put bundle resource files under /usr/lib/package_name/,
@@ -24,16 +25,18 @@
 // generate postinst or prerm files.
 
 use super::super::common;
-use crate::Settings;
+use crate::{FileAssociation, Settings};
 use anyhow::Context;
+use handlebars::Handlebars;
 use heck::AsKebabCase;
 use image::{self, codecs::png::PngDecoder, ImageDecoder};
 use libflate::gzip;
 use log::info;
+use serde::Serialize;
 use walkdir::WalkDir;
 
 use std::{
-  collections::BTreeSet,
+  collections::{BTreeMap, BTreeSet},
   ffi::OsStr,
   fs::{self, File},
   io::{self, Write},
@@ -130,6 +133,8 @@ pub fn generate_data(
   let icons =
     generate_icon_files(settings, &data_dir).with_context(|| "Failed to create icon files")?;
   generate_desktop_file(settings, &data_dir).with_context(|| "Failed to create desktop file")?;
+  generate_mime_info_file(settings, &data_dir)
+    .with_context(|| "Failed to create shared MIME info file")?;
 
   Ok((data_dir, icons))
 }
@@ -153,14 +158,91 @@ fn generate_desktop_file(settings: &Settings, data_dir: &Path) -> crate::Result<
   if !settings.short_description().is_empty() {
     writeln!(file, "Comment={}", settings.short_description())?;
   }
-  writeln!(file, "Exec={}", bin_name)?;
+  let schemes = settings
+    .deep_link_protocols()
+    .iter()
+    .flat_map(|protocol| &protocol.schemes)
+    .map(|scheme| format!("x-scheme-handler/{}", scheme))
+    .collect::<Vec<_>>();
+  let file_mime_types = settings
+    .file_associations()
+    .iter()
+    .filter_map(association_mime_type)
+    .collect::<Vec<_>>();
+  // make the desktop environment pass the opened URLs or files as arguments
+  let exec_args = match (schemes.is_empty(), file_mime_types.is_empty()) {
+    (true, true) => "",
+    (false, true) => " %u",
+    (true, false) => " %F",
+    (false, false) => " %U",
+  };
+  writeln!(file, "Exec={}{}", bin_name, exec_args)?;
   writeln!(file, "Icon={}", bin_name)?;
+  let mime_types = schemes
+    .iter()
+    .chain(&file_mime_types)
+    .map(|mime_type| format!("{};", mime_type))
+    .collect::<String>();
+  if !mime_types.is_empty() {
+    writeln!(file, "MimeType={}", mime_types)?;
+  }
   writeln!(file, "Name={}", settings.product_name())?;
   writeln!(file, "Terminal=false")?;
   writeln!(file, "Type=Application")?;
   Ok(())
 }
 
+/// The MIME type of the files of an association, `None` if it has no extension.
+fn association_mime_type(association: &FileAssociation) -> Option<String> {
+  association.mime_type.clone().or_else(|| {
+    association
+      .ext
+      .first()
+      .map(|ext| format!("application/x-{}", ext))
+  })
+}
+
+/// Generate the shared MIME info file declaring the file associations and store it under the `data_dir`.
+fn generate_mime_info_file(settings: &Settings, data_dir: &Path) -> crate::Result<()> {
+  #[derive(Serialize)]
+  struct MimeType<'a> {
+    mime_type: String,
+    description: Option<&'a str>,
+    ext: &'a [String],
+  }
+
+  let mime_types = settings
+    .file_associations()
+    .iter()
+    .filter_map(|association| {
+      association_mime_type(association).map(|mime_type| MimeType {
+        mime_type,
+        description: association.description.as_deref(),
+        ext: &association.ext,
+      })
+    })
+    .collect::<Vec<_>>();
+  if mime_types.is_empty() {
+    return Ok(());
+  }
+
+  // For more information about the format of this file, see
+  // https://specifications.freedesktop.org/shared-mime-info-spec/shared-mime-info-spec-latest.html
+  let mut handlebars = Handlebars::new();
+  handlebars
+    .register_template_string("mime-info", include_str!("templates/mime-info.xml"))
+    .expect("Failed to register template for handlebars");
+  let mut data = BTreeMap::new();
+  data.insert("mime_types", mime_types);
+
+  let mime_info_path = data_dir
+    .join("usr/share/mime/packages")
+    .join(format!("{}.xml", settings.main_binary_name()));
+  let file = &mut common::create_file(&mime_info_path)?;
+  file.write_all(handlebars.render("mime-info", &data)?.as_bytes())?;
+  Ok(())
+}
+
 /// Generates the debian control file and stores it under the `control_dir`.
 fn generate_control_file(
   settings: &Settings,
@@ -169,6 +169,57 @@ fn create_info_plist(
     plist.insert("NSHumanReadableCopyright".into(), copyright.into());
   }
 
+  if !settings.file_associations().is_empty() {
+    let mut document_types = Vec::new();
+    for association in settings.file_associations() {
+      let mut document_type = plist::Dictionary::new();
+      document_type.insert(
+        "CFBundleTypeExtensions".into(),
+        plist::Value::Array(
+          association
+            .ext
+            .iter()
+            .map(|ext| ext.clone().into())
+            .collect(),
+        ),
+      );
+      if let Some(mime_type) = &association.mime_type {
+        document_type.insert(
+          "CFBundleTypeMIMETypes".into(),
+          plist::Value::Array(vec![mime_type.clone().into()]),
+        );
+      }
+      document_type.insert(
+        "CFBundleTypeName".into(),
+        association
+          .description
+          .clone()
+          .or_else(|| association.ext.first().cloned())
+          .unwrap_or_default()
+          .into(),
+      );
+      document_type.insert(
+        "CFBundleTypeRole".into(),
+        association.role.to_string().into(),
+      );
+      if let Some(icon) = &association.icon {
+        let icon_file_name = icon
+          .file_name()
+          .expect("No file name")
+          .to_string_lossy()
+          .into_owned();
+        common::copy_file(icon, &bundle_dir.join("Resources").join(&icon_file_name))
+          .with_context(|| format!("Failed to copy file association icon {:?}", icon))?;
+        document_type.insert("CFBundleTypeIconFile".into(), icon_file_name.into());
+      }
+      document_types.push(document_type.into());
+    }
+    plist.insert(
+      "CFBundleDocumentTypes".into(),
+      plist::Value::Array(document_types),
+    );
+  }
+
   if let Some(exception_domain) = settings.macos().exception_domain.clone() {
     let mut security = plist::Dictionary::new();
     let mut domain = plist::Dictionary::new();
@@ -5,7 +5,9 @@
 
 use super::category::AppCategory;
 use crate::bundle::{common, platform::target_triple};
-pub use tauri_utils::config::WebviewInstallMode;
+pub use tauri_utils::config::{
+  BundleTypeRole, DeepLinkProtocol, FileAssociation, WebviewInstallMode,
+};
 use tauri_utils::{
   config::{BundleType, NSISInstallerMode},
   resources::{external_binaries, ResourcePaths},
@@ -378,6 +380,10 @@ pub struct BundleSettings {
   pub updater: Option<UpdaterSettings>,
   /// Windows-specific settings.
   pub windows: WindowsSettings,
+  /// The custom URL schemes handled by the app.
+  pub deep_link_protocols: Option<Vec<DeepLinkProtocol>>,
+  /// The file types the app is registered to open.
+  pub file_associations: Option<Vec<FileAssociation>>,
 }
 
 /// A binary to bundle.
@@ -787,6 +793,24 @@ impl Settings {
     self.bundle_settings.long_description.as_deref()
   }
 
+  /// Returns the custom URL schemes handled by the app.
+  pub fn deep_link_protocols(&self) -> &[DeepLinkProtocol] {
+    self
+      .bundle_settings
+      .deep_link_protocols
+      .as_deref()
+      .unwrap_or_default()
+  }
+
+  /// Returns the file types the app is registered to open.
+  pub fn file_associations(&self) -> &[FileAssociation] {
+    self
+      .bundle_settings
+      .file_associations
+      .as_deref()
+      .unwrap_or_default()
+  }
+
   /// Returns the debian settings.
   pub fn deb(&self) -> &DebianSettings {
     &self.bundle_settings.deb
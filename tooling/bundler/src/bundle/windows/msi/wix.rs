@@ -8,9 +8,9 @@ use crate::bundle::{
   path_utils::{copy_file, FileOpts},
   settings::Settings,
   windows::util::{
-    download, download_and_verify, extract_zip, try_sign, HashAlgorithm, WEBVIEW2_BOOTSTRAPPER_URL,
-    WEBVIEW2_X64_INSTALLER_GUID, WEBVIEW2_X86_INSTALLER_GUID, WIX_OUTPUT_FOLDER_NAME,
-    WIX_UPDATER_OUTPUT_FOLDER_NAME,
+    download, download_and_verify, extract_zip, generate_file_associations_data, try_sign,
+    HashAlgorithm, WEBVIEW2_BOOTSTRAPPER_URL, WEBVIEW2_X64_INSTALLER_GUID,
+    WEBVIEW2_X86_INSTALLER_GUID, WIX_OUTPUT_FOLDER_NAME, WIX_UPDATER_OUTPUT_FOLDER_NAME,
   },
 };
 use anyhow::{bail, Context};
@@ -602,6 +602,11 @@ pub fn build_wix_app_installer(
   data.insert("resources", to_json(resources_wix_string));
   data.insert("resource_file_ids", to_json(files_ids));
 
+  data.insert(
+    "file_associations",
+    to_json(generate_file_associations_data(settings)?),
+  );
+
   let merge_modules = get_merge_modules(settings)?;
   data.insert("merge_modules", to_json(merge_modules));
 
@@ -9,12 +9,12 @@ use std::{
 };
 
 use log::info;
+use serde::Serialize;
 use sha2::Digest;
 use zip::ZipArchive;
 
 #[cfg(target_os = "windows")]
 use crate::bundle::windows::sign::{sign, SignParams};
-#[cfg(target_os = "windows")]
 use crate::Settings;
 
 pub const WEBVIEW2_BOOTSTRAPPER_URL: &str = "https://go.microsoft.com/fwlink/p/?LinkId=2124703";
@@ -136,3 +136,51 @@ pub fn extract_zip(data: &[u8], path: &Path) -> crate::Result<()> {
 pub fn remove_unc_lossy<P: AsRef<Path>>(p: P) -> PathBuf {
   PathBuf::from(p.as_ref().to_string_lossy().replacen(r"\\?\", "", 1))
 }
+
+/// A file extension registered by the installers.
+#[derive(Serialize)]
+pub struct FileAssociationData {
+  /// The file extension, without the leading dot.
+  ext: String,
+  /// The programmatic identifier of the file type.
+  prog_id: String,
+  /// The human readable description of the file type.
+  description: String,
+  /// The MIME type of the files.
+  mime_type: Option<String>,
+  /// The path of the icon to install.
+  icon_path: Option<PathBuf>,
+  /// The file name of the installed icon.
+  icon_name: Option<String>,
+}
+
+/// Generates the registry data of the file associations, one entry per extension.
+pub fn generate_file_associations_data(
+  settings: &Settings,
+) -> crate::Result<Vec<FileAssociationData>> {
+  let cwd = std::env::current_dir()?;
+  let mut data = Vec::new();
+  for association in settings.file_associations() {
+    let icon_path = match &association.icon {
+      Some(icon) => Some(remove_unc_lossy(cwd.join(icon).canonicalize()?)),
+      None => None,
+    };
+    for ext in &association.ext {
+      let prog_id = format!("{}.{}", settings.main_binary_name(), ext);
+      data.push(FileAssociationData {
+        ext: ext.clone(),
+        // the NSIS installer script can't contain double quotes in strings
+        description: association
+          .description
+          .as_ref()
+          .map(|description| description.replace('"', "'"))
+          .unwrap_or_else(|| format!("{} file", ext)),
+        mime_type: association.mime_type.clone(),
+        icon_name: icon_path.as_ref().map(|_| format!("{}.ico", prog_id)),
+        icon_path: icon_path.clone(),
+        prog_id,
+      });
+    }
+  }
+  Ok(data)
+}
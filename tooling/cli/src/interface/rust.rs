@@ -1050,6 +1050,8 @@ fn tauri_config_to_bundle_settings(
     short_description: config.short_description,
     long_description: config.long_description,
     external_bin: config.external_bin,
+    deep_link_protocols: config.deep_link_protocols,
+    file_associations: config.file_associations,
     deb: DebianSettings {
       depends: if depends.is_empty() {
         None